@@ -32,6 +32,9 @@ cargo clean -p rustfst-cli
 
 cargo build --manifest-path rustfst/Cargo.toml --features "state-label-u32"
 cargo test --manifest-path rustfst/Cargo.toml  --features "state-label-u32"
+cargo test --manifest-path rustfst/Cargo.toml --features "randgen"
+cargo test --manifest-path rustfst/Cargo.toml --features "serde"
+cargo test --manifest-path rustfst/Cargo.toml --features "rayon"
 cargo build --all
 cargo test --all
 cargo check --benches --all # running benches on travis is useless
@@ -2,13 +2,15 @@ use std::fs::File;
 use std::io::Write;
 use std::time::{Duration, Instant};
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use clap::ArgMatches;
 use colored::Colorize;
 use log::{debug, info};
 
 use rustfst::prelude::*;
 
+use crate::cli_semiring::{CliSemiring, SemiringType};
+
 fn duration_to_seconds(duration: &Duration) -> f64 {
     duration.as_secs() as f64 + duration.subsec_nanos() as f64 * 1.0e-9
 }
@@ -28,43 +30,61 @@ pub trait BinaryFstAlgorithm {
     fn get_path_out(&self) -> &str;
     fn get_algorithm_name(&self) -> String;
 
-    fn read(&self) -> Result<(VectorFst<TropicalWeight>, VectorFst<TropicalWeight>)> {
+    fn read<W: CliSemiring>(&self) -> Result<(VectorFst<W>, VectorFst<W>)> {
         Ok((
-            VectorFst::<TropicalWeight>::read(self.get_path_in_1())?,
-            VectorFst::<TropicalWeight>::read(self.get_path_in_2())?,
+            VectorFst::<W>::read(self.get_path_in_1())?,
+            VectorFst::<W>::read(self.get_path_in_2())?,
         ))
     }
 
-    fn run_algorithm(
+    fn run_algorithm<W: CliSemiring>(
         &self,
-        fst_1: VectorFst<TropicalWeight>,
-        fst_2: VectorFst<TropicalWeight>,
-    ) -> Result<VectorFst<TropicalWeight>>;
+        fst_1: VectorFst<W>,
+        fst_2: VectorFst<W>,
+    ) -> Result<VectorFst<W>>;
 
-    fn write(&self, fst: &VectorFst<TropicalWeight>) -> Result<()> {
+    fn write<W: CliSemiring>(&self, fst: &VectorFst<W>) -> Result<()> {
         fst.write(self.get_path_out())
     }
 
+    /// Runs the algorithm in the semiring of the input FSTs, read from their headers. Both FSTs
+    /// must have the same semiring.
     fn run_cli_or_bench(&self, m: &ArgMatches) -> Result<()> {
+        let semiring_type_1 = SemiringType::from_fst_file(self.get_path_in_1())?;
+        let semiring_type_2 = SemiringType::from_fst_file(self.get_path_in_2())?;
+        if semiring_type_1 != semiring_type_2 {
+            bail!(
+                "The input FSTs must have the same semiring : {:?} != {:?}",
+                semiring_type_1,
+                semiring_type_2
+            );
+        }
+        match semiring_type_1 {
+            SemiringType::Tropical => self.run_cli_or_bench_with_semiring::<TropicalWeight>(m),
+            SemiringType::Log => self.run_cli_or_bench_with_semiring::<LogWeight>(m),
+        }
+    }
+
+    fn run_cli_or_bench_with_semiring<W: CliSemiring>(&self, m: &ArgMatches) -> Result<()> {
         if m.is_present("bench") {
             // Run bench
-            self.run_bench(
+            self.run_bench::<W>(
                 m.value_of("n_warm_ups").unwrap().parse().unwrap(),
                 m.value_of("n_iters").unwrap().parse().unwrap(),
                 m.value_of("export-markdown"),
             )
         } else {
             // Run cli
-            self.run_cli()
+            self.run_cli::<W>()
         }
     }
 
-    fn run_cli(&self) -> Result<()> {
+    fn run_cli<W: CliSemiring>(&self) -> Result<()> {
         info!("Running {} algorithm", self.get_algorithm_name().blue());
         // Parsing
         debug!("Parsing...");
         let parsing_start = Instant::now();
-        let (fst_1, fst_2) = self.read()?;
+        let (fst_1, fst_2) = self.read::<W>()?;
         let duration_parsing = parsing_start.elapsed();
         debug!("Duration parsing : {:?}", &duration_parsing);
 
@@ -85,7 +105,7 @@ pub trait BinaryFstAlgorithm {
         Ok(())
     }
 
-    fn run_bench(
+    fn run_bench<W: CliSemiring>(
         &self,
         n_warm_ups: usize,
         n_iters: usize,
@@ -107,7 +127,7 @@ pub trait BinaryFstAlgorithm {
         for i in 0..(n_warm_ups + n_iters) {
             // Parsing
             let parsing_start = Instant::now();
-            let (fst_1, fst_2) = self.read()?;
+            let (fst_1, fst_2) = self.read::<W>()?;
             let duration_parsing = parsing_start.elapsed();
 
             // Algorithm
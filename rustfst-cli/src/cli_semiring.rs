@@ -0,0 +1,46 @@
+use anyhow::{bail, Result};
+
+use rustfst::prelude::*;
+
+/// Semirings of the FSTs the CLI can process.
+pub trait CliSemiring:
+    SerializableSemiring
+    + WeaklyDivisibleSemiring
+    + WeightQuantize
+    + Semiring<Type = f32, ReverseWeight = Self>
+{
+}
+
+impl<W> CliSemiring for W where
+    W: SerializableSemiring
+        + WeaklyDivisibleSemiring
+        + WeightQuantize
+        + Semiring<Type = f32, ReverseWeight = W>
+{
+}
+
+/// Semiring of an FST file, detected from the tr type stored in its header.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SemiringType {
+    Tropical,
+    Log,
+}
+
+impl SemiringType {
+    pub fn from_fst_file(path: &str) -> Result<Self> {
+        let tr_type = read_fst_header(path)?.tr_type;
+        if tr_type == Tr::<TropicalWeight>::tr_type() {
+            Ok(SemiringType::Tropical)
+        } else if tr_type == Tr::<LogWeight>::tr_type() {
+            Ok(SemiringType::Log)
+        } else {
+            bail!(
+                "Unsupported tr type {:?} for {:?}, expected {:?} or {:?}",
+                tr_type,
+                path,
+                Tr::<TropicalWeight>::tr_type(),
+                Tr::<LogWeight>::tr_type()
+            )
+        }
+    }
+}
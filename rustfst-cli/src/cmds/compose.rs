@@ -1,12 +1,14 @@
 use anyhow::Result;
+use log::warn;
 
 use rustfst::algorithms::compose::{
     compose, ComposeFst, ComposeFstOpOptions, LabelReachableData, MatcherFst,
 };
 use rustfst::fst_impls::VectorFst;
-use rustfst::semirings::TropicalWeight;
+use rustfst::fst_traits::Fst;
 
 use crate::binary_fst_algorithm::BinaryFstAlgorithm;
+use crate::cli_semiring::CliSemiring;
 use rustfst::algorithms::compose::compose_filters::{
     AltSequenceComposeFilterBuilder, ComposeFilterBuilder,
 };
@@ -65,14 +67,15 @@ impl BinaryFstAlgorithm for ComposeAlgorithm {
         "compose".to_string()
     }
 
-    fn run_algorithm(
+    fn run_algorithm<W: CliSemiring>(
         &self,
-        fst_1: VectorFst<TropicalWeight>,
-        mut fst_2: VectorFst<TropicalWeight>,
-    ) -> Result<VectorFst<TropicalWeight>> {
+        fst_1: VectorFst<W>,
+        mut fst_2: VectorFst<W>,
+    ) -> Result<VectorFst<W>> {
+        check_symbol_tables(&fst_1, &fst_2);
         match self.compose_type {
             ComposeType::Default => {
-                compose::<TropicalWeight, VectorFst<_>, VectorFst<_>, _, _, _>(&fst_1, &fst_2)
+                compose::<W, VectorFst<_>, VectorFst<_>, _, _, _>(&fst_1, &fst_2)
             }
             ComposeType::LookAhead => {
                 type TLaFst<'a, S, F> = MatcherFst<
@@ -183,6 +186,21 @@ impl BinaryFstAlgorithm for ComposeAlgorithm {
     }
 }
 
+/// Warns if the output labels of `fst_1` and the input labels of `fst_2` don't refer to the same
+/// symbols. The composition is still computed as it only relies on the labels.
+fn check_symbol_tables<W: CliSemiring>(fst_1: &VectorFst<W>, fst_2: &VectorFst<W>) {
+    if let (Some(osymt), Some(isymt)) = (fst_1.output_symbols(), fst_2.input_symbols()) {
+        if !osymt.compatible(isymt) {
+            warn!(
+                "The output symbol table of the first FST and the input symbol table of the \
+                 second FST don't match : checksums {} != {}",
+                osymt.labeled_check_sum(),
+                isymt.labeled_check_sum()
+            );
+        }
+    }
+}
+
 impl ComposeAlgorithm {
     pub fn new(path_in_1: &str, path_in_2: &str, path_out: &str, compose_type: &str) -> Self {
         let compose_type = match compose_type {
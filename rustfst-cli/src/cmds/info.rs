@@ -0,0 +1,119 @@
+use std::fmt::Write;
+use std::sync::Arc;
+
+use anyhow::Result;
+use log::info;
+
+use rustfst::fst_properties::FstProperties;
+use rustfst::prelude::*;
+
+use crate::cli_semiring::{CliSemiring, SemiringType};
+
+/// Prints a summary of an FST, in the spirit of OpenFST's `fstinfo`.
+pub struct InfoAlgorithm {
+    path_in: String,
+}
+
+/// Properties reported by the summary. The negative ones are implied by the computed properties.
+const REPORTED_PROPERTIES: &[(&str, FstProperties)] = &[
+    ("acceptor", FstProperties::ACCEPTOR),
+    ("input deterministic", FstProperties::I_DETERMINISTIC),
+    ("output deterministic", FstProperties::O_DETERMINISTIC),
+    ("input/output epsilons", FstProperties::EPSILONS),
+    ("input epsilons", FstProperties::I_EPSILONS),
+    ("output epsilons", FstProperties::O_EPSILONS),
+    ("input label sorted", FstProperties::I_LABEL_SORTED),
+    ("output label sorted", FstProperties::O_LABEL_SORTED),
+    ("weighted", FstProperties::WEIGHTED),
+    ("cyclic", FstProperties::CYCLIC),
+    ("cyclic at initial state", FstProperties::INITIAL_CYCLIC),
+    ("top sorted", FstProperties::TOP_SORTED),
+    ("accessible", FstProperties::ACCESSIBLE),
+    ("coaccessible", FstProperties::COACCESSIBLE),
+    ("string", FstProperties::STRING),
+    ("weighted cycles", FstProperties::WEIGHTED_CYCLES),
+];
+
+fn symbol_table_name(symt: Option<&Arc<SymbolTable>>) -> String {
+    match symt {
+        Some(symt) => format!("{} symbols", symt.len()),
+        None => "none".to_string(),
+    }
+}
+
+fn summary<W: CliSemiring>(mut fst: VectorFst<W>) -> Result<String> {
+    let mut num_trs = 0;
+    let mut num_epsilons = 0;
+    let mut num_input_epsilons = 0;
+    let mut num_output_epsilons = 0;
+    let mut num_final_states = 0;
+    for state in fst.states_range() {
+        num_trs += fst.num_trs(state)?;
+        num_input_epsilons += fst.num_input_epsilons(state)?;
+        num_output_epsilons += fst.num_output_epsilons(state)?;
+        num_epsilons += fst
+            .get_trs(state)?
+            .trs()
+            .iter()
+            .filter(|tr| tr.ilabel == EPS_LABEL && tr.olabel == EPS_LABEL)
+            .count();
+        if fst.is_final(state)? {
+            num_final_states += 1;
+        }
+    }
+    let start = match fst.start() {
+        Some(s) => format!("{}", s),
+        None => "none".to_string(),
+    };
+
+    let mut res = String::new();
+    let mut line = |name: &str, value: String| writeln!(res, "{:<50}{}", name, value);
+    line("fst type", VectorFst::<W>::fst_type())?;
+    line("arc type", Tr::<W>::tr_type())?;
+    line("input symbol table", symbol_table_name(fst.input_symbols()))?;
+    line(
+        "output symbol table",
+        symbol_table_name(fst.output_symbols()),
+    )?;
+    line("# of states", format!("{}", fst.num_states()))?;
+    line("# of arcs", format!("{}", num_trs))?;
+    line("initial state", start)?;
+    line("# of final states", format!("{}", num_final_states))?;
+    line("# of input/output epsilons", format!("{}", num_epsilons))?;
+    line("# of input epsilons", format!("{}", num_input_epsilons))?;
+    line("# of output epsilons", format!("{}", num_output_epsilons))?;
+    line(
+        "# of accessible states",
+        format!("{}", num_accessible(&fst)),
+    )?;
+    line(
+        "# of coaccessible states",
+        format!("{}", num_coaccessible(&fst)),
+    )?;
+
+    let props = fst.compute_and_update_properties_all()?;
+    for &(name, prop) in REPORTED_PROPERTIES {
+        let value = if props.contains(prop) { "y" } else { "n" };
+        line(name, value.to_string())?;
+    }
+    Ok(res)
+}
+
+impl InfoAlgorithm {
+    pub fn new(path_in: &str) -> Self {
+        Self {
+            path_in: path_in.to_string(),
+        }
+    }
+
+    /// Prints the summary of the input FST on the standard output.
+    pub fn run_cli(&self) -> Result<()> {
+        info!("Computing the summary of {}", self.path_in);
+        let res = match SemiringType::from_fst_file(&self.path_in)? {
+            SemiringType::Tropical => summary(VectorFst::<TropicalWeight>::read(&self.path_in)?)?,
+            SemiringType::Log => summary(VectorFst::<LogWeight>::read(&self.path_in)?)?,
+        };
+        print!("{}", res);
+        Ok(())
+    }
+}
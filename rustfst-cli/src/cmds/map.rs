@@ -3,6 +3,7 @@ use unsafe_unwrap::UnsafeUnwrap;
 
 use rustfst::prelude::*;
 
+use crate::cli_semiring::CliSemiring;
 use crate::unary_fst_algorithm::UnaryFstAlgorithm;
 
 pub struct MapAlgorithm {
@@ -25,10 +26,7 @@ impl UnaryFstAlgorithm for MapAlgorithm {
         format!("map {}", self.map_type)
     }
 
-    fn run_algorithm(
-        &self,
-        mut fst: VectorFst<TropicalWeight>,
-    ) -> Result<VectorFst<TropicalWeight>> {
+    fn run_algorithm<W: CliSemiring>(&self, mut fst: VectorFst<W>) -> Result<VectorFst<W>> {
         match self.map_type.as_str() {
             "tr_sum" | "arc_sum" => {
                 tr_sum(&mut fst);
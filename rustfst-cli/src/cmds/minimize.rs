@@ -1,5 +1,6 @@
 use rustfst::prelude::*;
 
+use crate::cli_semiring::CliSemiring;
 use crate::unary_fst_algorithm::UnaryFstAlgorithm;
 use anyhow::Result;
 
@@ -22,10 +23,7 @@ impl UnaryFstAlgorithm for MinimizeAlgorithm {
         "minimize".to_string()
     }
 
-    fn run_algorithm(
-        &self,
-        mut fst: VectorFst<TropicalWeight>,
-    ) -> Result<VectorFst<TropicalWeight>> {
+    fn run_algorithm<W: CliSemiring>(&self, mut fst: VectorFst<W>) -> Result<VectorFst<W>> {
         let config = MinimizeConfig::default().with_allow_nondet(self.allow_nondet);
         minimize_with_config(&mut fst, config)?;
         Ok(fst)
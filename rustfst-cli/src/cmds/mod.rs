@@ -1,5 +1,6 @@
 pub mod compose;
 pub mod connect;
+pub mod info;
 pub mod invert;
 pub mod map;
 pub mod minimize;
@@ -2,6 +2,7 @@ use anyhow::Result;
 
 use rustfst::prelude::*;
 
+use crate::cli_semiring::CliSemiring;
 use crate::unary_fst_algorithm::UnaryFstAlgorithm;
 
 pub struct RmFinalEpsilonAlgorithm {
@@ -22,10 +23,7 @@ impl UnaryFstAlgorithm for RmFinalEpsilonAlgorithm {
         "rm final epsilon".to_string()
     }
 
-    fn run_algorithm(
-        &self,
-        mut fst: VectorFst<TropicalWeight>,
-    ) -> Result<VectorFst<TropicalWeight>> {
+    fn run_algorithm<W: CliSemiring>(&self, mut fst: VectorFst<W>) -> Result<VectorFst<W>> {
         rm_final_epsilon(&mut fst)?;
         Ok(fst)
     }
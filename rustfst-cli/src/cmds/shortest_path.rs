@@ -1,7 +1,8 @@
 use rustfst::prelude::*;
 
+use crate::cli_semiring::CliSemiring;
 use crate::unary_fst_algorithm::UnaryFstAlgorithm;
-use anyhow::Result;
+use anyhow::{bail, Result};
 
 pub struct ShortestPathAlgorithm {
     path_in: String,
@@ -23,7 +24,14 @@ impl UnaryFstAlgorithm for ShortestPathAlgorithm {
         "shortest path".to_string()
     }
 
-    fn run_algorithm(&self, fst: VectorFst<TropicalWeight>) -> Result<VectorFst<TropicalWeight>> {
+    fn run_algorithm<W: CliSemiring>(&self, fst: VectorFst<W>) -> Result<VectorFst<W>> {
+        if !W::properties().contains(SemiringProperties::PATH) {
+            bail!(
+                "The shortest path requires a semiring with a natural order (path property) \
+                 such as the tropical semiring, got the {} semiring",
+                W::weight_type()
+            );
+        }
         let config = ShortestPathConfig::default()
             .with_nshortest(self.nshortest)
             .with_unique(self.unique);
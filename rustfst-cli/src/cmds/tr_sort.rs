@@ -2,6 +2,7 @@ use anyhow::{bail, Result};
 
 use rustfst::prelude::*;
 
+use crate::cli_semiring::CliSemiring;
 use crate::unary_fst_algorithm::UnaryFstAlgorithm;
 
 pub struct TrsortAlgorithm {
@@ -23,13 +24,11 @@ impl UnaryFstAlgorithm for TrsortAlgorithm {
         "tr_sort".to_string()
     }
 
-    fn run_algorithm(
-        &self,
-        mut fst: VectorFst<TropicalWeight>,
-    ) -> Result<VectorFst<TropicalWeight>> {
+    fn run_algorithm<W: CliSemiring>(&self, mut fst: VectorFst<W>) -> Result<VectorFst<W>> {
         match self.sort_type.as_str() {
             "ilabel" => tr_sort(&mut fst, ILabelCompare {}),
             "olabel" => tr_sort(&mut fst, OLabelCompare {}),
+            "nextstate" => tr_sort(&mut fst, NextStateCompare {}),
             _ => bail!("Unknow sort_type : {}", self.sort_type),
         };
         Ok(fst)
@@ -7,6 +7,7 @@ use log::error;
 use crate::binary_fst_algorithm::BinaryFstAlgorithm;
 use crate::cmds::compose::ComposeAlgorithm;
 use crate::cmds::connect::ConnectAlgorithm;
+use crate::cmds::info::InfoAlgorithm;
 use crate::cmds::invert::InvertAlgorithm;
 use crate::cmds::map::MapAlgorithm;
 use crate::cmds::minimize::MinimizeAlgorithm;
@@ -20,6 +21,7 @@ use crate::cmds::tr_sort::TrsortAlgorithm;
 use crate::unary_fst_algorithm::UnaryFstAlgorithm;
 
 pub mod binary_fst_algorithm;
+pub mod cli_semiring;
 pub mod cmds;
 pub mod unary_fst_algorithm;
 
@@ -27,7 +29,7 @@ fn main() {
     let mut app = App::new("rustfst")
         .version("1.0")
         .author("Alexandre Caulier <alexandre.caulier@protonmail.com>")
-        .about("Rustfst CLI");
+        .about("Rustfst CLI. The FSTs may be over the tropical or the log semiring.");
 
     // Minimization
     let minimize_cmd = SubCommand::with_name("minimize")
@@ -52,7 +54,7 @@ fn main() {
                 .help("Comparison method.")
                 .long("sort_type")
                 .takes_value(true)
-                .possible_values(&["ilabel", "olabel"])
+                .possible_values(&["ilabel", "olabel", "nextstate"])
                 .default_value("ilabel"),
         );
     app = app.subcommand(one_in_one_out_options(tr_sort_cmd));
@@ -63,7 +65,13 @@ fn main() {
         .arg(
             Arg::with_name("project_output")
                 .help("Project output (vs. input)")
-                .long("project_output"),
+                .long("project_output")
+                .conflicts_with("project_input"),
+        )
+        .arg(
+            Arg::with_name("project_input")
+                .help("Project input (default)")
+                .long("project_input"),
         );
     app = app.subcommand(one_in_one_out_options(project_cmd));
 
@@ -155,6 +163,16 @@ fn main() {
         );
     app = app.subcommand(two_in_one_out_options(compose_cmd));
 
+    // Info
+    let info_cmd = SubCommand::with_name("info")
+        .about("Prints a summary of an FST.")
+        .arg(
+            Arg::with_name("in.fst")
+                .help("Path to input fst file.")
+                .required(true),
+        );
+    app = app.subcommand(info_cmd);
+
     let matches = app.get_matches();
 
     let env = env_logger::Env::default().filter_or(env_logger::DEFAULT_FILTER_ENV, "debug");
@@ -165,7 +183,7 @@ fn main() {
 
     if let Err(e) = handle(matches) {
         error!("{:?}", e);
-        process::exit(exitcode::OK)
+        process::exit(exitcode::SOFTWARE)
     }
 }
 
@@ -246,6 +264,7 @@ fn handle(matches: clap::ArgMatches) -> Result<()> {
             m.value_of("compose_type").unwrap(),
         )
         .run_cli_or_bench(m),
+        ("info", Some(m)) => InfoAlgorithm::new(m.value_of("in.fst").unwrap()).run_cli(),
         (s, _) => Err(format_err!("Unknown subcommand {}.", s)),
     }
 }
@@ -9,6 +9,8 @@ use log::{debug, info};
 
 use rustfst::prelude::*;
 
+use crate::cli_semiring::{CliSemiring, SemiringType};
+
 fn duration_to_seconds(duration: &Duration) -> f64 {
     duration.as_secs() as f64 + duration.subsec_nanos() as f64 * 1.0e-9
 }
@@ -27,36 +29,44 @@ pub trait UnaryFstAlgorithm {
     fn get_path_out(&self) -> &str;
     fn get_algorithm_name(&self) -> String;
 
-    fn read(&self) -> Result<VectorFst<TropicalWeight>> {
-        VectorFst::<TropicalWeight>::read(self.get_path_in())
+    fn read<W: CliSemiring>(&self) -> Result<VectorFst<W>> {
+        VectorFst::<W>::read(self.get_path_in())
     }
 
-    fn run_algorithm(&self, fst: VectorFst<TropicalWeight>) -> Result<VectorFst<TropicalWeight>>;
+    fn run_algorithm<W: CliSemiring>(&self, fst: VectorFst<W>) -> Result<VectorFst<W>>;
 
-    fn write(&self, fst: &VectorFst<TropicalWeight>) -> Result<()> {
+    fn write<W: CliSemiring>(&self, fst: &VectorFst<W>) -> Result<()> {
         fst.write(self.get_path_out())
     }
 
+    /// Runs the algorithm in the semiring of the input FST, read from its header.
     fn run_cli_or_bench(&self, m: &ArgMatches) -> Result<()> {
+        match SemiringType::from_fst_file(self.get_path_in())? {
+            SemiringType::Tropical => self.run_cli_or_bench_with_semiring::<TropicalWeight>(m),
+            SemiringType::Log => self.run_cli_or_bench_with_semiring::<LogWeight>(m),
+        }
+    }
+
+    fn run_cli_or_bench_with_semiring<W: CliSemiring>(&self, m: &ArgMatches) -> Result<()> {
         if m.is_present("bench") {
             // Run bench
-            self.run_bench(
+            self.run_bench::<W>(
                 m.value_of("n_warm_ups").unwrap().parse().unwrap(),
                 m.value_of("n_iters").unwrap().parse().unwrap(),
                 m.value_of("export-markdown"),
             )
         } else {
             // Run cli
-            self.run_cli()
+            self.run_cli::<W>()
         }
     }
 
-    fn run_cli(&self) -> Result<()> {
+    fn run_cli<W: CliSemiring>(&self) -> Result<()> {
         info!("Running {} algorithm", self.get_algorithm_name().blue());
         // Parsing
         debug!("Parsing...");
         let parsing_start = Instant::now();
-        let mut fst = self.read()?;
+        let mut fst = self.read::<W>()?;
         let duration_parsing = parsing_start.elapsed();
         debug!("Duration parsing : {:?}", &duration_parsing);
 
@@ -77,7 +87,7 @@ pub trait UnaryFstAlgorithm {
         Ok(())
     }
 
-    fn run_bench(
+    fn run_bench<W: CliSemiring>(
         &self,
         n_warm_ups: usize,
         n_iters: usize,
@@ -99,7 +109,7 @@ pub trait UnaryFstAlgorithm {
         for i in 0..(n_warm_ups + n_iters) {
             // Parsing
             let parsing_start = Instant::now();
-            let mut fst = self.read()?;
+            let mut fst = self.read::<W>()?;
             let duration_parsing = parsing_start.elapsed();
 
             // Algorithm
@@ -0,0 +1,295 @@
+use std::path::Path;
+use std::process::{Command, Output};
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use tempfile::tempdir;
+
+use rustfst::algorithms::compose::compose;
+use rustfst::fst_properties::FstProperties;
+use rustfst::prelude::*;
+use rustfst::symt;
+
+fn run_cli_output(args: &[&str]) -> Result<Output> {
+    let output = Command::new(env!("CARGO_BIN_EXE_rustfst-cli"))
+        .args(args)
+        .output()?;
+    ensure!(
+        output.status.success(),
+        "rustfst-cli {:?} failed : {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Ok(output)
+}
+
+/// Runs the CLI and returns its logs.
+fn run_cli(args: &[&str]) -> Result<String> {
+    let output = run_cli_output(args)?;
+    Ok(String::from_utf8_lossy(&output.stderr).into_owned())
+}
+
+/// Runs the CLI, expecting it to fail, and returns its logs.
+fn run_cli_failure(args: &[&str]) -> Result<String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_rustfst-cli"))
+        .args(args)
+        .output()?;
+    ensure!(
+        !output.status.success(),
+        "rustfst-cli {:?} should have failed",
+        args
+    );
+    Ok(String::from_utf8_lossy(&output.stderr).into_owned())
+}
+
+fn path_str(path: &Path) -> &str {
+    path.to_str().unwrap()
+}
+
+#[test]
+fn test_tr_sort_log_weight() -> Result<()> {
+    let mut fst = VectorFst::<LogWeight>::new();
+    fst.add_states(2);
+    fst.set_start(0)?;
+    fst.add_tr(0, Tr::new(3, 1, 0.5, 1))?;
+    fst.add_tr(0, Tr::new(1, 2, 1.5, 1))?;
+    fst.add_tr(0, Tr::new(2, 3, 2.5, 0))?;
+    fst.set_final(1, 0.3)?;
+
+    let dir = tempdir()?;
+    let path_in = dir.path().join("in.fst");
+    let path_out = dir.path().join("out.fst");
+    fst.write(&path_in)?;
+
+    run_cli(&["tr_sort", path_str(&path_in), path_str(&path_out)])?;
+
+    let fst_out = VectorFst::<LogWeight>::read(&path_out)?;
+    let mut fst_ref = fst.clone();
+    tr_sort(&mut fst_ref, ILabelCompare {});
+    assert_eq!(fst_out, fst_ref);
+    Ok(())
+}
+
+fn build_transducer() -> Result<VectorFst<TropicalWeight>> {
+    let mut fst = VectorFst::<TropicalWeight>::new();
+    fst.add_states(3);
+    fst.set_start(0)?;
+    fst.add_tr(0, Tr::new(1, 2, 0.5, 1))?;
+    fst.add_tr(0, Tr::new(3, 0, 1.5, 2))?;
+    fst.add_tr(1, Tr::new(2, 4, 2.5, 2))?;
+    fst.set_final(2, 0.3)?;
+    Ok(fst)
+}
+
+#[test]
+fn test_invert() -> Result<()> {
+    let fst = build_transducer()?;
+
+    let dir = tempdir()?;
+    let path_in = dir.path().join("in.fst");
+    let path_out = dir.path().join("out.fst");
+    fst.write(&path_in)?;
+
+    run_cli(&["invert", path_str(&path_in), path_str(&path_out)])?;
+
+    let fst_out = VectorFst::<TropicalWeight>::read(&path_out)?;
+    assert_eq!(fst_out.num_states(), fst.num_states());
+    assert_eq!(fst_out.start(), fst.start());
+    for state in fst.states_range() {
+        let trs = fst.get_trs(state)?;
+        let trs_out = fst_out.get_trs(state)?;
+        assert_eq!(trs_out.len(), trs.len());
+        for (tr_out, tr) in trs_out.trs().iter().zip(trs.trs()) {
+            assert_eq!(tr_out.ilabel, tr.olabel);
+            assert_eq!(tr_out.olabel, tr.ilabel);
+            assert_eq!(tr_out.weight, tr.weight);
+            assert_eq!(tr_out.nextstate, tr.nextstate);
+        }
+        assert_eq!(fst_out.final_weight(state)?, fst.final_weight(state)?);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_project() -> Result<()> {
+    let fst = build_transducer()?;
+
+    let dir = tempdir()?;
+    let path_in = dir.path().join("in.fst");
+    let path_out = dir.path().join("out.fst");
+    let path_out_input = dir.path().join("out_input.fst");
+    fst.write(&path_in)?;
+
+    run_cli(&[
+        "project",
+        path_str(&path_in),
+        path_str(&path_out),
+        "--project_output",
+    ])?;
+    run_cli(&[
+        "project",
+        path_str(&path_in),
+        path_str(&path_out_input),
+        "--project_input",
+    ])?;
+
+    let mut fst_out = VectorFst::<TropicalWeight>::read(&path_out)?;
+    let props = fst_out.compute_and_update_properties_all()?;
+    assert!(props.contains(FstProperties::ACCEPTOR));
+    let mut fst_ref = fst.clone();
+    project(&mut fst_ref, ProjectType::ProjectOutput);
+    assert_eq!(fst_out, fst_ref);
+
+    let fst_out_input = VectorFst::<TropicalWeight>::read(&path_out_input)?;
+    let mut fst_ref = fst;
+    project(&mut fst_ref, ProjectType::ProjectInput);
+    assert_eq!(fst_out_input, fst_ref);
+    Ok(())
+}
+
+fn build_acceptor() -> Result<VectorFst<TropicalWeight>> {
+    let mut fst = VectorFst::<TropicalWeight>::new();
+    fst.add_states(2);
+    fst.set_start(0)?;
+    fst.add_tr(0, Tr::new(2, 2, 1.0, 1))?;
+    fst.add_tr(0, Tr::new(0, 0, 0.25, 1))?;
+    fst.add_tr(1, Tr::new(4, 4, 0.5, 1))?;
+    fst.set_final(1, 0.1)?;
+    Ok(fst)
+}
+
+#[test]
+fn test_compose() -> Result<()> {
+    let fst_1 = build_transducer()?;
+    let fst_2 = build_acceptor()?;
+
+    let dir = tempdir()?;
+    let path_in_1 = dir.path().join("in_1.fst");
+    let path_in_2 = dir.path().join("in_2.fst");
+    let path_out = dir.path().join("out.fst");
+    fst_1.write(&path_in_1)?;
+    fst_2.write(&path_in_2)?;
+
+    let logs = run_cli(&[
+        "compose",
+        path_str(&path_in_1),
+        path_str(&path_in_2),
+        path_str(&path_out),
+    ])?;
+    assert!(!logs.contains("don't match"));
+
+    let fst_out = VectorFst::<TropicalWeight>::read(&path_out)?;
+    let fst_ref: VectorFst<_> = compose::<_, VectorFst<_>, VectorFst<_>, _, _, _>(&fst_1, &fst_2)?;
+    assert_eq!(fst_out, fst_ref);
+    Ok(())
+}
+
+#[test]
+fn test_compose_symbol_tables_mismatch() -> Result<()> {
+    let mut fst_1 = build_transducer()?;
+    let mut fst_2 = build_acceptor()?;
+    fst_1.set_output_symbols(Arc::new(symt!["a", "b", "c", "d"]));
+    fst_2.set_input_symbols(Arc::new(symt!["b", "a", "c", "d"]));
+
+    let dir = tempdir()?;
+    let path_in_1 = dir.path().join("in_1.fst");
+    let path_in_2 = dir.path().join("in_2.fst");
+    let path_out = dir.path().join("out.fst");
+    fst_1.write(&path_in_1)?;
+    fst_2.write(&path_in_2)?;
+
+    // The composition is still computed.
+    let logs = run_cli(&[
+        "compose",
+        path_str(&path_in_1),
+        path_str(&path_in_2),
+        path_str(&path_out),
+    ])?;
+    assert!(logs.contains("don't match"));
+
+    let fst_out = VectorFst::<TropicalWeight>::read(&path_out)?;
+    let fst_ref: VectorFst<_> = compose::<_, VectorFst<_>, VectorFst<_>, _, _, _>(&fst_1, &fst_2)?;
+    assert_eq!(fst_out, fst_ref);
+    Ok(())
+}
+
+fn build_lattice<W: Semiring<Type = f32>>() -> Result<VectorFst<W>> {
+    let mut fst = VectorFst::<W>::new();
+    fst.add_states(3);
+    fst.set_start(0)?;
+    fst.add_tr(0, Tr::new(1, 1, W::new(1.0), 1))?;
+    fst.add_tr(0, Tr::new(2, 2, W::new(0.5), 1))?;
+    fst.add_tr(0, Tr::new(5, 5, W::new(4.0), 2))?;
+    fst.add_tr(1, Tr::new(3, 3, W::new(2.0), 2))?;
+    fst.add_tr(1, Tr::new(4, 4, W::new(1.0), 2))?;
+    fst.set_final(2, W::one())?;
+    Ok(fst)
+}
+
+#[test]
+fn test_shortest_path() -> Result<()> {
+    let fst = build_lattice::<TropicalWeight>()?;
+
+    let dir = tempdir()?;
+    let path_in = dir.path().join("in.fst");
+    let path_out = dir.path().join("out.fst");
+    fst.write(&path_in)?;
+
+    run_cli(&["shortestpath", path_str(&path_in), path_str(&path_out)])?;
+
+    let fst_out = VectorFst::<TropicalWeight>::read(&path_out)?;
+    let paths: Vec<_> = fst_out.paths_iter().collect();
+    assert_eq!(paths.len(), 1);
+    assert_eq!(paths[0].ilabels, vec![2, 4]);
+    assert_eq!(paths[0].olabels, vec![2, 4]);
+    assert_eq!(paths[0].weight, TropicalWeight::new(1.5));
+    Ok(())
+}
+
+#[test]
+fn test_shortest_path_requires_path_semiring() -> Result<()> {
+    let fst = build_lattice::<LogWeight>()?;
+
+    let dir = tempdir()?;
+    let path_in = dir.path().join("in.fst");
+    let path_out = dir.path().join("out.fst");
+    fst.write(&path_in)?;
+
+    let logs = run_cli_failure(&["shortestpath", path_str(&path_in), path_str(&path_out)])?;
+    assert!(logs.contains("natural order"));
+    assert!(!path_out.exists());
+    Ok(())
+}
+
+#[test]
+fn test_info() -> Result<()> {
+    let fst = build_transducer()?;
+
+    let dir = tempdir()?;
+    let path_in = dir.path().join("in.fst");
+    fst.write(&path_in)?;
+
+    let output = run_cli_output(&["info", path_str(&path_in)])?;
+    let summary = String::from_utf8_lossy(&output.stdout);
+    // Each line is made of the name of the field padded to 50 characters followed by its value.
+    let value = |name: &str| {
+        summary
+            .lines()
+            .map(|line| line.split_at(50))
+            .find(|(field, _)| field.trim_end() == name)
+            .map(|(_, value)| value)
+    };
+    assert_eq!(value("arc type"), Some("standard"));
+    assert_eq!(value("# of states"), Some("3"));
+    assert_eq!(value("# of arcs"), Some("3"));
+    assert_eq!(value("initial state"), Some("0"));
+    assert_eq!(value("# of final states"), Some("1"));
+    assert_eq!(value("# of input epsilons"), Some("0"));
+    assert_eq!(value("# of output epsilons"), Some("1"));
+    assert_eq!(value("# of accessible states"), Some("3"));
+    assert_eq!(value("# of coaccessible states"), Some("3"));
+    assert_eq!(value("acceptor"), Some("n"));
+    assert_eq!(value("weighted"), Some("y"));
+    assert_eq!(value("cyclic"), Some("n"));
+    Ok(())
+}
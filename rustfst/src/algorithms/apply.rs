@@ -0,0 +1,102 @@
+use anyhow::Result;
+
+use crate::algorithms::compose::compose;
+use crate::fst_impls::VectorFst;
+use crate::fst_traits::{ExpandedFst, PathsIterator};
+use crate::semirings::Semiring;
+use crate::utils::acceptor;
+use crate::Label;
+
+/// Applies a transducer to a sequence of input labels and returns the output label sequences
+/// with their weight.
+///
+/// The input is turned into a linear acceptor which is composed with `fst`, one output is then
+/// returned for each successful path of the composition. The outputs are not deduplicated: two
+/// paths of `fst` producing the same output both appear in the result. As the composition can be
+/// cyclic, at most `max_paths` outputs are returned.
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::algorithms::apply;
+/// # use rustfst::utils::transducer;
+/// # fn main() -> Result<()> {
+/// let fst: VectorFst<TropicalWeight> = transducer(&[1, 2], &[3, 4], TropicalWeight::new(0.5));
+///
+/// assert_eq!(apply(&fst, &[1, 2], 10)?, vec![(vec![3, 4], TropicalWeight::new(0.5))]);
+/// assert_eq!(apply(&fst, &[2, 1], 10)?, vec![]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn apply<W, F>(fst: &F, input: &[Label], max_paths: usize) -> Result<Vec<(Vec<Label>, W)>>
+where
+    W: Semiring,
+    F: ExpandedFst<W>,
+{
+    let input_fst: VectorFst<W> = acceptor(input, W::one());
+    let composed: VectorFst<W> = compose::<_, VectorFst<W>, F, _, _, _>(&input_fst, fst)?;
+    Ok(composed
+        .paths_iter()
+        .take(max_paths)
+        .map(|path| (path.olabels, path.weight))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fst_traits::MutableFst;
+    use crate::semirings::TropicalWeight;
+    use crate::{Tr, EPS_LABEL};
+
+    #[test]
+    fn test_apply_transducer() -> Result<()> {
+        // Two pronunciations for the input 1 2.
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(4);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 10, 0.5, 1))?;
+        fst.add_tr(0, Tr::new(1, 11, 1.5, 1))?;
+        fst.add_tr(1, Tr::new(2, 20, 0.25, 2))?;
+        fst.add_tr(1, Tr::new(3, 30, 0.25, 3))?;
+        fst.set_final(2, TropicalWeight::one())?;
+        fst.set_final(3, TropicalWeight::one())?;
+
+        let mut outputs = apply(&fst, &[1, 2], 10)?;
+        outputs.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            outputs,
+            vec![
+                (vec![10, 20], TropicalWeight::new(0.75)),
+                (vec![11, 20], TropicalWeight::new(1.75)),
+            ]
+        );
+        assert!(apply(&fst, &[1], 10)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_cyclic_max_paths() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(2);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 5, 1.0, 1))?;
+        fst.add_tr(1, Tr::new(EPS_LABEL, 6, 1.0, 1))?;
+        fst.set_final(1, TropicalWeight::one())?;
+
+        let mut outputs = apply(&fst, &[1], 3)?;
+        outputs.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            outputs,
+            vec![
+                (vec![5], TropicalWeight::new(1.0)),
+                (vec![5, 6], TropicalWeight::new(2.0)),
+                (vec![5, 6, 6], TropicalWeight::new(3.0)),
+            ]
+        );
+        Ok(())
+    }
+}
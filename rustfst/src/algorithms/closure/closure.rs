@@ -69,3 +69,44 @@ where
         FstProperties::all_properties(),
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    use anyhow::Result;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::{Fst, PathsIterator};
+    use crate::semirings::TropicalWeight;
+    use crate::utils::acceptor;
+    use crate::{FstPath, SymbolTable};
+
+    #[test]
+    fn test_closure_star_keeps_symbol_tables() -> Result<()> {
+        let mut symt = SymbolTable::new();
+        symt.add_symbol("a");
+        symt.add_symbol("b");
+        let symt = Arc::new(symt);
+
+        let mut fst: VectorFst<TropicalWeight> = acceptor(&[1, 2], TropicalWeight::new(0.5));
+        fst.set_input_symbols(Arc::clone(&symt));
+        fst.set_output_symbols(Arc::clone(&symt));
+
+        closure(&mut fst, ClosureType::ClosureStar);
+
+        assert_eq!(fst.input_symbols(), Some(&symt));
+        assert_eq!(fst.output_symbols(), Some(&symt));
+        let paths: Vec<_> = fst.paths_iter().take(2).collect();
+        assert_eq!(
+            paths,
+            vec![
+                FstPath::new(vec![], vec![], TropicalWeight::one()),
+                FstPath::new(vec![1, 2], vec![1, 2], TropicalWeight::new(0.5)),
+            ]
+        );
+        Ok(())
+    }
+}
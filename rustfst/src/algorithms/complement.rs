@@ -0,0 +1,156 @@
+use std::collections::{BTreeSet, HashMap};
+
+use anyhow::Result;
+
+use crate::algorithms::tr_compares::ILabelCompare;
+use crate::algorithms::tr_sort;
+use crate::fst_properties::{compute_fst_properties, FstProperties};
+use crate::fst_traits::{ExpandedFst, MutableFst};
+use crate::semirings::Semiring;
+use crate::{Label, StateId, Tr, Trs, EPS_LABEL};
+
+/// This operation computes the complement of a deterministic acceptor, i.e the acceptor
+/// accepting all the strings over `alphabet` that are not accepted by the input FST.
+///
+/// The input FST is completed with a sink state : every missing transition on a label of
+/// `alphabet` leads to it. The final states then become non-final and conversely.
+/// The weights of the input FST are ignored, all the weights of the output are `One()`.
+///
+/// An error is returned if the input FST is not an epsilon-free deterministic acceptor or if
+/// one of its labels is not in `alphabet`. The trs of the output FST are sorted by input labels.
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::algorithms::complement;
+/// # use rustfst::utils::acceptor;
+/// # use rustfst::{check_path_in_fst, FstPath};
+/// # fn main() -> Result<()> {
+/// let fst: VectorFst<TropicalWeight> = acceptor(&[1], TropicalWeight::one());
+///
+/// let complement_fst: VectorFst<TropicalWeight> = complement(&fst, vec![1, 2])?;
+///
+/// let path_a = FstPath::new(vec![1], vec![1], TropicalWeight::one());
+/// let path_ba = FstPath::new(vec![2, 1], vec![2, 1], TropicalWeight::one());
+/// assert!(!check_path_in_fst(&complement_fst, &path_a));
+/// assert!(check_path_in_fst(&complement_fst, &path_ba));
+/// # Ok(())
+/// # }
+/// ```
+pub fn complement<W, F1, F2, I>(fst: &F1, alphabet: I) -> Result<F2>
+where
+    W: Semiring,
+    F1: ExpandedFst<W>,
+    F2: MutableFst<W>,
+    I: IntoIterator<Item = Label>,
+{
+    let props =
+        FstProperties::ACCEPTOR | FstProperties::NO_EPSILONS | FstProperties::I_DETERMINISTIC;
+    let mask = props
+        | FstProperties::NOT_ACCEPTOR
+        | FstProperties::EPSILONS
+        | FstProperties::NOT_I_DETERMINISTIC;
+    let mut known = FstProperties::empty();
+    if !compute_fst_properties(fst, mask, &mut known, true)?.contains(props) {
+        bail!("Complement : Fst must be an epsilon-free deterministic acceptor")
+    }
+
+    let alphabet: BTreeSet<Label> = alphabet.into_iter().filter(|l| *l != EPS_LABEL).collect();
+
+    let mut ofst = F2::new();
+    let num_states = fst.num_states();
+    ofst.add_states(num_states + 1);
+    let sink = num_states as StateId;
+
+    match fst.start() {
+        Some(start) => ofst.set_start(start)?,
+        // The input FST accepts the empty language : the sink state accepts everything.
+        None => ofst.set_start(sink)?,
+    };
+
+    for state in fst.states_range() {
+        let trs = fst.get_trs(state)?;
+        let mut nextstates = HashMap::with_capacity(trs.len());
+        for tr in trs.trs() {
+            if !alphabet.contains(&tr.ilabel) {
+                bail!(
+                    "Complement : label {:?} of state {:?} is not in the alphabet",
+                    tr.ilabel,
+                    state
+                )
+            }
+            nextstates.insert(tr.ilabel, tr.nextstate);
+        }
+        for label in alphabet.iter() {
+            let nextstate = nextstates.get(label).cloned().unwrap_or(sink);
+            ofst.add_tr(state, Tr::new(*label, *label, W::one(), nextstate))?;
+        }
+        if !fst.is_final(state)? {
+            ofst.set_final(state, W::one())?;
+        }
+    }
+
+    for label in alphabet.iter() {
+        ofst.add_tr(sink, Tr::new(*label, *label, W::one(), sink))?;
+    }
+    ofst.set_final(sink, W::one())?;
+
+    ofst.set_symts_from_fst(fst);
+    tr_sort(&mut ofst, ILabelCompare {});
+    Ok(ofst)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fst_impls::VectorFst;
+    use crate::semirings::TropicalWeight;
+    use crate::utils::acceptor;
+    use crate::{check_path_in_fst, FstPath};
+
+    fn path(labels: &[Label]) -> FstPath<TropicalWeight> {
+        FstPath::new(labels.to_vec(), labels.to_vec(), TropicalWeight::one())
+    }
+
+    #[test]
+    fn test_complement_linear_acceptor() -> Result<()> {
+        let fst: VectorFst<TropicalWeight> = acceptor(&[1, 2], TropicalWeight::new(3.0));
+
+        let complement_fst: VectorFst<TropicalWeight> = complement(&fst, vec![1, 2])?;
+
+        assert_eq!(complement_fst.num_states(), fst.num_states() + 1);
+        assert!(!check_path_in_fst(&complement_fst, &path(&[1, 2])));
+        for labels in &[
+            vec![],
+            vec![1],
+            vec![2],
+            vec![1, 1],
+            vec![2, 1],
+            vec![1, 2, 1],
+        ] {
+            assert!(check_path_in_fst(&complement_fst, &path(labels)));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_complement_empty_fst() -> Result<()> {
+        let fst = VectorFst::<TropicalWeight>::new();
+
+        let complement_fst: VectorFst<TropicalWeight> = complement(&fst, vec![1])?;
+
+        assert!(check_path_in_fst(&complement_fst, &path(&[])));
+        assert!(check_path_in_fst(&complement_fst, &path(&[1, 1])));
+        Ok(())
+    }
+
+    #[test]
+    fn test_complement_label_not_in_alphabet() {
+        let fst: VectorFst<TropicalWeight> = acceptor(&[1, 3], TropicalWeight::one());
+        let res: Result<VectorFst<TropicalWeight>> = complement(&fst, vec![1, 2]);
+        assert!(res.is_err());
+    }
+}
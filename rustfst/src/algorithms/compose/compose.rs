@@ -206,3 +206,72 @@ pub fn compose<
     let config = ComposeConfig::default();
     compose_with_config(fst1, fst2, config)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::{CoreFst, PathsIterator};
+    use crate::semirings::TropicalWeight;
+    use crate::{FstPath, Tr, EPS_LABEL};
+
+    #[test]
+    fn test_compose_with_epsilons() -> Result<()> {
+        // Lexicon : 1 2 -> 10 (epsilon on the output side)
+        let mut lexicon = VectorFst::<TropicalWeight>::new();
+        lexicon.add_states(3);
+        lexicon.set_start(0)?;
+        lexicon.add_tr(0, Tr::new(1, 10, 1.0, 1))?;
+        lexicon.add_tr(1, Tr::new(2, EPS_LABEL, 2.0, 2))?;
+        lexicon.set_final(2, TropicalWeight::one())?;
+
+        // Grammar : 10 -> 20 30 (epsilon on the input side)
+        let mut grammar = VectorFst::<TropicalWeight>::new();
+        grammar.add_states(3);
+        grammar.set_start(0)?;
+        grammar.add_tr(0, Tr::new(EPS_LABEL, 20, 0.5, 1))?;
+        grammar.add_tr(1, Tr::new(10, 30, 0.25, 2))?;
+        grammar.set_final(2, TropicalWeight::one())?;
+
+        let composed: VectorFst<_> =
+            compose::<_, VectorFst<_>, VectorFst<_>, _, _, _>(lexicon.clone(), grammar.clone())?;
+
+        assert!(composed.start().is_some());
+        let paths: Vec<_> = composed.paths_iter().collect();
+        assert_eq!(
+            paths,
+            vec![FstPath::new(
+                vec![1, 2],
+                vec![20, 30],
+                TropicalWeight::new(3.75)
+            )]
+        );
+
+        // Each epsilon path must be counted only once whatever the filter used.
+        for filter in &[
+            ComposeFilterEnum::SequenceFilter,
+            ComposeFilterEnum::AltSequenceFilter,
+            ComposeFilterEnum::MatchFilter,
+        ] {
+            let config = ComposeConfig {
+                compose_filter: *filter,
+                connect: true,
+            };
+            let composed_with_filter: VectorFst<_> =
+                compose_with_config::<_, VectorFst<_>, VectorFst<_>, _, _, _>(
+                    lexicon.clone(),
+                    grammar.clone(),
+                    config,
+                )?;
+            assert_eq!(
+                composed_with_filter.paths_iter().collect::<Vec<_>>(),
+                paths,
+                "{:?}",
+                filter
+            );
+        }
+
+        Ok(())
+    }
+}
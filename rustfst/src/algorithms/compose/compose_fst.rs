@@ -355,9 +355,12 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::algorithms::compose::compose;
     use crate::algorithms::compose::matchers::SortedMatcher;
     use crate::fst_impls::VectorFst;
+    use crate::fst_traits::PathsIterator;
     use crate::semirings::TropicalWeight;
+    use crate::{Label, Tr};
 
     #[test]
     fn test_compose_fst_sync() {
@@ -392,4 +395,60 @@ mod test {
             >,
         >();
     }
+
+    fn linear_fst(labels: &[Label]) -> Result<VectorFst<TropicalWeight>> {
+        let mut fst = VectorFst::new();
+        let mut current = fst.add_state();
+        fst.set_start(current)?;
+        for &label in labels {
+            let next = fst.add_state();
+            fst.add_tr(current, Tr::new(label, label, 1.0, next))?;
+            current = next;
+        }
+        fst.set_final(current, TropicalWeight::one())?;
+        Ok(fst)
+    }
+
+    #[test]
+    fn test_compose_fst_lazy_expansion() -> Result<()> {
+        let fst1 = Arc::new(linear_fst(&[1, 2, 3])?);
+        let fst2 = Arc::new(linear_fst(&[1, 2, 3])?);
+
+        let cache = Arc::new(SimpleVecCache::<TropicalWeight>::default());
+        let compose_fst = ComposeFst::<
+            TropicalWeight,
+            VectorFst<_>,
+            VectorFst<_>,
+            _,
+            _,
+            SortedMatcher<_, _, _>,
+            SortedMatcher<_, _, _>,
+            SequenceComposeFilterBuilder<_, _, _, _, _, _, _>,
+            _,
+        >::new_with_options_and_cache(
+            Arc::clone(&fst1),
+            Arc::clone(&fst2),
+            ComposeFstOpOptions::default(),
+            Arc::clone(&cache),
+        )?;
+
+        // Nothing is computed until requested.
+        assert_eq!(cache.compute_num_known_trs(), 0);
+
+        let start = compose_fst.start().unwrap();
+        let trs = compose_fst.get_trs(start)?;
+        assert_eq!(trs.len(), 1);
+
+        // Only the trs of the start state have been expanded.
+        assert_eq!(cache.compute_num_known_trs(), 1);
+        assert_eq!(cache.len_trs(), start as usize + 1);
+
+        let static_fst: VectorFst<_> =
+            compose::<_, VectorFst<_>, VectorFst<_>, _, _, _>(fst1, fst2)?;
+        assert_eq!(
+            compose_fst.paths_iter().collect::<Vec<_>>(),
+            static_fst.paths_iter().collect::<Vec<_>>()
+        );
+        Ok(())
+    }
 }
@@ -107,3 +107,68 @@ where
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::{CoreFst, PathsIterator};
+    use crate::semirings::TropicalWeight;
+    use crate::utils::acceptor;
+    use crate::FstPath;
+
+    #[test]
+    fn test_concat_weight_propagation() -> Result<()> {
+        let mut fst_1 = VectorFst::<TropicalWeight>::new();
+        let s0 = fst_1.add_state();
+        let s1 = fst_1.add_state();
+        fst_1.set_start(s0)?;
+        fst_1.add_tr(s0, Tr::new(1, 1, 1.0, s1))?;
+        fst_1.set_final(s0, 0.5)?;
+        fst_1.set_final(s1, 2.0)?;
+        let fst_2: VectorFst<TropicalWeight> = acceptor(&[2], TropicalWeight::new(3.0));
+
+        concat(&mut fst_1, &fst_2)?;
+
+        assert_eq!(fst_1.final_weight(s0)?, None);
+        assert_eq!(fst_1.final_weight(s1)?, None);
+        let mut paths: Vec<_> = fst_1.paths_iter().collect();
+        paths.sort_by(|a, b| a.ilabels.cmp(&b.ilabels));
+        assert_eq!(
+            paths,
+            vec![
+                FstPath::new(vec![1, 2], vec![1, 2], TropicalWeight::new(6.0)),
+                FstPath::new(vec![2], vec![2], TropicalWeight::new(3.5)),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_no_final_state() -> Result<()> {
+        let mut fst_1 = VectorFst::<TropicalWeight>::new();
+        let s0 = fst_1.add_state();
+        let s1 = fst_1.add_state();
+        fst_1.set_start(s0)?;
+        fst_1.add_tr(s0, Tr::new(1, 1, 1.0, s1))?;
+        let fst_2: VectorFst<TropicalWeight> = acceptor(&[2], TropicalWeight::new(3.0));
+
+        concat(&mut fst_1, &fst_2)?;
+
+        assert_eq!(fst_1.paths_iter().count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_empty_fst() -> Result<()> {
+        let mut fst_1: VectorFst<TropicalWeight> = acceptor(&[1], TropicalWeight::new(1.0));
+        let fst_2 = VectorFst::<TropicalWeight>::new();
+
+        concat(&mut fst_1, &fst_2)?;
+
+        assert_eq!(fst_1.num_states(), 2);
+        assert_eq!(fst_1.paths_iter().count(), 0);
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::marker::PhantomData;
 
 use anyhow::Result;
@@ -50,12 +51,48 @@ use crate::NO_STATE_ID;
 /// ![connect_out](https://raw.githubusercontent.com/Garvys/rustfst-images-doc/master/images/connect_out.svg?sanitize=true)
 ///
 pub fn connect<W: Semiring, F: ExpandedFst<W> + MutableFst<W>>(fst: &mut F) -> Result<()> {
+    connect_with_mapping(fst)?;
+    Ok(())
+}
+
+/// Same as `connect` but also returns the mapping from the ids of the states kept in the FST
+/// to their new ids. The deleted states are absent from the mapping.
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::fst_traits::MutableFst;
+/// # use rustfst::algorithms::connect_with_mapping;
+/// # use rustfst::Tr;
+/// # fn main() -> Result<()> {
+/// let mut fst = VectorFst::<TropicalWeight>::new();
+/// fst.add_states(3);
+/// fst.set_start(1)?;
+/// fst.add_tr(1, Tr::new(1, 1, 1.0, 2))?;
+/// fst.set_final(2, TropicalWeight::one())?;
+///
+/// let mapping = connect_with_mapping(&mut fst)?;
+///
+/// assert_eq!(mapping.get(&0), None);
+/// assert_eq!(mapping.get(&1), Some(&0));
+/// assert_eq!(mapping.get(&2), Some(&1));
+/// # Ok(())
+/// # }
+/// ```
+pub fn connect_with_mapping<W: Semiring, F: ExpandedFst<W> + MutableFst<W>>(
+    fst: &mut F,
+) -> Result<HashMap<StateId, StateId>> {
     let mut visitor = ConnectVisitor::new(fst);
     dfs_visit(fst, &mut visitor, &AnyTrFilter {}, false);
     let mut dstates = Vec::with_capacity(visitor.access.len());
+    let mut mapping = HashMap::with_capacity(visitor.access.len());
     for s in 0..visitor.access.len() {
         if !visitor.access[s] || !visitor.coaccess[s] {
             dstates.push(s as StateId);
+        } else {
+            mapping.insert(s as StateId, mapping.len() as StateId);
         }
     }
     fst.del_states(dstates)?;
@@ -63,7 +100,43 @@ pub fn connect<W: Semiring, F: ExpandedFst<W> + MutableFst<W>>(fst: &mut F) -> R
         FstProperties::ACCESSIBLE | FstProperties::COACCESSIBLE,
         FstProperties::ACCESSIBLE | FstProperties::COACCESSIBLE,
     );
-    Ok(())
+    Ok(mapping)
+}
+
+/// Returns the number of states of the FST that can be reached from the start state. The FST is
+/// not modified.
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::fst_traits::MutableFst;
+/// # use rustfst::algorithms::{num_accessible, num_coaccessible};
+/// # use rustfst::Tr;
+/// # fn main() -> Result<()> {
+/// let mut fst = VectorFst::<TropicalWeight>::new();
+/// fst.add_states(3);
+/// fst.set_start(1)?;
+/// fst.add_tr(1, Tr::new(1, 1, 1.0, 2))?;
+///
+/// assert_eq!(num_accessible(&fst), 2);
+/// assert_eq!(num_coaccessible(&fst), 0);
+/// # Ok(())
+/// # }
+/// ```
+pub fn num_accessible<W: Semiring, F: ExpandedFst<W>>(fst: &F) -> usize {
+    let mut visitor = ConnectVisitor::new(fst);
+    dfs_visit(fst, &mut visitor, &AnyTrFilter {}, false);
+    visitor.access.iter().filter(|a| **a).count()
+}
+
+/// Returns the number of states of the FST from which a final state can be reached. The FST is
+/// not modified.
+pub fn num_coaccessible<W: Semiring, F: ExpandedFst<W>>(fst: &F) -> usize {
+    let mut visitor = ConnectVisitor::new(fst);
+    dfs_visit(fst, &mut visitor, &AnyTrFilter {}, false);
+    visitor.coaccess.iter().filter(|a| **a).count()
 }
 
 struct ConnectVisitor<'a, W: Semiring, F: Fst<W>> {
@@ -208,4 +281,58 @@ mod tests {
             ));
         }
     }
+
+    #[test]
+    fn test_connect_with_mapping() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(5);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(0, Tr::new(2, 2, 1.0, 2))?;
+        fst.add_tr(2, Tr::new(3, 3, 1.0, 4))?;
+        fst.add_tr(3, Tr::new(4, 4, 1.0, 4))?;
+        fst.set_final(4, TropicalWeight::one())?;
+
+        let fst_ref = fst.clone();
+        let mapping = connect_with_mapping(&mut fst)?;
+
+        // State 1 is not coaccessible and state 3 is not accessible.
+        assert_eq!(fst.num_states(), 3);
+        assert_eq!(mapping.len(), 3);
+        assert_eq!(mapping.get(&1), None);
+        assert_eq!(mapping.get(&3), None);
+
+        let old_state = 2;
+        let new_state = mapping[&old_state];
+        assert_eq!(new_state, 1);
+        assert_eq!(
+            fst.get_trs(new_state)?.len(),
+            fst_ref.get_trs(old_state)?.len()
+        );
+        assert_eq!(fst.final_weight(mapping[&4])?, Some(TropicalWeight::one()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_num_accessible_coaccessible() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(5);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(0, Tr::new(2, 2, 1.0, 2))?;
+        fst.add_tr(2, Tr::new(3, 3, 1.0, 4))?;
+        fst.add_tr(3, Tr::new(4, 4, 1.0, 4))?;
+        fst.set_final(4, TropicalWeight::one())?;
+
+        // State 3 is not accessible and state 1 is not coaccessible.
+        assert_eq!(num_accessible(&fst), 4);
+        assert_eq!(num_coaccessible(&fst), 4);
+        assert_eq!(fst.num_states(), 5);
+
+        connect(&mut fst)?;
+        assert_eq!(num_accessible(&fst), 3);
+        assert_eq!(num_coaccessible(&fst), 3);
+        assert_eq!(fst.num_states(), 3);
+        Ok(())
+    }
 }
@@ -0,0 +1,176 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use crate::fst_traits::MutableFst;
+use crate::semirings::WeightQuantize;
+use crate::{Label, StateId, Trs};
+
+/// Final weight and trs of a state, with their weights quantized. The trs are sorted so that
+/// the signature doesn't depend on their order.
+#[derive(PartialEq, Eq, Hash)]
+struct StateSignature<W: WeightQuantize> {
+    final_weight: Option<W>,
+    trs: Vec<(Label, Label, StateId, W)>,
+}
+
+fn state_signature<W, F>(fst: &F, state: StateId, delta: f32) -> Result<StateSignature<W>>
+where
+    W: WeightQuantize,
+    F: MutableFst<W>,
+{
+    let final_weight = match unsafe { fst.final_weight_unchecked(state) } {
+        Some(w) => Some(w.quantize(delta)?),
+        None => None,
+    };
+    let mut trs = unsafe { fst.get_trs_unchecked(state) }
+        .trs()
+        .iter()
+        .map(|tr| {
+            Ok((
+                tr.ilabel,
+                tr.olabel,
+                tr.nextstate,
+                tr.weight.quantize(delta)?,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    trs.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    Ok(StateSignature { final_weight, trs })
+}
+
+/// Merges the states having the same final weight and the same trs once their weights are
+/// quantized with `delta`. The incoming trs of the merged states are redirected to the state
+/// with the lowest id, whose weights are kept unchanged.
+///
+/// Merging states can make their predecessors identical, so the merge is repeated until no more
+/// states can be merged. This is a cheap way to reduce an FST with float weights before
+/// minimizing it.
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::algorithms::dedup_states;
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::fst_traits::{ExpandedFst, MutableFst};
+/// # use rustfst::semirings::TropicalWeight;
+/// # use rustfst::Tr;
+/// # fn main() -> Result<()> {
+/// let mut fst = VectorFst::<TropicalWeight>::new();
+/// fst.add_states(3);
+/// fst.set_start(0)?;
+/// fst.add_tr(0, Tr::new(1, 1, 0.5, 1))?;
+/// fst.add_tr(0, Tr::new(2, 2, 0.5, 2))?;
+/// fst.set_final(1, 0.25)?;
+/// fst.set_final(2, 0.2501)?;
+///
+/// dedup_states(&mut fst, 0.01)?;
+/// assert_eq!(fst.num_states(), 2);
+/// # Ok(())
+/// # }
+/// ```
+pub fn dedup_states<W, F>(fst: &mut F, delta: f32) -> Result<()>
+where
+    W: WeightQuantize,
+    F: MutableFst<W>,
+{
+    loop {
+        let mut representatives = HashMap::new();
+        let mut mapping: Vec<StateId> = fst.states_range().collect();
+        let mut merged_states = vec![];
+        for state in fst.states_range() {
+            match representatives.entry(state_signature(fst, state, delta)?) {
+                Entry::Occupied(e) => {
+                    mapping[state as usize] = *e.get();
+                    merged_states.push(state);
+                }
+                Entry::Vacant(e) => {
+                    e.insert(state);
+                }
+            }
+        }
+        if merged_states.is_empty() {
+            return Ok(());
+        }
+
+        for state in fst.states_range() {
+            let mut it_tr = fst.tr_iter_mut(state)?;
+            for idx in 0..it_tr.len() {
+                let nextstate = it_tr[idx].nextstate;
+                let new_nextstate = mapping[nextstate as usize];
+                if new_nextstate != nextstate {
+                    unsafe { it_tr.set_nextstate_unchecked(idx, new_nextstate) };
+                }
+            }
+        }
+        if let Some(start) = fst.start() {
+            fst.set_start(mapping[start as usize])?;
+        }
+        fst.del_states(merged_states)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::ExpandedFst;
+    use crate::semirings::TropicalWeight;
+    use crate::Tr;
+
+    #[test]
+    fn test_dedup_states_quantized_weights() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(3);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(0, Tr::new(2, 2, 2.0, 2))?;
+        fst.set_final(1, 0.0)?;
+        fst.set_final(2, 1e-9)?;
+
+        let mut fst_not_merged = fst.clone();
+        dedup_states(&mut fst_not_merged, 1e-12)?;
+        assert_eq!(fst_not_merged, fst);
+
+        dedup_states(&mut fst, 1e-6)?;
+
+        let mut fst_ref = VectorFst::<TropicalWeight>::new();
+        fst_ref.add_states(2);
+        fst_ref.set_start(0)?;
+        fst_ref.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst_ref.add_tr(0, Tr::new(2, 2, 2.0, 1))?;
+        fst_ref.set_final(1, 0.0)?;
+        assert_eq!(fst, fst_ref);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dedup_states_merges_predecessors() -> Result<()> {
+        // States 3 and 4 are identical. Once merged, states 1 and 2 become identical.
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(5);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(0, Tr::new(2, 2, 1.0, 2))?;
+        fst.add_tr(1, Tr::new(3, 3, 0.5, 3))?;
+        fst.add_tr(2, Tr::new(3, 3, 0.5, 4))?;
+        fst.set_final(3, 0.25)?;
+        fst.set_final(4, 0.25)?;
+
+        dedup_states(&mut fst, 1e-6)?;
+
+        let mut fst_ref = VectorFst::<TropicalWeight>::new();
+        fst_ref.add_states(3);
+        fst_ref.set_start(0)?;
+        fst_ref.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst_ref.add_tr(0, Tr::new(2, 2, 1.0, 1))?;
+        fst_ref.add_tr(1, Tr::new(3, 3, 0.5, 2))?;
+        fst_ref.set_final(2, 0.25)?;
+        assert_eq!(fst, fst_ref);
+        assert_eq!(fst.num_states(), 3);
+        Ok(())
+    }
+}
@@ -152,10 +152,10 @@ where
     B: Borrow<F> + Debug,
     BT: Borrow<[W]> + PartialEq + Debug,
 {
-    pub fn new(fst: B, in_dist: Option<BT>, delta: f32) -> Result<Self> {
+    pub fn new(fst: B, in_dist: Option<BT>, delta: f32, max_residuals: usize) -> Result<Self> {
         let isymt = fst.borrow().input_symbols().cloned();
         let osymt = fst.borrow().output_symbols().cloned();
-        let fst_op = DeterminizeFsaOp::new(fst, in_dist, delta)?;
+        let fst_op = DeterminizeFsaOp::new(fst, in_dist, delta, max_residuals)?;
         let fst_cache = SimpleHashMapCache::default();
         let lazy_fst = LazyFst::from_op_and_cache(fst_op, fst_cache, isymt, osymt);
         Ok(DeterminizeFsa(lazy_fst, PhantomData))
@@ -132,13 +132,13 @@ where
     B: Borrow<F> + Debug,
     BT: Borrow<[W]> + Debug + PartialEq,
 {
-    pub fn new(fst: B, in_dist: Option<BT>, delta: f32) -> Result<Self> {
+    pub fn new(fst: B, in_dist: Option<BT>, delta: f32, max_residuals: usize) -> Result<Self> {
         if !fst.borrow().properties().contains(FstProperties::ACCEPTOR) {
             bail!("DeterminizeFsaImpl : expected acceptor as argument");
         }
         Ok(Self {
             fst,
-            state_table: DeterminizeStateTable::new(in_dist),
+            state_table: DeterminizeStateTable::new(in_dist, max_residuals),
             delta,
             ghost: PhantomData,
         })
@@ -13,12 +13,12 @@ use crate::algorithms::weight_convert;
 use crate::algorithms::weight_converters::{FromGallicConverter, ToGallicConverter};
 use crate::fst_impls::VectorFst;
 use crate::fst_properties::mutable_properties::determinize_properties;
-use crate::fst_properties::FstProperties;
+use crate::fst_properties::{compute_fst_properties, FstProperties};
 use crate::fst_traits::{AllocableFst, ExpandedFst, Fst, MutableFst};
-use crate::semirings::SemiringProperties;
 use crate::semirings::{
     GallicWeight, GallicWeightMin, GallicWeightRestrict, WeaklyDivisibleSemiring, WeightQuantize,
 };
+use crate::semirings::{Semiring, SemiringProperties};
 use crate::{EPS_LABEL, KDELTA};
 
 pub fn determinize_with_distance<W, F1, F2>(
@@ -34,11 +34,17 @@ where
     if !W::properties().contains(SemiringProperties::LEFT_SEMIRING) {
         bail!("determinize_fsa : weight must be left distributive")
     }
-    let fst = DeterminizeFsa::<_, F1, DefaultCommonDivisor, _, _>::new(ifst, Some(in_dist), delta)?;
+    let max_residuals = DeterminizeConfig::default().max_residuals;
+    let fst = DeterminizeFsa::<_, F1, DefaultCommonDivisor, _, _>::new(
+        ifst,
+        Some(in_dist),
+        delta,
+        max_residuals,
+    )?;
     fst.compute_with_distance()
 }
 
-pub fn determinize_fsa<W, F1, F2, CD>(fst_in: &F1, delta: f32) -> Result<F2>
+pub fn determinize_fsa<W, F1, F2, CD>(fst_in: &F1, delta: f32, max_residuals: usize) -> Result<F2>
 where
     W: WeaklyDivisibleSemiring + WeightQuantize,
     F1: Fst<W>,
@@ -48,11 +54,17 @@ where
     if !W::properties().contains(SemiringProperties::LEFT_SEMIRING) {
         bail!("determinize_fsa : weight must be left distributive")
     }
-    let det_fsa: DeterminizeFsa<W, F1, CD, _, Vec<W>> = DeterminizeFsa::new(fst_in, None, delta)?;
+    let det_fsa: DeterminizeFsa<W, F1, CD, _, Vec<W>> =
+        DeterminizeFsa::new(fst_in, None, delta, max_residuals)?;
     det_fsa.compute()
 }
 
-pub fn determinize_fst<W, F1, F2>(fst_in: &F1, det_type: DeterminizeType, delta: f32) -> Result<F2>
+pub fn determinize_fst<W, F1, F2>(
+    fst_in: &F1,
+    det_type: DeterminizeType,
+    delta: f32,
+    max_residuals: usize,
+) -> Result<F2>
 where
     W: WeaklyDivisibleSemiring + WeightQuantize + 'static,
     F1: ExpandedFst<W>,
@@ -80,7 +92,11 @@ where
             let fsa: VectorFst<GallicWeightMin<W>> =
                 weight_convert(fst_in.borrow(), &mut to_gallic)?;
             let determinized_fsa: VectorFst<GallicWeightMin<W>> =
-                determinize_fsa::<_, VectorFst<_>, _, GallicCommonDivisor>(&fsa, delta)?;
+                determinize_fsa::<_, VectorFst<_>, _, GallicCommonDivisor>(
+                    &fsa,
+                    delta,
+                    max_residuals,
+                )?;
             let factored_determinized_fsa: VectorFst<GallicWeightMin<W>> =
                 factor_weight::<_, VectorFst<GallicWeightMin<W>>, _, _, GallicFactorMin<W>>(
                     &determinized_fsa,
@@ -92,7 +108,11 @@ where
             let fsa: VectorFst<GallicWeightRestrict<W>> =
                 weight_convert(fst_in.borrow(), &mut to_gallic)?;
             let determinized_fsa: VectorFst<GallicWeightRestrict<W>> =
-                determinize_fsa::<_, VectorFst<_>, _, GallicCommonDivisor>(&fsa, delta)?;
+                determinize_fsa::<_, VectorFst<_>, _, GallicCommonDivisor>(
+                    &fsa,
+                    delta,
+                    max_residuals,
+                )?;
             let factored_determinized_fsa: VectorFst<GallicWeightRestrict<W>> =
                 factor_weight::<
                     _,
@@ -106,7 +126,11 @@ where
         DeterminizeType::DeterminizeNonFunctional => {
             let fsa: VectorFst<GallicWeight<W>> = weight_convert(fst_in.borrow(), &mut to_gallic)?;
             let determinized_fsa: VectorFst<GallicWeight<W>> =
-                determinize_fsa::<_, VectorFst<_>, _, GallicCommonDivisor>(&fsa, delta)?;
+                determinize_fsa::<_, VectorFst<_>, _, GallicCommonDivisor>(
+                    &fsa,
+                    delta,
+                    max_residuals,
+                )?;
             let factored_determinized_fsa: VectorFst<GallicWeight<W>> =
                 factor_weight::<_, VectorFst<GallicWeight<W>>, _, _, GallicFactor<W>>(
                     &determinized_fsa,
@@ -121,11 +145,16 @@ where
 pub struct DeterminizeConfig {
     delta: f32,
     det_type: DeterminizeType,
+    max_residuals: usize,
 }
 
 impl DeterminizeConfig {
     pub fn new(delta: f32, det_type: DeterminizeType) -> Self {
-        Self { delta, det_type }
+        Self {
+            delta,
+            det_type,
+            ..Self::default()
+        }
     }
 
     pub fn with_delta(self, delta: f32) -> Self {
@@ -135,6 +164,17 @@ impl DeterminizeConfig {
     pub fn with_det_type(self, det_type: DeterminizeType) -> Self {
         Self { det_type, ..self }
     }
+
+    /// Sets the maximum number of states of the determinized FST corresponding to the same
+    /// subset of input states with different residual weights. The residual weights of an FST
+    /// that can't be determinized keep growing : the determinization fails once this number
+    /// is exceeded instead of running forever. Defaults to 10000.
+    pub fn with_max_residuals(self, max_residuals: usize) -> Self {
+        Self {
+            max_residuals,
+            ..self
+        }
+    }
 }
 
 impl Default for DeterminizeConfig {
@@ -142,6 +182,7 @@ impl Default for DeterminizeConfig {
         Self {
             delta: KDELTA,
             det_type: DeterminizeType::DeterminizeFunctional,
+            max_residuals: 10_000,
         }
     }
 }
@@ -159,6 +200,11 @@ where
 /// state has two transitions with the same input label. For this algorithm,
 /// epsilon transitions are treated as regular symbols.
 ///
+/// Not all weighted FSTs can be determinized, e.g. when two paths with the same labels go
+/// through cycles with different weights. `FstError::NonDeterministic` is returned in that
+/// case, once more than `max_residuals` states of the result correspond to the same subset of
+/// input states (see `DeterminizeConfig::with_max_residuals`).
+///
 /// # Example
 ///
 /// ## Input
@@ -177,11 +223,12 @@ where
 {
     let delta = config.delta;
     let det_type = config.det_type;
+    let max_residuals = config.max_residuals;
     let iprops = fst_in.borrow().properties();
     let mut fst_res: F2 = if iprops.contains(FstProperties::ACCEPTOR) {
-        determinize_fsa::<_, F1, _, DefaultCommonDivisor>(fst_in, delta)?
+        determinize_fsa::<_, F1, _, DefaultCommonDivisor>(fst_in, delta, max_residuals)?
     } else {
-        determinize_fst(fst_in, det_type, delta)?
+        determinize_fst(fst_in, det_type, delta, max_residuals)?
     };
 
     let distinct_psubsequential_labels = if det_type == DeterminizeType::DeterminizeNonFunctional {
@@ -199,12 +246,27 @@ where
     Ok(fst_res)
 }
 
+/// Returns whether the FST is deterministic i.e no state has two leaving
+/// transitions with the same input label. Epsilon transitions are treated as
+/// regular symbols. The stored `FstProperties` are used when they are known.
+pub fn is_deterministic<W: Semiring, F: ExpandedFst<W>>(fst: &F) -> Result<bool> {
+    let mut known = FstProperties::empty();
+    let props = compute_fst_properties(
+        fst,
+        FstProperties::I_DETERMINISTIC | FstProperties::NOT_I_DETERMINISTIC,
+        &mut known,
+        true,
+    )?;
+    Ok(props.contains(FstProperties::I_DETERMINISTIC))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::fst_impls::VectorFst;
+    use crate::fst_traits::PathsIterator;
     use crate::semirings::TropicalWeight;
     use crate::tr::Tr;
-    use crate::Semiring;
+    use crate::{FstError, FstPath};
 
     use super::*;
 
@@ -269,4 +331,69 @@ mod tests {
         assert_eq!(determinized_fst, ref_fst);
         Ok(())
     }
+
+    #[test]
+    fn test_determinize_ambiguous_acceptor() -> Result<()> {
+        let mut input_fst = VectorFst::<TropicalWeight>::new();
+        input_fst.add_states(4);
+        input_fst.set_start(0)?;
+        input_fst.set_final(3, TropicalWeight::one())?;
+
+        input_fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        input_fst.add_tr(0, Tr::new(1, 1, 2.0, 2))?;
+        input_fst.add_tr(1, Tr::new(2, 2, 3.0, 3))?;
+        input_fst.add_tr(2, Tr::new(3, 3, 1.0, 3))?;
+
+        assert!(!is_deterministic(&input_fst)?);
+
+        let determinized_fst: VectorFst<TropicalWeight> = determinize(&input_fst)?;
+
+        assert!(is_deterministic(&determinized_fst)?);
+
+        let paths: Vec<_> = determinized_fst.paths_iter().collect();
+        let ref_paths = vec![
+            FstPath::new(vec![1, 2], vec![1, 2], TropicalWeight::new(4.0)),
+            FstPath::new(vec![1, 3], vec![1, 3], TropicalWeight::new(3.0)),
+        ];
+        assert_eq!(paths.len(), ref_paths.len());
+        for path in ref_paths {
+            assert!(paths.contains(&path), "Missing path {:?}", path);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_determinize_non_determinizable_acceptor() -> Result<()> {
+        // The strings a b^n go through the self-loops of the states 1 and 2 whose weights
+        // differ : the residual weight of the state 2 grows with n.
+        let mut input_fst = VectorFst::<TropicalWeight>::new();
+        input_fst.add_states(3);
+        input_fst.set_start(0)?;
+        input_fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        input_fst.add_tr(0, Tr::new(1, 1, 2.0, 2))?;
+        input_fst.add_tr(1, Tr::new(2, 2, 1.0, 1))?;
+        input_fst.add_tr(2, Tr::new(2, 2, 2.0, 2))?;
+        input_fst.set_final(1, TropicalWeight::one())?;
+        input_fst.set_final(2, TropicalWeight::one())?;
+
+        let res: Result<VectorFst<TropicalWeight>> = determinize(&input_fst);
+        let err = res.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<FstError>(),
+            Some(&FstError::NonDeterministic)
+        );
+
+        let config = DeterminizeConfig::default().with_max_residuals(10);
+        let res: Result<VectorFst<TropicalWeight>> = determinize_with_config(&input_fst, config);
+        assert!(res.is_err());
+
+        // With equal weights on the self-loops, the residual weights no longer grow.
+        input_fst
+            .tr_iter_mut(2)?
+            .set_weight(0, TropicalWeight::new(1.0))?;
+        let determinized_fst: VectorFst<TropicalWeight> =
+            determinize_with_config(&input_fst, config)?;
+        assert!(is_deterministic(&determinized_fst)?);
+        Ok(())
+    }
 }
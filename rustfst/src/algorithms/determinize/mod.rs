@@ -1,7 +1,8 @@
 pub(self) use determinize_fsa::DeterminizeFsa;
 pub(self) use determinize_fsa_op::DeterminizeFsaOp;
 pub use determinize_static::{
-    determinize, determinize_with_config, determinize_with_distance, DeterminizeConfig,
+    determinize, determinize_with_config, determinize_with_distance, is_deterministic,
+    DeterminizeConfig,
 };
 pub(self) use divisors::{DefaultCommonDivisor, GallicCommonDivisor};
 pub(self) use element::{DeterminizeElement, DeterminizeStateTuple, DeterminizeTr, WeightedSubset};
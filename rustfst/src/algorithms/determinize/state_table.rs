@@ -1,12 +1,13 @@
 use std::borrow::Borrow;
+use std::collections::HashMap;
 use std::fmt;
 use std::sync::Mutex;
 
 use bimap::BiHashMap;
 
 use crate::algorithms::determinize::{DeterminizeStateTuple, WeightedSubset};
-use crate::{Semiring, StateId};
-use anyhow::Result;
+use crate::{FstError, Semiring, StateId};
+use anyhow::{Context, Result};
 
 #[derive(Debug, PartialEq)]
 struct InnerDeterminizeStateTable<W: Semiring, B: Borrow<[W]>> {
@@ -15,6 +16,10 @@ struct InnerDeterminizeStateTable<W: Semiring, B: Borrow<[W]>> {
     in_dist: Option<B>,
     // Distance to final DFA states.
     out_dist: Vec<Option<W>>,
+    // Number of tuples sharing the same filter state and subset of states, i.e. only differing
+    // by their residual weights.
+    num_residuals: HashMap<(StateId, Vec<StateId>), usize>,
+    max_residuals: usize,
 }
 
 impl<W: Semiring, B: Borrow<[W]> + PartialEq> InnerDeterminizeStateTable<W, B> {
@@ -52,11 +57,13 @@ impl<W: Semiring, B: Borrow<[W]> + PartialEq> PartialEq for DeterminizeStateTabl
 }
 
 impl<W: Semiring, B: Borrow<[W]>> DeterminizeStateTable<W, B> {
-    pub fn new(in_dist: Option<B>) -> Self {
+    pub fn new(in_dist: Option<B>, max_residuals: usize) -> Self {
         Self(Mutex::new(InnerDeterminizeStateTable {
             in_dist,
             out_dist: vec![],
             table: BiHashMap::new(),
+            num_residuals: HashMap::new(),
+            max_residuals,
         }))
     }
 
@@ -74,9 +81,31 @@ impl<W: Semiring, B: Borrow<[W]>> DeterminizeStateTable<W, B> {
 
 impl<W: Semiring, B: Borrow<[W]> + PartialEq> DeterminizeStateTable<W, B> {
     /// Looks up integer ID from entry. If it doesn't exist and insert
+    ///
+    /// An error is returned when more than `max_residuals` tuples share the same subset of
+    /// states. This happens when the residual weights keep growing, i.e. when the FST can't be
+    /// determinized.
     pub fn find_id_from_ref(&self, tuple: &DeterminizeStateTuple<W>) -> Result<StateId> {
         let mut inner = self.0.lock().unwrap();
         if !inner.table.contains_right(tuple) {
+            let mut states: Vec<_> = tuple.subset.iter().map(|e| e.state).collect();
+            states.sort_unstable();
+            let max_residuals = inner.max_residuals;
+            let num_residuals = inner
+                .num_residuals
+                .entry((tuple.filter_state, states))
+                .or_insert(0);
+            *num_residuals += 1;
+            if *num_residuals > max_residuals {
+                return Err(FstError::NonDeterministic).with_context(|| {
+                    format!(
+                        "Determinize : More than {} states with the same subset of states but \
+                         different residual weights",
+                        max_residuals
+                    )
+                });
+            }
+
             let n = inner.table.len();
             inner.table.insert(n as StateId, tuple.clone());
 
@@ -17,6 +17,12 @@ enum DfsStateColor {
     Black,
 }
 
+/// Callbacks invoked by `dfs_visit` during the traversal of an FST. The methods returning a
+/// boolean stop the traversal when they return false.
+///
+/// The transitions are classified as in a classic DFS : a tree transition discovers a new state,
+/// a back transition reaches a state being visited (and thus closes a cycle) and a forward or
+/// cross transition reaches a state already finished.
 pub trait Visitor<'a, W: Semiring, F: Fst<W>> {
     /// Invoked before DFS visit.
     fn init_visit(&mut self, fst: &'a F);
@@ -95,14 +101,55 @@ impl<W: Semiring, TRS: Trs<W>> OpenFstIterator<W, TRS> {
     }
 }
 
+/// Performs a depth-first traversal of the FST starting from the start state, calling the
+/// methods of the visitor on each state and transition. Only the trs kept by `tr_filter` are
+/// followed, e.g. `EpsilonTrFilter` restricts the traversal to the epsilon trs.
+///
+/// If `access_only` is false, the traversal continues from each state not yet discovered once
+/// all the states accessible from the start state are finished. The visit stops as soon as one
+/// of the methods of the visitor returns false.
+///
+/// As in OpenFst, nothing is visited when the FST has no start state, whatever `access_only`:
+/// only `init_visit` and `finish_visit` are invoked.
 pub fn dfs_visit<'a, W: Semiring, F: ExpandedFst<W>, V: Visitor<'a, W, F>, A: TrFilter<W>>(
     fst: &'a F,
     visitor: &mut V,
     tr_filter: &A,
     access_only: bool,
 ) {
-    visitor.init_visit(fst);
+    dfs_visit_from(fst, visitor, tr_filter, access_only, fst.start())
+}
+
+/// Same as `dfs_visit` with `access_only` set to false, except that the traversal starts from
+/// the first state when the FST has no start state. All the states are thus visited.
+pub(crate) fn dfs_visit_all_states<
+    'a,
+    W: Semiring,
+    F: ExpandedFst<W>,
+    V: Visitor<'a, W, F>,
+    A: TrFilter<W>,
+>(
+    fst: &'a F,
+    visitor: &mut V,
+    tr_filter: &A,
+) {
     let start = match fst.start() {
+        Some(s) => Some(s),
+        None if fst.num_states() > 0 => Some(0),
+        None => None,
+    };
+    dfs_visit_from(fst, visitor, tr_filter, false, start)
+}
+
+fn dfs_visit_from<'a, W: Semiring, F: ExpandedFst<W>, V: Visitor<'a, W, F>, A: TrFilter<W>>(
+    fst: &'a F,
+    visitor: &mut V,
+    tr_filter: &A,
+    access_only: bool,
+    start: Option<StateId>,
+) {
+    visitor.init_visit(fst);
+    let start = match start {
         None => {
             visitor.finish_visit();
             return;
@@ -186,3 +233,173 @@ pub fn dfs_visit<'a, W: Semiring, F: ExpandedFst<W>, V: Visitor<'a, W, F>, A: Tr
     }
     visitor.finish_visit();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anyhow::Result;
+
+    use crate::algorithms::tr_filters::{AnyTrFilter, EpsilonTrFilter};
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::MutableFst;
+    use crate::semirings::TropicalWeight;
+    use crate::EPS_LABEL;
+
+    /// Keeps the trs having at least one non-epsilon label.
+    #[derive(Clone, Debug, PartialEq)]
+    struct NonEpsilonTrFilter {}
+
+    impl<W: Semiring> TrFilter<W> for NonEpsilonTrFilter {
+        fn keep(&self, tr: &Tr<W>) -> bool {
+            tr.ilabel != EPS_LABEL || tr.olabel != EPS_LABEL
+        }
+    }
+
+    /// Records the discovered states.
+    struct DiscoveredStatesVisitor {
+        states: Vec<StateId>,
+    }
+
+    impl<'a, W: Semiring, F: 'a + Fst<W>> Visitor<'a, W, F> for DiscoveredStatesVisitor {
+        fn init_visit(&mut self, _fst: &'a F) {}
+
+        fn init_state(&mut self, s: StateId, _root: StateId) -> bool {
+            self.states.push(s);
+            true
+        }
+
+        fn tree_tr(&mut self, _s: StateId, _tr: &Tr<W>) -> bool {
+            true
+        }
+
+        fn back_tr(&mut self, _s: StateId, _tr: &Tr<W>) -> bool {
+            true
+        }
+
+        fn forward_or_cross_tr(&mut self, _s: StateId, _tr: &Tr<W>) -> bool {
+            true
+        }
+
+        fn finish_state(&mut self, _s: StateId, _parent: Option<StateId>, _tr: Option<&Tr<W>>) {}
+
+        fn finish_visit(&mut self) {}
+    }
+
+    fn discovered_states<A: TrFilter<TropicalWeight>>(
+        fst: &VectorFst<TropicalWeight>,
+        tr_filter: &A,
+    ) -> Vec<StateId> {
+        let mut visitor = DiscoveredStatesVisitor { states: vec![] };
+        dfs_visit(fst, &mut visitor, tr_filter, true);
+        let mut states = visitor.states;
+        states.sort_unstable();
+        states
+    }
+
+    /// Records the order in which the states are finished.
+    struct FinishOrderVisitor {
+        finish: Vec<StateId>,
+        back_trs: Vec<(StateId, StateId)>,
+    }
+
+    impl<'a, W: Semiring, F: 'a + Fst<W>> Visitor<'a, W, F> for FinishOrderVisitor {
+        fn init_visit(&mut self, _fst: &'a F) {}
+
+        fn init_state(&mut self, _s: StateId, _root: StateId) -> bool {
+            true
+        }
+
+        fn tree_tr(&mut self, _s: StateId, _tr: &Tr<W>) -> bool {
+            true
+        }
+
+        fn back_tr(&mut self, s: StateId, tr: &Tr<W>) -> bool {
+            self.back_trs.push((s, tr.nextstate));
+            true
+        }
+
+        fn forward_or_cross_tr(&mut self, _s: StateId, _tr: &Tr<W>) -> bool {
+            true
+        }
+
+        fn finish_state(&mut self, s: StateId, _parent: Option<StateId>, _tr: Option<&Tr<W>>) {
+            self.finish.push(s);
+        }
+
+        fn finish_visit(&mut self) {}
+    }
+
+    #[test]
+    fn test_dfs_visit_finish_order() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(5);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(0, Tr::new(2, 2, 1.0, 2))?;
+        fst.add_tr(1, Tr::new(3, 3, 1.0, 3))?;
+        fst.add_tr(2, Tr::new(4, 4, 1.0, 3))?;
+        fst.add_tr(3, Tr::new(5, 5, 1.0, 1))?;
+        // State 4 isn't accessible.
+        fst.add_tr(4, Tr::new(6, 6, 1.0, 0))?;
+
+        let mut visitor = FinishOrderVisitor {
+            finish: vec![],
+            back_trs: vec![],
+        };
+        dfs_visit(&fst, &mut visitor, &AnyTrFilter {}, true);
+        assert_eq!(visitor.finish, vec![3, 1, 2, 0]);
+        assert_eq!(visitor.back_trs, vec![(3, 1)]);
+
+        let mut visitor = FinishOrderVisitor {
+            finish: vec![],
+            back_trs: vec![],
+        };
+        dfs_visit(&fst, &mut visitor, &AnyTrFilter {}, false);
+        assert_eq!(visitor.finish, vec![3, 1, 2, 0, 4]);
+        assert_eq!(visitor.back_trs, vec![(3, 1)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dfs_visit_tr_filters() -> Result<()> {
+        // States 2 and 4 are only reachable through epsilon trs.
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(5);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(0, Tr::new(EPS_LABEL, EPS_LABEL, 1.0, 2))?;
+        fst.add_tr(1, Tr::new(2, 0, 1.0, 3))?;
+        fst.add_tr(2, Tr::new(3, 3, 1.0, 3))?;
+        fst.add_tr(3, Tr::new(EPS_LABEL, EPS_LABEL, 1.0, 4))?;
+        fst.set_final(4, 1.0)?;
+
+        assert_eq!(
+            discovered_states(&fst, &AnyTrFilter {}),
+            vec![0, 1, 2, 3, 4]
+        );
+        assert_eq!(
+            discovered_states(&fst, &NonEpsilonTrFilter {}),
+            vec![0, 1, 3]
+        );
+        assert_eq!(discovered_states(&fst, &EpsilonTrFilter {}), vec![0, 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dfs_visit_no_start_state() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(3);
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(2, Tr::new(2, 2, 1.0, 0))?;
+
+        let mut visitor = DiscoveredStatesVisitor { states: vec![] };
+        dfs_visit(&fst, &mut visitor, &AnyTrFilter {}, false);
+        assert!(visitor.states.is_empty());
+
+        let mut visitor = DiscoveredStatesVisitor { states: vec![] };
+        dfs_visit_all_states(&fst, &mut visitor, &AnyTrFilter {});
+        assert_eq!(visitor.states, vec![0, 1, 2]);
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+
+use crate::algorithms::complement;
+use crate::algorithms::compose::compose;
+use crate::fst_impls::VectorFst;
+use crate::fst_properties::{compute_fst_properties, FstProperties};
+use crate::fst_traits::{AllocableFst, ExpandedFst, MutableFst};
+use crate::semirings::Semiring;
+use crate::{Trs, EPS_LABEL};
+
+/// This operation computes the difference between two FSTs : the output FST contains the
+/// paths of `fst_1` whose output string is not accepted by `fst_2`, with their weights.
+///
+/// `fst_2` must be an unweighted, epsilon-free and deterministic acceptor. The difference is
+/// computed as the composition of `fst_1` with the complement of `fst_2` over the labels
+/// appearing in both FSTs.
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::algorithms::{difference, union::union};
+/// # use rustfst::fst_traits::PathsIterator;
+/// # use rustfst::utils::acceptor;
+/// # use rustfst::FstPath;
+/// # fn main() -> Result<()> {
+/// let mut fst_1: VectorFst<TropicalWeight> = acceptor(&[1, 2], TropicalWeight::new(1.0));
+/// let fst_b: VectorFst<TropicalWeight> = acceptor(&[2], TropicalWeight::new(2.0));
+/// union(&mut fst_1, &fst_b)?;
+///
+/// let fst_2: VectorFst<TropicalWeight> = acceptor(&[1, 2], TropicalWeight::one());
+///
+/// let fst_res: VectorFst<TropicalWeight> = difference(&fst_1, &fst_2)?;
+/// let paths: Vec<_> = fst_res.paths_iter().collect();
+/// assert_eq!(paths, vec![FstPath::new(vec![2], vec![2], TropicalWeight::new(2.0))]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn difference<W, F1, F2, F3>(fst_1: &F1, fst_2: &F2) -> Result<F3>
+where
+    W: Semiring,
+    F1: ExpandedFst<W>,
+    F2: ExpandedFst<W>,
+    F3: MutableFst<W> + AllocableFst<W>,
+{
+    let mut known = FstProperties::empty();
+    let mask = FstProperties::UNWEIGHTED | FstProperties::WEIGHTED;
+    if !compute_fst_properties(fst_2, mask, &mut known, true)?.contains(FstProperties::UNWEIGHTED) {
+        bail!("Difference : fst_2 must be unweighted")
+    }
+
+    let mut alphabet = BTreeSet::new();
+    for state in fst_1.states_range() {
+        alphabet.extend(fst_1.get_trs(state)?.trs().iter().map(|tr| tr.olabel));
+    }
+    for state in fst_2.states_range() {
+        alphabet.extend(fst_2.get_trs(state)?.trs().iter().map(|tr| tr.ilabel));
+    }
+    alphabet.remove(&EPS_LABEL);
+
+    let complement_fst: VectorFst<W> = complement(fst_2, alphabet)?;
+    compose::<_, F1, VectorFst<W>, F3, _, _>(fst_1, &complement_fst)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    use crate::algorithms::union::union;
+    use crate::fst_traits::PathsIterator;
+    use crate::semirings::TropicalWeight;
+    use crate::utils::acceptor;
+    use crate::{FstPath, Label, Tr};
+
+    fn path(labels: &[Label], weight: f32) -> FstPath<TropicalWeight> {
+        FstPath::new(
+            labels.to_vec(),
+            labels.to_vec(),
+            TropicalWeight::new(weight),
+        )
+    }
+
+    /// Acceptor of the strings "a", "ab", "b" and "bb" over the alphabet {a, b}.
+    fn strings_fst() -> Result<VectorFst<TropicalWeight>> {
+        let mut fst: VectorFst<TropicalWeight> = acceptor(&[1], TropicalWeight::new(1.0));
+        for (labels, weight) in &[(vec![1, 2], 2.0), (vec![2], 3.0), (vec![2, 2], 4.0)] {
+            let fst_string: VectorFst<_> = acceptor(labels, TropicalWeight::new(*weight));
+            union(&mut fst, &fst_string)?;
+        }
+        Ok(fst)
+    }
+
+    #[test]
+    fn test_difference() -> Result<()> {
+        let fst_1 = strings_fst()?;
+
+        // Removes "ab" and "bb".
+        let mut fst_2: VectorFst<TropicalWeight> = acceptor(&[1, 2], TropicalWeight::one());
+        fst_2.add_tr(0, Tr::new(2, 2, TropicalWeight::one(), 1))?;
+
+        let fst_res: VectorFst<TropicalWeight> = difference(&fst_1, &fst_2)?;
+
+        let paths: HashSet<_> = fst_res.paths_iter().collect();
+        let paths_ref: HashSet<_> = vec![path(&[1], 1.0), path(&[2], 3.0)].into_iter().collect();
+        assert_eq!(paths, paths_ref);
+        Ok(())
+    }
+
+    #[test]
+    fn test_difference_with_label_not_in_fst_1() -> Result<()> {
+        let fst_1 = strings_fst()?;
+        let fst_2: VectorFst<TropicalWeight> = acceptor(&[3], TropicalWeight::one());
+
+        let fst_res: VectorFst<TropicalWeight> = difference(&fst_1, &fst_2)?;
+
+        let paths: HashSet<_> = fst_res.paths_iter().collect();
+        let paths_ref: HashSet<_> = fst_1.paths_iter().collect();
+        assert_eq!(paths, paths_ref);
+        Ok(())
+    }
+
+    #[test]
+    fn test_difference_weighted_fst_2() -> Result<()> {
+        let fst_1 = strings_fst()?;
+        let fst_2: VectorFst<TropicalWeight> = acceptor(&[1], TropicalWeight::new(1.0));
+        let res: Result<VectorFst<TropicalWeight>> = difference(&fst_1, &fst_2);
+        assert!(res.is_err());
+        Ok(())
+    }
+}
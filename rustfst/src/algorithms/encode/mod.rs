@@ -8,3 +8,55 @@ mod decode_static;
 mod encode_static;
 mod encode_type;
 mod table;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    use anyhow::Result;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_properties::FstProperties;
+    use crate::fst_traits::{MutableFst, PathsIterator};
+    use crate::semirings::TropicalWeight;
+    use crate::{FstPath, Tr};
+
+    fn transducer_fst() -> Result<VectorFst<TropicalWeight>> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(3);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 2, 1.0, 1))?;
+        fst.add_tr(0, Tr::new(1, 3, 2.0, 1))?;
+        fst.add_tr(1, Tr::new(2, 2, 1.0, 2))?;
+        fst.add_tr(1, Tr::new(1, 2, 1.0, 2))?;
+        fst.set_final(1, 3.0)?;
+        fst.set_final(2, 0.5)?;
+        Ok(fst)
+    }
+
+    #[test]
+    fn test_encode_decode_round_trip() -> Result<()> {
+        for encode_type in &[
+            EncodeType::EncodeLabels,
+            EncodeType::EncodeWeights,
+            EncodeType::EncodeWeightsAndLabels,
+        ] {
+            let fst_ref = transducer_fst()?;
+            let mut fst = fst_ref.clone();
+
+            let table = encode(&mut fst, *encode_type)?;
+            if encode_type.encode_labels() {
+                let props = fst.compute_and_update_properties(FstProperties::ACCEPTOR)?;
+                assert!(props.contains(FstProperties::ACCEPTOR));
+            }
+            decode(&mut fst, table)?;
+
+            let paths: HashSet<FstPath<TropicalWeight>> = fst.paths_iter().collect();
+            let paths_ref: HashSet<FstPath<TropicalWeight>> = fst_ref.paths_iter().collect();
+            assert_eq!(paths, paths_ref);
+        }
+        Ok(())
+    }
+}
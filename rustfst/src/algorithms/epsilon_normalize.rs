@@ -0,0 +1,168 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::algorithms::factor_weight::factor_iterators::GallicFactorLeft;
+use crate::algorithms::factor_weight::{factor_weight, FactorWeightOptions, FactorWeightType};
+use crate::algorithms::rm_epsilon::rm_epsilon;
+use crate::algorithms::weight_converters::{FromGallicConverter, ToGallicConverter};
+use crate::algorithms::{invert, weight_convert};
+use crate::fst_impls::VectorFst;
+use crate::fst_traits::{AllocableFst, ExpandedFst, MutableFst};
+use crate::semirings::{GallicWeightLeft, WeightQuantize};
+use crate::{EPS_LABEL, KDELTA};
+
+/// Defines on which side the epsilons are normalized.
+#[derive(Clone, Debug, Copy, PartialEq)]
+pub enum EpsNormalizeType {
+    /// On each path, the input epsilons follow all the non-epsilon input labels.
+    EpsNormInput,
+    /// On each path, the output epsilons follow all the non-epsilon output labels.
+    EpsNormOutput,
+}
+
+/// This operation creates an equivalent FST that is epsilon-normalized. An acceptor is
+/// epsilon-normalized if it is epsilon-removed. A transducer is input epsilon-normalized if,
+/// in addition, along any path all the trs with an epsilon input label follow the trs with a
+/// non-epsilon input label. Output epsilon-normalized is defined similarly.
+///
+/// The FST is converted to the gallic semiring, epsilon-removed, and the gallic weights are
+/// factored back into trs. The symbol tables are kept.
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::fst_traits::{CoreFst, MutableFst};
+/// # use rustfst::algorithms::{epsilon_normalize, EpsNormalizeType};
+/// # use rustfst::{Tr, Trs, EPS_LABEL};
+/// # fn main() -> Result<()> {
+/// let mut fst = VectorFst::<TropicalWeight>::new();
+/// fst.add_states(3);
+/// fst.set_start(0)?;
+/// fst.add_tr(0, Tr::new(EPS_LABEL, 1, 0.5, 1))?;
+/// fst.add_tr(1, Tr::new(2, 3, 1.0, 2))?;
+/// fst.set_final(2, TropicalWeight::one())?;
+///
+/// epsilon_normalize(&mut fst, EpsNormalizeType::EpsNormInput)?;
+///
+/// assert_eq!(fst.get_trs(0)?.trs(), &[Tr::new(2, 1, 1.5, 1)]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn epsilon_normalize<W, F>(fst: &mut F, eps_norm_type: EpsNormalizeType) -> Result<()>
+where
+    W: WeightQuantize,
+    F: ExpandedFst<W> + MutableFst<W> + AllocableFst<W>,
+{
+    let isymt = fst.input_symbols().map(Arc::clone);
+    let osymt = fst.output_symbols().map(Arc::clone);
+
+    if eps_norm_type == EpsNormalizeType::EpsNormOutput {
+        invert(fst);
+    }
+
+    let mut gfst: VectorFst<GallicWeightLeft<W>> =
+        weight_convert(&*fst, &mut ToGallicConverter {})?;
+    rm_epsilon(&mut gfst)?;
+
+    let factor_opts = FactorWeightOptions {
+        delta: KDELTA,
+        mode: FactorWeightType::FACTOR_FINAL_WEIGHTS | FactorWeightType::FACTOR_ARC_WEIGHTS,
+        final_ilabel: EPS_LABEL,
+        final_olabel: EPS_LABEL,
+        increment_final_ilabel: false,
+        increment_final_olabel: false,
+    };
+    let fwfst: VectorFst<GallicWeightLeft<W>> =
+        factor_weight::<_, VectorFst<_>, _, _, GallicFactorLeft<W>>(&gfst, factor_opts)?;
+
+    let mut from_gallic = FromGallicConverter {
+        superfinal_label: EPS_LABEL,
+    };
+    let mut ofst: F = weight_convert(&fwfst, &mut from_gallic)?;
+
+    if eps_norm_type == EpsNormalizeType::EpsNormOutput {
+        invert(&mut ofst);
+    }
+
+    match isymt {
+        Some(symt) => ofst.set_input_symbols(symt),
+        None => {
+            ofst.take_input_symbols();
+        }
+    };
+    match osymt {
+        Some(symt) => ofst.set_output_symbols(symt),
+        None => {
+            ofst.take_output_symbols();
+        }
+    };
+    *fst = ofst;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::algorithms::isomorphic;
+    use crate::semirings::{Semiring, TropicalWeight};
+    use crate::{SymbolTable, Tr};
+
+    #[test]
+    fn test_epsilon_normalize_input() -> Result<()> {
+        // 0 --<eps>:1/0.5--> 1 --2:<eps>/1--> 2 --3:4/2--> 3
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(4);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(EPS_LABEL, 1, 0.5, 1))?;
+        fst.add_tr(1, Tr::new(2, EPS_LABEL, 1.0, 2))?;
+        fst.add_tr(2, Tr::new(3, 4, 2.0, 3))?;
+        fst.set_final(3, TropicalWeight::one())?;
+
+        let mut symt = SymbolTable::new();
+        symt.add_symbols(vec!["a", "b", "c", "d"]);
+        let symt = Arc::new(symt);
+        fst.set_input_symbols(Arc::clone(&symt));
+
+        epsilon_normalize(&mut fst, EpsNormalizeType::EpsNormInput)?;
+
+        let mut fst_ref = VectorFst::<TropicalWeight>::new();
+        fst_ref.add_states(3);
+        fst_ref.set_start(0)?;
+        fst_ref.add_tr(0, Tr::new(2, 1, 1.5, 1))?;
+        fst_ref.add_tr(1, Tr::new(3, 4, 2.0, 2))?;
+        fst_ref.set_final(2, TropicalWeight::one())?;
+
+        assert!(isomorphic(&fst, &fst_ref)?);
+        assert_eq!(fst.input_symbols(), Some(&symt));
+        assert_eq!(fst.output_symbols(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_epsilon_normalize_output() -> Result<()> {
+        // 0 --1:<eps>/0.5--> 1 --2:3/1--> 2
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(3);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, EPS_LABEL, 0.5, 1))?;
+        fst.add_tr(1, Tr::new(2, 3, 1.0, 2))?;
+        fst.set_final(2, TropicalWeight::one())?;
+
+        epsilon_normalize(&mut fst, EpsNormalizeType::EpsNormOutput)?;
+
+        // The output epsilon is moved after the non-epsilon output label.
+        let mut fst_ref = VectorFst::<TropicalWeight>::new();
+        fst_ref.add_states(3);
+        fst_ref.set_start(0)?;
+        fst_ref.add_tr(0, Tr::new(1, 3, 1.5, 1))?;
+        fst_ref.add_tr(1, Tr::new(2, EPS_LABEL, TropicalWeight::one(), 2))?;
+        fst_ref.set_final(2, TropicalWeight::one())?;
+
+        assert!(isomorphic(&fst, &fst_ref)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,299 @@
+use std::collections::{HashMap, VecDeque};
+
+use anyhow::Result;
+
+use crate::algorithms::shortest_distance;
+use crate::fst_properties::{compute_fst_properties, FstProperties};
+use crate::fst_traits::ExpandedFst;
+use crate::semirings::{DivideType, Semiring, WeaklyDivisibleSemiring};
+use crate::{Label, StateId, Trs, KDELTA};
+
+/// Disjoint-set forest used to maintain the equivalence classes of the states.
+struct UnionFind {
+    parent: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+        }
+    }
+
+    fn find(&mut self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            self.parent[x] = self.parent[self.parent[x]];
+            x = self.parent[x];
+        }
+        x
+    }
+
+    fn union(&mut self, x: usize, y: usize) {
+        let root_x = self.find(x);
+        let root_y = self.find(y);
+        if root_x != root_y {
+            self.parent[root_x] = root_y;
+        }
+    }
+}
+
+fn check_properties<W: WeaklyDivisibleSemiring, F: ExpandedFst<W>>(
+    fst: &F,
+    name: &str,
+) -> Result<()> {
+    let props =
+        FstProperties::ACCEPTOR | FstProperties::NO_EPSILONS | FstProperties::I_DETERMINISTIC;
+    let mask = props
+        | FstProperties::NOT_ACCEPTOR
+        | FstProperties::EPSILONS
+        | FstProperties::NOT_I_DETERMINISTIC;
+    let mut known = FstProperties::empty();
+    let computed_props = compute_fst_properties(fst, mask, &mut known, true)?;
+    if !computed_props.contains(props) {
+        bail!(
+            "Equivalent : {} is not an acceptor, not input deterministic or has epsilons. Consider calling `rm_epsilon` and `determinize` first.",
+            name
+        )
+    }
+    Ok(())
+}
+
+/// Weights of the trs leaving `state` normalized by the potentials, indexed by label.
+/// Trs leading to states that can't reach a final state are ignored.
+fn normalized_trs<W: WeaklyDivisibleSemiring, F: ExpandedFst<W>>(
+    fst: &F,
+    state: StateId,
+    potentials: &[W],
+) -> Result<HashMap<Label, (W, StateId)>> {
+    let zero = W::zero();
+    let d_s = potentials.get(state as usize).unwrap_or(&zero);
+    let mut res = HashMap::new();
+    for tr in unsafe { fst.get_trs_unchecked(state).trs() } {
+        let d_ns = potentials.get(tr.nextstate as usize).unwrap_or(&zero);
+        if d_ns.is_zero() {
+            continue;
+        }
+        let weight = tr.weight.times(d_ns)?.divide(d_s, DivideType::DivideLeft)?;
+        res.insert(tr.ilabel, (weight, tr.nextstate));
+    }
+    Ok(res)
+}
+
+/// Weights are compared exactly first, as `approx_equal` can't compare infinite float weights
+/// (e.g the `zero` of the tropical semiring).
+fn weights_equal<W: Semiring>(w1: &W, w2: &W) -> bool {
+    w1 == w2 || w1.approx_equal(w2, KDELTA)
+}
+
+fn normalized_final_weight<W: WeaklyDivisibleSemiring, F: ExpandedFst<W>>(
+    fst: &F,
+    state: StateId,
+    potentials: &[W],
+) -> Result<W> {
+    match unsafe { fst.final_weight_unchecked(state) } {
+        Some(final_weight) => {
+            let zero = W::zero();
+            let d_s = potentials.get(state as usize).unwrap_or(&zero);
+            final_weight.divide(d_s, DivideType::DivideLeft)
+        }
+        None => Ok(W::zero()),
+    }
+}
+
+/// This operation determines if two weighted acceptors are equivalent, i.e. if they accept the
+/// same strings with the same weights.
+///
+/// Both acceptors must be input deterministic and epsilon-free, an error is returned otherwise
+/// (`rm_epsilon` and `determinize` can be used beforehand). The weights are normalized using the
+/// shortest distances to the final states and the states of both FSTs are then jointly
+/// partitioned (Hopcroft-Karp algorithm).
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::algorithms::equivalent;
+/// # use rustfst::utils::acceptor;
+/// # fn main() -> Result<()> {
+/// let fst_1: VectorFst<TropicalWeight> = acceptor(&[1, 2], TropicalWeight::new(1.0));
+/// let fst_2: VectorFst<TropicalWeight> = acceptor(&[1, 2], TropicalWeight::new(2.0));
+///
+/// assert!(equivalent(&fst_1, &fst_1)?);
+/// assert!(!equivalent(&fst_1, &fst_2)?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn equivalent<W, F1, F2>(fst_1: &F1, fst_2: &F2) -> Result<bool>
+where
+    W: WeaklyDivisibleSemiring,
+    F1: ExpandedFst<W>,
+    F2: ExpandedFst<W>,
+{
+    check_properties(fst_1, "fst_1")?;
+    check_properties(fst_2, "fst_2")?;
+
+    let potentials_1 = shortest_distance(fst_1, true)?;
+    let potentials_2 = shortest_distance(fst_2, true)?;
+
+    let zero = W::zero();
+    let total_weight_1 = fst_1
+        .start()
+        .and_then(|s| potentials_1.get(s as usize))
+        .unwrap_or(&zero);
+    let total_weight_2 = fst_2
+        .start()
+        .and_then(|s| potentials_2.get(s as usize))
+        .unwrap_or(&zero);
+    if !weights_equal(total_weight_1, total_weight_2) {
+        return Ok(false);
+    }
+    // Both FSTs accept the empty language.
+    if total_weight_1.is_zero() {
+        return Ok(true);
+    }
+    // The total weights are not zero so the start states exist.
+    let start_1 = fst_1.start().unwrap();
+    let start_2 = fst_2.start().unwrap();
+
+    // States of the second FST are shifted by the number of states of the first one.
+    let offset = fst_1.num_states();
+    let mut classes = UnionFind::new(offset + fst_2.num_states());
+    let mut queue = VecDeque::new();
+    classes.union(start_1 as usize, offset + start_2 as usize);
+    queue.push_back((start_1, start_2));
+
+    while let Some((s1, s2)) = queue.pop_front() {
+        let final_weight_1 = normalized_final_weight(fst_1, s1, &potentials_1)?;
+        let final_weight_2 = normalized_final_weight(fst_2, s2, &potentials_2)?;
+        if !weights_equal(&final_weight_1, &final_weight_2) {
+            return Ok(false);
+        }
+
+        let trs_1 = normalized_trs(fst_1, s1, &potentials_1)?;
+        let trs_2 = normalized_trs(fst_2, s2, &potentials_2)?;
+        if trs_1.len() != trs_2.len() {
+            return Ok(false);
+        }
+        for (label, (weight_1, nextstate_1)) in trs_1.iter() {
+            let (weight_2, nextstate_2) = match trs_2.get(label) {
+                Some(v) => v,
+                None => return Ok(false),
+            };
+            if !weights_equal(weight_1, weight_2) {
+                return Ok(false);
+            }
+            let class_1 = classes.find(*nextstate_1 as usize);
+            let class_2 = classes.find(offset + *nextstate_2 as usize);
+            if class_1 != class_2 {
+                classes.union(class_1, class_2);
+                queue.push_back((*nextstate_1, *nextstate_2));
+            }
+        }
+    }
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::MutableFst;
+    use crate::semirings::{Semiring, TropicalWeight};
+    use crate::Tr;
+
+    fn acceptor_abc(
+        w_a: f32,
+        w_b: f32,
+        w_c: f32,
+        final_weight_1: f32,
+        final_weight_2: f32,
+    ) -> Result<VectorFst<TropicalWeight>> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(3);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, w_a, 1))?;
+        fst.add_tr(1, Tr::new(2, 2, w_b, 2))?;
+        fst.add_tr(1, Tr::new(3, 3, w_c, 2))?;
+        fst.set_final(1, final_weight_1)?;
+        fst.set_final(2, final_weight_2)?;
+        Ok(fst)
+    }
+
+    #[test]
+    fn test_equivalent_different_weight_distribution() -> Result<()> {
+        let fst_1 = acceptor_abc(1.0, 2.0, 3.0, 2.0, 0.0)?;
+        let fst_2 = acceptor_abc(3.0, 0.0, 1.0, 0.0, 0.0)?;
+        assert_ne!(fst_1, fst_2);
+
+        assert!(equivalent(&fst_1, &fst_2)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_equivalent_with_cycles() -> Result<()> {
+        // Accepts a(ba)* with both FSTs, the second one being unrolled once.
+        let mut fst_1 = VectorFst::<TropicalWeight>::new();
+        fst_1.add_states(2);
+        fst_1.set_start(0)?;
+        fst_1.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst_1.add_tr(1, Tr::new(2, 2, 1.0, 0))?;
+        fst_1.set_final(1, TropicalWeight::one())?;
+
+        let mut fst_2 = VectorFst::<TropicalWeight>::new();
+        fst_2.add_states(4);
+        fst_2.set_start(3)?;
+        fst_2.add_tr(3, Tr::new(1, 1, 1.0, 2))?;
+        fst_2.add_tr(2, Tr::new(2, 2, 1.0, 1))?;
+        fst_2.add_tr(1, Tr::new(1, 1, 1.0, 0))?;
+        fst_2.add_tr(0, Tr::new(2, 2, 1.0, 3))?;
+        fst_2.set_final(2, TropicalWeight::one())?;
+        fst_2.set_final(0, TropicalWeight::one())?;
+
+        assert!(equivalent(&fst_1, &fst_2)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_not_equivalent() -> Result<()> {
+        let fst_1 = acceptor_abc(1.0, 2.0, 3.0, 2.0, 0.0)?;
+
+        let fst_2 = acceptor_abc(1.0, 2.5, 3.0, 2.0, 0.0)?;
+        assert!(!equivalent(&fst_1, &fst_2)?);
+
+        let mut fst_3 = acceptor_abc(1.0, 2.0, 3.0, 2.0, 0.0)?;
+        fst_3.add_tr(2, Tr::new(4, 4, 1.0, 2))?;
+        assert!(!equivalent(&fst_1, &fst_3)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_equivalent_empty_language() -> Result<()> {
+        let empty = VectorFst::<TropicalWeight>::new();
+        assert!(equivalent(&empty, &empty)?);
+
+        // A start state but no final state.
+        let mut fst_1 = VectorFst::<TropicalWeight>::new();
+        fst_1.add_states(2);
+        fst_1.set_start(0)?;
+        fst_1.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        assert!(equivalent(&empty, &fst_1)?);
+
+        let fst_2 = acceptor_abc(1.0, 2.0, 3.0, 2.0, 0.0)?;
+        assert!(!equivalent(&empty, &fst_2)?);
+        assert!(!equivalent(&fst_2, &fst_1)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_equivalent_not_deterministic() -> Result<()> {
+        let fst_1 = acceptor_abc(1.0, 2.0, 3.0, 2.0, 0.0)?;
+        let mut fst_2 = acceptor_abc(1.0, 2.0, 3.0, 2.0, 0.0)?;
+        fst_2.add_tr(0, Tr::new(1, 1, 2.0, 2))?;
+
+        assert!(equivalent(&fst_1, &fst_2).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+use anyhow::Result;
+
+use crate::algorithms::compose::compose;
+use crate::fst_properties::{compute_fst_properties, FstProperties};
+use crate::fst_traits::{AllocableFst, ExpandedFst, MutableFst};
+use crate::semirings::Semiring;
+
+fn check_acceptor<W: Semiring, F: ExpandedFst<W>>(fst: &F, name: &str) -> Result<()> {
+    let mask = FstProperties::ACCEPTOR | FstProperties::NOT_ACCEPTOR;
+    let mut known = FstProperties::empty();
+    if !compute_fst_properties(fst, mask, &mut known, true)?.contains(FstProperties::ACCEPTOR) {
+        bail!("Intersect : {} must be an acceptor", name)
+    }
+    Ok(())
+}
+
+/// This operation computes the intersection of two weighted acceptors.
+/// If `A` accepts string `x` with weight `a` and `B` accepts `x` with weight `b`,
+/// then their intersection accepts `x` with weight `a ⊗ b`.
+///
+/// This is the composition restricted to acceptors, epsilons are handled by the compose
+/// filter. As for `compose`, either the output labels of `fst_1` or the input labels of
+/// `fst_2` must be sorted. An error is returned if one of the FSTs is not an acceptor.
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::algorithms::intersect;
+/// # use rustfst::fst_traits::PathsIterator;
+/// # use rustfst::utils::acceptor;
+/// # use rustfst::FstPath;
+/// # fn main() -> Result<()> {
+/// let fst_1: VectorFst<TropicalWeight> = acceptor(&[1, 2], TropicalWeight::new(1.0));
+/// let fst_2: VectorFst<TropicalWeight> = acceptor(&[1, 2], TropicalWeight::new(2.0));
+///
+/// let fst_res: VectorFst<TropicalWeight> = intersect(&fst_1, &fst_2)?;
+/// let paths: Vec<_> = fst_res.paths_iter().collect();
+/// assert_eq!(paths, vec![FstPath::new(vec![1, 2], vec![1, 2], TropicalWeight::new(3.0))]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn intersect<W, F1, F2, F3>(fst_1: &F1, fst_2: &F2) -> Result<F3>
+where
+    W: Semiring,
+    F1: ExpandedFst<W>,
+    F2: ExpandedFst<W>,
+    F3: MutableFst<W> + AllocableFst<W>,
+{
+    check_acceptor(fst_1, "fst_1")?;
+    check_acceptor(fst_2, "fst_2")?;
+    compose::<_, F1, F2, F3, _, _>(fst_1, fst_2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    use crate::algorithms::tr_compares::ILabelCompare;
+    use crate::algorithms::tr_sort;
+    use crate::algorithms::union::union;
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::PathsIterator;
+    use crate::semirings::TropicalWeight;
+    use crate::utils::{acceptor, transducer};
+    use crate::{FstPath, Label, Tr, EPS_LABEL};
+
+    fn path(labels: &[Label], weight: f32) -> FstPath<TropicalWeight> {
+        FstPath::new(
+            labels.to_vec(),
+            labels.to_vec(),
+            TropicalWeight::new(weight),
+        )
+    }
+
+    fn strings_fst(strings: &[(Vec<Label>, f32)]) -> Result<VectorFst<TropicalWeight>> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        for (labels, weight) in strings {
+            let fst_string: VectorFst<_> = acceptor(labels, TropicalWeight::new(*weight));
+            union(&mut fst, &fst_string)?;
+        }
+        tr_sort(&mut fst, ILabelCompare {});
+        Ok(fst)
+    }
+
+    #[test]
+    fn test_intersect_common_strings() -> Result<()> {
+        let fst_1 = strings_fst(&[(vec![1], 1.0), (vec![1, 2], 2.0), (vec![3], 0.5)])?;
+        let fst_2 = strings_fst(&[(vec![1, 2], 1.5), (vec![3], 0.25), (vec![2], 1.0)])?;
+
+        let fst_res: VectorFst<TropicalWeight> = intersect(&fst_1, &fst_2)?;
+
+        let paths: HashSet<_> = fst_res.paths_iter().collect();
+        let paths_ref: HashSet<_> = vec![path(&[1, 2], 3.5), path(&[3], 0.75)]
+            .into_iter()
+            .collect();
+        assert_eq!(paths, paths_ref);
+        Ok(())
+    }
+
+    #[test]
+    fn test_intersect_with_epsilons() -> Result<()> {
+        // Accepts "1 2" with an epsilon transition in between.
+        let mut fst_1 = VectorFst::<TropicalWeight>::new();
+        fst_1.add_states(4);
+        fst_1.set_start(0)?;
+        fst_1.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst_1.add_tr(1, Tr::new(EPS_LABEL, EPS_LABEL, 0.5, 2))?;
+        fst_1.add_tr(2, Tr::new(2, 2, 1.0, 3))?;
+        fst_1.set_final(3, TropicalWeight::one())?;
+
+        let fst_2: VectorFst<TropicalWeight> = acceptor(&[1, 2], TropicalWeight::new(2.0));
+
+        let fst_res: VectorFst<TropicalWeight> = intersect(&fst_1, &fst_2)?;
+
+        let paths: Vec<_> = fst_res.paths_iter().collect();
+        assert_eq!(paths, vec![path(&[1, 2], 4.5)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_intersect_transducer() -> Result<()> {
+        let fst_1: VectorFst<TropicalWeight> = transducer(&[1], &[2], TropicalWeight::one());
+        let fst_2: VectorFst<TropicalWeight> = acceptor(&[2], TropicalWeight::one());
+        let res: Result<VectorFst<TropicalWeight>> = intersect(&fst_1, &fst_2);
+        assert!(res.is_err());
+        Ok(())
+    }
+}
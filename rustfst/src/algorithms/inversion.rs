@@ -5,6 +5,7 @@ use crate::semirings::Semiring;
 
 /// This operation inverts the transduction corresponding to an FST
 /// by exchanging the FST's input and output labels.
+/// The input and output symbol tables are exchanged as well.
 ///
 /// # Example 1
 /// ```
@@ -44,5 +45,65 @@ pub fn invert<W: Semiring, F: MutableFst<W>>(fst: &mut F) {
         }
     }
 
+    let isymt = fst.take_input_symbols();
+    let osymt = fst.take_output_symbols();
+    if let Some(symt) = osymt {
+        fst.set_input_symbols(symt);
+    }
+    if let Some(symt) = isymt {
+        fst.set_output_symbols(symt);
+    }
+
     fst.set_properties_with_mask(invert_properties(props), FstProperties::all_properties());
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::Fst;
+    use crate::semirings::TropicalWeight;
+    use crate::utils::transducer;
+    use crate::SymbolTable;
+
+    #[test]
+    fn test_invert_twice() {
+        let mut fst: VectorFst<TropicalWeight> =
+            transducer(&[1, 2], &[3, 4], TropicalWeight::new(1.5));
+        fst.set_input_symbols(Arc::new(vec!["a", "b"].into_iter().collect()));
+        fst.set_output_symbols(Arc::new(vec!["x", "y", "z", "w"].into_iter().collect()));
+        let fst_ref = fst.clone();
+
+        invert(&mut fst);
+        assert_ne!(fst, fst_ref);
+        invert(&mut fst);
+
+        assert_eq!(fst, fst_ref);
+        assert_eq!(fst.input_symbols(), fst_ref.input_symbols());
+        assert_eq!(fst.output_symbols(), fst_ref.output_symbols());
+    }
+
+    #[test]
+    fn test_invert_symbol_tables() {
+        let isymt: Arc<SymbolTable> = Arc::new(vec!["a", "b"].into_iter().collect());
+        let osymt: Arc<SymbolTable> = Arc::new(vec!["x", "y", "z", "w"].into_iter().collect());
+
+        let mut fst: VectorFst<TropicalWeight> =
+            transducer(&[1, 2], &[3, 4], TropicalWeight::one());
+        fst.set_input_symbols(Arc::clone(&isymt));
+        fst.set_output_symbols(Arc::clone(&osymt));
+        invert(&mut fst);
+        assert_eq!(fst.input_symbols(), Some(&osymt));
+        assert_eq!(fst.output_symbols(), Some(&isymt));
+
+        let mut fst: VectorFst<TropicalWeight> =
+            transducer(&[1, 2], &[3, 4], TropicalWeight::one());
+        fst.set_input_symbols(Arc::clone(&isymt));
+        invert(&mut fst);
+        assert_eq!(fst.input_symbols(), None);
+        assert_eq!(fst.output_symbols(), Some(&isymt));
+    }
+}
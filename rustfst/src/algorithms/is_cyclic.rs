@@ -0,0 +1,110 @@
+use anyhow::Result;
+
+use crate::algorithms::dfs_visit::{dfs_visit_all_states, Visitor};
+use crate::algorithms::tr_filters::AnyTrFilter;
+use crate::fst_traits::{ExpandedFst, Fst};
+use crate::semirings::Semiring;
+use crate::StateId;
+use crate::Tr;
+
+/// Visitor stopping the DFS on the first back transition, i.e the first cycle found.
+struct CycleVisitor {
+    cyclic: bool,
+}
+
+impl<'a, W: Semiring, F: 'a + Fst<W>> Visitor<'a, W, F> for CycleVisitor {
+    fn init_visit(&mut self, _fst: &'a F) {}
+
+    fn init_state(&mut self, _s: StateId, _root: StateId) -> bool {
+        true
+    }
+
+    fn tree_tr(&mut self, _s: StateId, _tr: &Tr<W>) -> bool {
+        true
+    }
+
+    fn back_tr(&mut self, _s: StateId, _tr: &Tr<W>) -> bool {
+        self.cyclic = true;
+        false
+    }
+
+    fn forward_or_cross_tr(&mut self, _s: StateId, _tr: &Tr<W>) -> bool {
+        true
+    }
+
+    fn finish_state(&mut self, _s: StateId, _parent: Option<StateId>, _tr: Option<&Tr<W>>) {}
+
+    fn finish_visit(&mut self) {}
+}
+
+/// Returns whether the FST contains a cycle, including in parts of the FST that are not
+/// accessible from the start state or in an FST without start state. The search stops on the
+/// first cycle found.
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::fst_traits::MutableFst;
+/// # use rustfst::algorithms::is_cyclic;
+/// # use rustfst::utils::acceptor;
+/// # use rustfst::Tr;
+/// # fn main() -> Result<()> {
+/// let mut fst: VectorFst<TropicalWeight> = acceptor(&[1, 2], TropicalWeight::one());
+/// assert!(!is_cyclic(&fst)?);
+///
+/// fst.add_tr(1, Tr::new(3, 3, TropicalWeight::one(), 0))?;
+/// assert!(is_cyclic(&fst)?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn is_cyclic<W: Semiring, F: ExpandedFst<W>>(fst: &F) -> Result<bool> {
+    let mut visitor = CycleVisitor { cyclic: false };
+    dfs_visit_all_states(fst, &mut visitor, &AnyTrFilter {});
+    Ok(visitor.cyclic)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::MutableFst;
+    use crate::semirings::TropicalWeight;
+    use crate::utils::acceptor;
+
+    #[test]
+    fn test_is_cyclic_chain() -> Result<()> {
+        let fst: VectorFst<TropicalWeight> = acceptor(&[1, 2, 3, 4], TropicalWeight::one());
+        assert!(!is_cyclic(&fst)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_cyclic_self_loop() -> Result<()> {
+        let mut fst: VectorFst<TropicalWeight> = acceptor(&[1, 2, 3], TropicalWeight::one());
+        fst.add_tr(2, Tr::new(4, 4, TropicalWeight::one(), 2))?;
+        assert!(is_cyclic(&fst)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_cyclic_empty_fst() -> Result<()> {
+        let fst = VectorFst::<TropicalWeight>::new();
+        assert!(!is_cyclic(&fst)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_cyclic_no_start_state() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(2);
+        fst.add_tr(0, Tr::new(1, 1, TropicalWeight::one(), 1))?;
+        assert!(!is_cyclic(&fst)?);
+
+        fst.add_tr(1, Tr::new(2, 2, TropicalWeight::one(), 0))?;
+        assert!(is_cyclic(&fst)?);
+        Ok(())
+    }
+}
@@ -1,5 +1,4 @@
 use std::cmp::Ordering;
-use std::collections::VecDeque;
 
 use anyhow::Result;
 
@@ -11,12 +10,78 @@ use std::marker::PhantomData;
 struct Isomorphism<'a, W: Semiring, F1: ExpandedFst<W>, F2: ExpandedFst<W>> {
     fst_1: &'a F1,
     fst_2: &'a F2,
-    state_pairs: Vec<Option<StateId>>,
-    queue: VecDeque<(StateId, StateId)>,
     w: PhantomData<W>,
     delta: f32,
 }
 
+/// State correspondences found so far, in the order they have been found. The pairs after the
+/// first `num_checked` ones remain to be checked.
+struct StateMapping {
+    state_pairs: Vec<Option<StateId>>,
+    rev_state_pairs: Vec<Option<StateId>>,
+    paired_states: Vec<StateId>,
+    num_checked: usize,
+}
+
+impl StateMapping {
+    fn new(num_states_1: usize, num_states_2: usize) -> Self {
+        Self {
+            state_pairs: vec![None; num_states_1],
+            rev_state_pairs: vec![None; num_states_2],
+            paired_states: vec![],
+            num_checked: 0,
+        }
+    }
+
+    // Maintains state correspondences and the pairs to check.
+    fn pair_state(&mut self, s1: StateId, s2: StateId) -> bool {
+        match (
+            self.state_pairs[s1 as usize],
+            self.rev_state_pairs[s2 as usize],
+        ) {
+            // Either already seen this pair or s1 already paired with another s2
+            (Some(paired_s2), _) => paired_s2 == s2,
+            // s2 already paired with another s1
+            (None, Some(_)) => false,
+            (None, None) => {
+                self.state_pairs[s1 as usize] = Some(s2);
+                self.rev_state_pairs[s2 as usize] = Some(s1);
+                self.paired_states.push(s1);
+                true
+            }
+        }
+    }
+
+    // Forgets the pairs found after the first `num_paired` ones.
+    fn undo(&mut self, num_paired: usize, num_checked: usize) {
+        for s1 in self.paired_states.drain(num_paired..) {
+            if let Some(s2) = self.state_pairs[s1 as usize].take() {
+                self.rev_state_pairs[s2 as usize] = None;
+            }
+        }
+        self.num_checked = num_checked;
+    }
+}
+
+/// Next states of trs sharing the same labels and weight, that can be paired in any order.
+struct AmbiguousTrs {
+    nextstates_1: Vec<StateId>,
+    nextstates_2: Vec<StateId>,
+    // Whether each next state of `nextstates_2` is already paired by a choice.
+    used_2: Vec<bool>,
+}
+
+/// Choice of the next state paired with `nextstates_1[idx]` of the ambiguous trs `group`, along
+/// with the sizes of the mapping and of the ambiguous trs to restore before the next choice.
+struct ChoicePoint {
+    group: usize,
+    idx: usize,
+    choice: Option<usize>,
+    num_paired: usize,
+    num_checked: usize,
+    num_groups: usize,
+}
+
 /// Compare trs in the order input label, output label, weight and nextstate.
 pub fn tr_compare<W: Semiring>(tr_1: &Tr<W>, tr_2: &Tr<W>) -> Ordering {
     if tr_1.ilabel < tr_2.ilabel {
@@ -51,26 +116,26 @@ impl<'a, W: Semiring, F1: ExpandedFst<W>, F2: ExpandedFst<W>> Isomorphism<'a, W,
         Self {
             fst_1,
             fst_2,
-            state_pairs: vec![None; fst_1.num_states()],
-            queue: VecDeque::new(),
             w: PhantomData,
             delta,
         }
     }
 
-    // Maintains state correspondences and queue.
-    fn pair_state(&mut self, s1: StateId, s2: StateId) -> bool {
-        if self.state_pairs[s1 as usize] == Some(s2) {
-            return true; // already seen this pair
-        } else if self.state_pairs[s1 as usize].is_some() {
-            return false; // s1 already paired with another s2
-        }
-        self.state_pairs[s1 as usize] = Some(s2);
-        self.queue.push_back((s1, s2));
-        true
+    fn same_labels_and_weight(&self, tr_1: &Tr<W>, tr_2: &Tr<W>) -> bool {
+        tr_1.ilabel == tr_2.ilabel
+            && tr_1.olabel == tr_2.olabel
+            && tr_1.weight.approx_equal(&tr_2.weight, self.delta)
     }
 
-    fn ismorphic_state(&mut self, s1: StateId, s2: StateId) -> Result<bool> {
+    // Checks the final weights and the trs of a pair of states. The next states of
+    // unambiguous trs are paired directly, the ambiguous ones are added to `ambiguous_trs`.
+    fn isomorphic_state(
+        &self,
+        mapping: &mut StateMapping,
+        ambiguous_trs: &mut Vec<AmbiguousTrs>,
+        s1: StateId,
+        s2: StateId,
+    ) -> Result<bool> {
         let fw1 = self.fst_1.final_weight(s1)?;
         let fw2 = self.fst_2.final_weight(s2)?;
         let fw_equal = match (fw1, fw2) {
@@ -98,35 +163,52 @@ impl<'a, W: Semiring, F1: ExpandedFst<W>, F2: ExpandedFst<W>> Isomorphism<'a, W,
         trs1.sort_by(|a, b| tr_compare(a, b));
         trs2.sort_by(|a, b| tr_compare(a, b));
 
-        for i in 0..trs1.len() {
-            let arc1 = trs1[i];
-            let arc2 = trs2[i];
-            if arc1.ilabel != arc2.ilabel {
+        for (tr1, tr2) in trs1.iter().zip(trs2.iter()) {
+            if !self.same_labels_and_weight(tr1, tr2) {
                 return Ok(false);
             }
-            if arc1.olabel != arc2.olabel {
-                return Ok(false);
+        }
+
+        let mut i = 0;
+        while i < trs1.len() {
+            let mut j = i + 1;
+            while j < trs1.len() && self.same_labels_and_weight(trs1[i], trs1[j]) {
+                j += 1;
             }
-            if !(arc1.weight.approx_equal(&arc2.weight, self.delta)) {
-                return Ok(false);
+            if j == i + 1 {
+                if !mapping.pair_state(trs1[i].nextstate, trs2[i].nextstate) {
+                    return Ok(false);
+                }
+            } else {
+                ambiguous_trs.push(AmbiguousTrs {
+                    nextstates_1: trs1[i..j].iter().map(|tr| tr.nextstate).collect(),
+                    nextstates_2: trs2[i..j].iter().map(|tr| tr.nextstate).collect(),
+                    used_2: vec![false; j - i],
+                });
             }
-            if !(self.pair_state(arc1.nextstate, arc2.nextstate)) {
+            i = j;
+        }
+        Ok(true)
+    }
+
+    // Checks all the pairs of states that remain to be checked.
+    fn explore(
+        &self,
+        mapping: &mut StateMapping,
+        ambiguous_trs: &mut Vec<AmbiguousTrs>,
+    ) -> Result<bool> {
+        while mapping.num_checked < mapping.paired_states.len() {
+            let s1 = mapping.paired_states[mapping.num_checked];
+            let s2 = mapping.state_pairs[s1 as usize].unwrap();
+            mapping.num_checked += 1;
+            if !self.isomorphic_state(mapping, ambiguous_trs, s1, s2)? {
                 return Ok(false);
             }
-            if i > 0 {
-                let arc0 = trs1[i - 1];
-                if arc1.ilabel == arc0.ilabel
-                    && arc1.olabel == arc0.olabel
-                    && arc1.weight.approx_equal(&arc0.weight, self.delta)
-                {
-                    bail!("Isomorphic: Non-determinism as an unweighted automaton")
-                }
-            }
         }
         Ok(true)
     }
 
-    fn isomorphic(&mut self) -> Result<bool> {
+    fn isomorphic(&self) -> Result<bool> {
         // Both FSTs don't have a start state => both don't recognize anything
         if self.fst_1.start().is_none() && self.fst_2.start().is_none() {
             return Ok(true);
@@ -137,19 +219,74 @@ impl<'a, W: Semiring, F1: ExpandedFst<W>, F2: ExpandedFst<W>> Isomorphism<'a, W,
             return Ok(false);
         }
 
-        self.pair_state(self.fst_1.start().unwrap(), self.fst_2.start().unwrap());
+        let mut mapping = StateMapping::new(self.fst_1.num_states(), self.fst_2.num_states());
+        mapping.pair_state(self.fst_1.start().unwrap(), self.fst_2.start().unwrap());
+
+        // Every possible pairing of the next states of the ambiguous trs is tried with a stack of
+        // choices. Backtracking undoes the pairs found since the last choice instead of copying
+        // the mapping at each choice.
+        let mut ambiguous_trs = vec![];
+        let mut choices: Vec<ChoicePoint> = vec![];
+        // Next ambiguous tr to pair, as (group, index in nextstates_1).
+        let mut next_choice = (0, 0);
+        let mut consistent = self.explore(&mut mapping, &mut ambiguous_trs)?;
+        loop {
+            if consistent {
+                let (group, idx) = next_choice;
+                if group == ambiguous_trs.len() {
+                    return Ok(true);
+                }
+                choices.push(ChoicePoint {
+                    group,
+                    idx,
+                    choice: None,
+                    num_paired: mapping.paired_states.len(),
+                    num_checked: mapping.num_checked,
+                    num_groups: ambiguous_trs.len(),
+                });
+            }
 
-        while !self.queue.is_empty() {
-            let (s1, s2) = self.queue.pop_front().unwrap();
-            if !self.ismorphic_state(s1, s2)? {
-                return Ok(false);
+            // Tries the next candidate of the last choice, backtracking once they are exhausted.
+            let choice_point = match choices.last_mut() {
+                Some(c) => c,
+                None => return Ok(false),
+            };
+            mapping.undo(choice_point.num_paired, choice_point.num_checked);
+            ambiguous_trs.truncate(choice_point.num_groups);
+            let group = &mut ambiguous_trs[choice_point.group];
+            let first_candidate = match choice_point.choice.take() {
+                Some(j) => {
+                    group.used_2[j] = false;
+                    j + 1
+                }
+                None => 0,
+            };
+            let ns1 = group.nextstates_1[choice_point.idx];
+            let candidate = (first_candidate..group.nextstates_2.len())
+                .find(|j| !group.used_2[*j] && mapping.pair_state(ns1, group.nextstates_2[*j]));
+            match candidate {
+                Some(j) => {
+                    group.used_2[j] = true;
+                    choice_point.choice = Some(j);
+                    next_choice = if choice_point.idx + 1 < group.nextstates_1.len() {
+                        (choice_point.group, choice_point.idx + 1)
+                    } else {
+                        (choice_point.group + 1, 0)
+                    };
+                    consistent = self.explore(&mut mapping, &mut ambiguous_trs)?;
+                }
+                None => {
+                    choices.pop();
+                    consistent = false;
+                }
             }
         }
-
-        Ok(true)
     }
 }
 
+/// Configuration of `isomorphic_with_config`. Two weights are considered equal when they are
+/// approximately equal with `delta`.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct IsomorphicConfig {
     delta: f32,
 }
@@ -172,6 +309,9 @@ impl IsomorphicConfig {
 ///
 /// In other words, Isomorphic(A, B) is true if and only if the states of A can
 /// be renumbered and the transitions leaving each state reordered so that Equal(A, B) is true.
+///
+/// When several transitions leaving a state share the same labels and weight, every possible
+/// pairing of their destination states is tried.
 pub fn isomorphic<W, F1, F2>(fst_1: &F1, fst_2: &F2) -> Result<bool>
 where
     W: Semiring,
@@ -197,7 +337,7 @@ where
     F1: ExpandedFst<W>,
     F2: ExpandedFst<W>,
 {
-    let mut iso = Isomorphism::new(fst_1, fst_2, config.delta);
+    let iso = Isomorphism::new(fst_1, fst_2, config.delta);
     iso.isomorphic()
 }
 
@@ -243,4 +383,84 @@ mod test {
 
         Ok(())
     }
+
+    fn ambiguous_fst(weight: f32) -> Result<VectorFst<LogWeight>> {
+        let mut fst = VectorFst::<LogWeight>::new();
+        fst.add_states(4);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 2))?;
+        fst.add_tr(1, Tr::new(2, 2, weight, 3))?;
+        fst.add_tr(2, Tr::new(3, 3, 1.0, 3))?;
+        fst.set_final(3, LogWeight::one())?;
+        Ok(fst)
+    }
+
+    #[test]
+    fn test_isomorphic_permuted_states_with_ambiguity() -> Result<()> {
+        let fst_1 = ambiguous_fst(2.0)?;
+
+        // Same FST with the states renumbered (0 <-> 3 and 1 <-> 2).
+        let mut fst_2 = VectorFst::<LogWeight>::new();
+        fst_2.add_states(4);
+        fst_2.set_start(3)?;
+        fst_2.add_tr(3, Tr::new(1, 1, 1.0, 1))?;
+        fst_2.add_tr(3, Tr::new(1, 1, 1.0, 2))?;
+        fst_2.add_tr(2, Tr::new(2, 2, 2.0, 0))?;
+        fst_2.add_tr(1, Tr::new(3, 3, 1.0, 0))?;
+        fst_2.set_final(0, LogWeight::one())?;
+
+        assert!(isomorphic(&fst_1, &fst_2)?);
+        assert!(!isomorphic(&fst_1, &ambiguous_fst(2.5)?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_isomorphic_not_bijective() -> Result<()> {
+        let fst_1: VectorFst<LogWeight> = SerializableFst::from_text_string(
+            "0\t1\t1\t1\n\
+             0\t2\t2\t2\n\
+             1\n\
+             2\n",
+        )?;
+        let fst_2: VectorFst<LogWeight> = SerializableFst::from_text_string(
+            "0\t1\t1\t1\n\
+             0\t1\t2\t2\n\
+             1\n",
+        )?;
+
+        assert!(!isomorphic(&fst_1, &fst_2)?);
+        Ok(())
+    }
+
+    // Chain of `num_levels` diamonds made of two identical trs, with the states renumbered by
+    // `state_id`.
+    fn diamonds_fst(
+        num_levels: StateId,
+        state_id: impl Fn(StateId) -> StateId,
+    ) -> Result<VectorFst<LogWeight>> {
+        let mut fst = VectorFst::<LogWeight>::new();
+        fst.add_states(3 * num_levels as usize + 1);
+        fst.set_start(state_id(0))?;
+        for level in 0..num_levels {
+            let s = 3 * level;
+            for branch in s + 1..s + 3 {
+                fst.add_tr(state_id(s), Tr::new(1, 1, 1.0, state_id(branch)))?;
+                fst.add_tr(state_id(branch), Tr::new(2, 2, 1.0, state_id(s + 3)))?;
+            }
+        }
+        fst.set_final(state_id(3 * num_levels), LogWeight::one())?;
+        Ok(fst)
+    }
+
+    #[test]
+    fn test_isomorphic_many_ambiguous_trs() -> Result<()> {
+        let num_levels = 50_000;
+        let max_state = 3 * num_levels;
+        let fst_1 = diamonds_fst(num_levels, |s| s)?;
+        let fst_2 = diamonds_fst(num_levels, |s| max_state - s)?;
+
+        assert!(isomorphic(&fst_1, &fst_2)?);
+        Ok(())
+    }
 }
@@ -0,0 +1,411 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+
+use crate::algorithms::lazy::cache::cache_internal_types::{CachedData, FinalWeight, StartState};
+use crate::algorithms::lazy::{CacheStatus, FstCache};
+use crate::semirings::Semiring;
+use crate::{StateId, Trs, TrsVec, EPS_LABEL};
+
+/// Options of the caches bounding the number of expanded states they keep in memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheOptions {
+    /// Maximum number of states for which the trs or the final weight are kept. A value of 0 is
+    /// treated as 1.
+    pub max_states: usize,
+}
+
+impl CacheOptions {
+    pub fn new(max_states: usize) -> Self {
+        Self { max_states }
+    }
+}
+
+impl Default for CacheOptions {
+    fn default() -> Self {
+        Self {
+            max_states: usize::MAX,
+        }
+    }
+}
+
+/// Number of trs and of epsilons of a cached state.
+#[derive(Debug, Clone, Copy)]
+struct TrsCounts {
+    ntrs: usize,
+    niepsilons: usize,
+    noepsilons: usize,
+}
+
+#[derive(Debug, Clone)]
+struct LruCacheData<W: Semiring> {
+    trs: HashMap<StateId, TrsVec<W>>,
+    trs_counts: HashMap<StateId, TrsCounts>,
+    final_weights: HashMap<StateId, FinalWeight<W>>,
+    // Last access time of each cached state.
+    last_access: HashMap<StateId, u64>,
+    // Cached states ordered by access time, the least recently accessed one first.
+    recency: BTreeMap<u64, StateId>,
+    tick: u64,
+    num_known_states: usize,
+}
+
+impl<W: Semiring> Default for LruCacheData<W> {
+    fn default() -> Self {
+        Self {
+            trs: HashMap::new(),
+            trs_counts: HashMap::new(),
+            final_weights: HashMap::new(),
+            last_access: HashMap::new(),
+            recency: BTreeMap::new(),
+            tick: 0,
+            num_known_states: 0,
+        }
+    }
+}
+
+impl<W: Semiring> LruCacheData<W> {
+    /// Marks the state as the most recently accessed one.
+    fn touch(&mut self, id: StateId) {
+        if let Some(old_tick) = self.last_access.insert(id, self.tick) {
+            self.recency.remove(&old_tick);
+        }
+        self.recency.insert(self.tick, id);
+        self.tick += 1;
+    }
+
+    /// Drops the least recently accessed states until at most `max_states` remain.
+    fn evict(&mut self, max_states: usize) {
+        let max_states = std::cmp::max(max_states, 1);
+        while self.last_access.len() > max_states {
+            let (&tick, &state) = self.recency.iter().next().unwrap();
+            self.recency.remove(&tick);
+            self.last_access.remove(&state);
+            self.trs.remove(&state);
+            self.trs_counts.remove(&state);
+            self.final_weights.remove(&state);
+        }
+    }
+}
+
+/// Cache keeping at most `max_states` expanded states in memory.
+///
+/// When the limit is reached, the trs, the number of trs and of epsilons and the final weight of
+/// the least recently accessed state are dropped. They are reported as not computed afterwards
+/// and will be recomputed by the lazy FST on the next access. The number of known states never
+/// decreases on eviction.
+#[derive(Debug)]
+pub struct LruCache<W: Semiring> {
+    opts: CacheOptions,
+    start: Mutex<CachedData<CacheStatus<StartState>>>,
+    data: Mutex<LruCacheData<W>>,
+}
+
+impl<W: Semiring> LruCache<W> {
+    pub fn new(opts: CacheOptions) -> Self {
+        Self {
+            opts,
+            start: Mutex::new(CachedData::default()),
+            data: Mutex::new(LruCacheData::default()),
+        }
+    }
+
+    pub fn options(&self) -> &CacheOptions {
+        &self.opts
+    }
+
+    /// Number of states currently stored in the cache.
+    pub fn num_cached_states(&self) -> usize {
+        self.data.lock().unwrap().last_access.len()
+    }
+
+    pub fn clear(&self) {
+        let mut data_start = self.start.lock().unwrap();
+        data_start.clear();
+
+        let mut data = self.data.lock().unwrap();
+        *data = LruCacheData::default();
+    }
+}
+
+impl<W: Semiring> Clone for LruCache<W> {
+    fn clone(&self) -> Self {
+        Self {
+            opts: self.opts,
+            start: Mutex::new(self.start.lock().unwrap().clone()),
+            data: Mutex::new(self.data.lock().unwrap().clone()),
+        }
+    }
+}
+
+impl<W: Semiring> Default for LruCache<W> {
+    fn default() -> Self {
+        Self::new(CacheOptions::default())
+    }
+}
+
+impl<W: Semiring> FstCache<W> for LruCache<W> {
+    fn get_start(&self) -> CacheStatus<StartState> {
+        let res = self.start.lock().unwrap();
+        res.data
+    }
+
+    fn insert_start(&self, id: StartState) {
+        let mut data = self.start.lock().unwrap();
+        if let Some(s) = id {
+            data.num_known_states = std::cmp::max(data.num_known_states, s as usize + 1);
+        }
+        data.data = CacheStatus::Computed(id);
+    }
+
+    fn get_trs(&self, id: StateId) -> CacheStatus<TrsVec<W>> {
+        let mut data = self.data.lock().unwrap();
+        let trs = match data.trs.get(&id) {
+            Some(trs) => trs.shallow_clone(),
+            None => return CacheStatus::NotComputed,
+        };
+        data.touch(id);
+        CacheStatus::Computed(trs)
+    }
+
+    fn insert_trs(&self, id: StateId, trs: TrsVec<W>) {
+        let mut data = self.data.lock().unwrap();
+        let mut niepsilons = 0;
+        let mut noepsilons = 0;
+        for tr in trs.trs() {
+            data.num_known_states = std::cmp::max(data.num_known_states, tr.nextstate as usize + 1);
+            if tr.ilabel == EPS_LABEL {
+                niepsilons += 1;
+            }
+            if tr.olabel == EPS_LABEL {
+                noepsilons += 1;
+            }
+        }
+        data.trs_counts.insert(
+            id,
+            TrsCounts {
+                ntrs: trs.len(),
+                niepsilons,
+                noepsilons,
+            },
+        );
+        data.trs.insert(id, trs);
+        data.touch(id);
+        data.evict(self.opts.max_states);
+    }
+
+    fn get_final_weight(&self, id: StateId) -> CacheStatus<FinalWeight<W>> {
+        let mut data = self.data.lock().unwrap();
+        let final_weight = match data.final_weights.get(&id) {
+            Some(e) => e.clone(),
+            None => return CacheStatus::NotComputed,
+        };
+        data.touch(id);
+        CacheStatus::Computed(final_weight)
+    }
+
+    fn insert_final_weight(&self, id: StateId, weight: FinalWeight<W>) {
+        let mut data = self.data.lock().unwrap();
+        data.num_known_states = std::cmp::max(data.num_known_states, id as usize + 1);
+        data.final_weights.insert(id, weight);
+        data.touch(id);
+        data.evict(self.opts.max_states);
+    }
+
+    fn num_known_states(&self) -> usize {
+        let mut n = 0;
+        n = std::cmp::max(n, self.start.lock().unwrap().num_known_states);
+        n = std::cmp::max(n, self.data.lock().unwrap().num_known_states);
+        n
+    }
+
+    fn compute_num_known_trs(&self) -> usize {
+        let data = self.data.lock().unwrap();
+        data.trs_counts.values().map(|c| c.ntrs).sum()
+    }
+
+    fn num_trs(&self, id: StateId) -> Option<usize> {
+        let data = self.data.lock().unwrap();
+        data.trs_counts.get(&id).map(|c| c.ntrs)
+    }
+
+    fn num_input_epsilons(&self, id: StateId) -> Option<usize> {
+        let data = self.data.lock().unwrap();
+        data.trs_counts.get(&id).map(|c| c.niepsilons)
+    }
+
+    fn num_output_epsilons(&self, id: StateId) -> Option<usize> {
+        let data = self.data.lock().unwrap();
+        data.trs_counts.get(&id).map(|c| c.noepsilons)
+    }
+
+    fn len_trs(&self) -> usize {
+        self.data.lock().unwrap().trs.len()
+    }
+
+    fn len_final_weights(&self) -> usize {
+        self.data.lock().unwrap().final_weights.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    use anyhow::Result;
+
+    use crate::algorithms::lazy::{FstOp, LazyFst};
+    use crate::fst_impls::VectorFst;
+    use crate::fst_properties::FstProperties;
+    use crate::fst_traits::{CoreFst, FstIterator, MutableFst};
+    use crate::semirings::TropicalWeight;
+    use crate::Tr;
+
+    fn trs(nextstate: StateId) -> TrsVec<TropicalWeight> {
+        let mut trs = TrsVec::default();
+        trs.push(Tr::new(1, 1, TropicalWeight::one(), nextstate));
+        trs
+    }
+
+    #[test]
+    fn test_lru_cache_evicts_least_recently_accessed() {
+        let cache = LruCache::<TropicalWeight>::new(CacheOptions::new(2));
+        cache.insert_trs(0, trs(1));
+        cache.insert_trs(1, trs(2));
+        // State 0 becomes the most recently accessed one.
+        assert!(cache.get_trs(0).is_computed());
+        cache.insert_trs(2, trs(3));
+
+        assert_eq!(cache.num_cached_states(), 2);
+        assert!(cache.get_trs(0).is_computed());
+        assert!(cache.get_trs(1).is_not_computed());
+        assert!(cache.get_trs(2).is_computed());
+        assert_eq!(cache.num_known_states(), 4);
+
+        // The counts of the evicted state are dropped along with its trs.
+        assert_eq!(cache.num_trs(0), Some(1));
+        assert_eq!(cache.num_trs(1), None);
+        assert_eq!(cache.num_input_epsilons(1), None);
+        assert_eq!(cache.num_output_epsilons(1), None);
+        assert_eq!(cache.compute_num_known_trs(), 2);
+    }
+
+    #[test]
+    fn test_lru_cache_evicts_final_weights_with_trs() {
+        let cache = LruCache::<TropicalWeight>::new(CacheOptions::new(1));
+        cache.insert_trs(0, trs(1));
+        cache.insert_final_weight(0, None);
+        cache.insert_final_weight(1, Some(TropicalWeight::one()));
+
+        assert_eq!(cache.num_cached_states(), 1);
+        assert!(cache.get_trs(0).is_not_computed());
+        assert!(cache.get_final_weight(0).is_not_computed());
+        assert_eq!(
+            cache.get_final_weight(1),
+            CacheStatus::Computed(Some(TropicalWeight::one()))
+        );
+    }
+
+    /// Linear acceptor 0 -> 1 -> ... -> n - 1 counting the number of expansions.
+    #[derive(Debug)]
+    struct ChainOp {
+        num_states: StateId,
+        num_computed_trs: Mutex<usize>,
+    }
+
+    impl FstOp<TropicalWeight> for ChainOp {
+        fn compute_start(&self) -> Result<Option<StateId>> {
+            Ok(Some(0))
+        }
+
+        fn compute_trs(&self, id: StateId) -> Result<TrsVec<TropicalWeight>> {
+            *self.num_computed_trs.lock().unwrap() += 1;
+            let mut trs = TrsVec::default();
+            if id + 1 < self.num_states {
+                trs.push(Tr::new(
+                    id + 1,
+                    id + 1,
+                    TropicalWeight::new(id as f32),
+                    id + 1,
+                ));
+            }
+            Ok(trs)
+        }
+
+        fn compute_final_weight(&self, id: StateId) -> Result<Option<TropicalWeight>> {
+            if id + 1 == self.num_states {
+                Ok(Some(TropicalWeight::one()))
+            } else {
+                Ok(None)
+            }
+        }
+
+        fn properties(&self) -> FstProperties {
+            FstProperties::empty()
+        }
+    }
+
+    #[test]
+    fn test_lru_cache_lazy_fst_bounded() -> Result<()> {
+        let num_states = 100;
+        let op = Arc::new(ChainOp {
+            num_states,
+            num_computed_trs: Mutex::new(0),
+        });
+        let cache = Arc::new(LruCache::new(CacheOptions::new(5)));
+        let lazy_fst = LazyFst::from_op_and_cache(Arc::clone(&op), Arc::clone(&cache), None, None);
+
+        let mut fst_ref = VectorFst::<TropicalWeight>::new();
+        fst_ref.add_states(num_states as usize);
+        fst_ref.set_start(0)?;
+        for s in 0..num_states - 1 {
+            fst_ref.add_tr(
+                s,
+                Tr::new(s + 1, s + 1, TropicalWeight::new(s as f32), s + 1),
+            )?;
+        }
+        fst_ref.set_final(num_states - 1, TropicalWeight::one())?;
+
+        let fst: VectorFst<_> = lazy_fst.compute()?;
+        assert_eq!(fst, fst_ref);
+        assert_eq!(cache.num_cached_states(), 5);
+        assert_eq!(cache.len_trs(), 5);
+        assert_eq!(cache.len_final_weights(), 5);
+        assert_eq!(cache.data.lock().unwrap().trs_counts.len(), 5);
+        assert_eq!(cache.data.lock().unwrap().recency.len(), 5);
+        assert_eq!(cache.num_known_states(), num_states as usize);
+
+        // The evicted states are recomputed on access.
+        let num_computed_trs = *op.num_computed_trs.lock().unwrap();
+        assert_eq!(lazy_fst.get_trs(0)?.trs(), fst_ref.get_trs(0)?.trs());
+        assert_eq!(*op.num_computed_trs.lock().unwrap(), num_computed_trs + 1);
+        assert_eq!(cache.num_cached_states(), 5);
+        assert_eq!(cache.data.lock().unwrap().trs_counts.len(), 5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lru_cache_lazy_fst_num_trs_after_eviction() -> Result<()> {
+        let op = Arc::new(ChainOp {
+            num_states: 3,
+            num_computed_trs: Mutex::new(0),
+        });
+        // Computing the final weight of a state evicts its trs.
+        let cache = Arc::new(LruCache::new(CacheOptions::new(1)));
+        let lazy_fst = LazyFst::from_op_and_cache(op, Arc::clone(&cache), None, None);
+
+        let num_trs: Vec<_> = lazy_fst.fst_iter().map(|data| data.num_trs).collect();
+        assert_eq!(num_trs, vec![1, 1, 0]);
+        assert!(cache.get_trs(0).is_not_computed());
+
+        // The counts of the evicted state are recomputed.
+        assert_eq!(cache.num_trs(0), None);
+        assert_eq!(lazy_fst.num_trs(0)?, 1);
+        assert_eq!(lazy_fst.num_input_epsilons(0)?, 0);
+        assert_eq!(lazy_fst.num_output_epsilons(0)?, 0);
+        assert_eq!(cache.num_cached_states(), 1);
+        assert!(lazy_fst.num_trs(3).is_err());
+        Ok(())
+    }
+}
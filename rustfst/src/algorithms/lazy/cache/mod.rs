@@ -2,6 +2,7 @@ pub(self) mod cache_internal_types;
 pub mod cache_status;
 pub mod first_cache;
 pub mod fst_cache;
+pub mod lru_cache;
 pub mod simple_hash_map_cache;
 pub mod simple_vec_cache;
 pub(self) mod utils_parsing;
@@ -10,6 +11,7 @@ pub(self) mod utils_serialization;
 pub use self::cache_status::CacheStatus;
 pub use self::first_cache::FirstCache;
 pub use self::fst_cache::FstCache;
+pub use self::lru_cache::{CacheOptions, LruCache};
 pub use self::simple_hash_map_cache::SimpleHashMapCache;
 pub use self::simple_vec_cache::SimpleVecCache;
 
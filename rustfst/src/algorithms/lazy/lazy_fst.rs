@@ -18,7 +18,7 @@ use crate::fst_traits::{
     AllocableFst, CoreFst, Fst, FstIterData, FstIterator, MutableFst, StateIterator,
 };
 use crate::semirings::{Semiring, SerializableSemiring};
-use crate::{StateId, SymbolTable, Trs, TrsVec};
+use crate::{FstError, StateId, SymbolTable, Trs, TrsVec, EPS_LABEL};
 
 #[derive(Debug, Clone)]
 pub struct LazyFst<W: Semiring, Op: FstOp<W>, Cache> {
@@ -61,13 +61,14 @@ impl<W: Semiring, Op: FstOp<W>, Cache: FstCache<W>> CoreFst<W> for LazyFst<W, Op
     }
 
     fn num_trs(&self, s: StateId) -> Result<usize> {
-        self.cache
-            .num_trs(s)
-            .ok_or_else(|| format_err!("State {:?} doesn't exist", s))
+        match self.cache.num_trs(s) {
+            Some(n) => Ok(n),
+            None => Ok(self.get_trs_evicted(s)?.len()),
+        }
     }
 
     unsafe fn num_trs_unchecked(&self, s: StateId) -> usize {
-        self.cache.num_trs(s).unsafe_unwrap()
+        self.num_trs(s).unsafe_unwrap()
     }
 
     fn get_trs(&self, state_id: StateId) -> Result<Self::TRS> {
@@ -90,15 +91,27 @@ impl<W: Semiring, Op: FstOp<W>, Cache: FstCache<W>> CoreFst<W> for LazyFst<W, Op
     }
 
     fn num_input_epsilons(&self, state: StateId) -> Result<usize> {
-        self.cache
-            .num_input_epsilons(state)
-            .ok_or_else(|| format_err!("State {:?} doesn't exist", state))
+        match self.cache.num_input_epsilons(state) {
+            Some(n) => Ok(n),
+            None => Ok(self
+                .get_trs_evicted(state)?
+                .trs()
+                .iter()
+                .filter(|tr| tr.ilabel == EPS_LABEL)
+                .count()),
+        }
     }
 
     fn num_output_epsilons(&self, state: StateId) -> Result<usize> {
-        self.cache
-            .num_output_epsilons(state)
-            .ok_or_else(|| format_err!("State {:?} doesn't exist", state))
+        match self.cache.num_output_epsilons(state) {
+            Some(n) => Ok(n),
+            None => Ok(self
+                .get_trs_evicted(state)?
+                .trs()
+                .iter()
+                .filter(|tr| tr.olabel == EPS_LABEL)
+                .count()),
+        }
     }
 }
 
@@ -158,11 +171,14 @@ where
     fn fst_iter(&'a self) -> Self::FstIter {
         let it = repeat(self);
         izip!(self.states_iter(), it).map(Box::new(|(state_id, p): (StateId, &'a Self)| {
+            let trs = unsafe { p.get_trs_unchecked(state_id) };
+            // Computing the final weight may evict the trs from the cache.
+            let num_trs = trs.len();
             FstIterData {
                 state_id,
-                trs: unsafe { p.get_trs_unchecked(state_id) },
+                trs,
                 final_weight: unsafe { p.final_weight_unchecked(state_id) },
-                num_trs: unsafe { p.num_trs_unchecked(state_id) },
+                num_trs,
             }
         }))
     }
@@ -236,7 +252,7 @@ where
         visited_states[start_state as usize] = true;
         queue.push_back(start_state);
         while let Some(s) = queue.pop_front() {
-            let trs_owner = unsafe { self.get_trs_unchecked(s) };
+            let trs_owner = self.get_trs(s)?;
             for tr in trs_owner.trs() {
                 if (tr.nextstate as usize) >= visited_states.len() {
                     visited_states.resize(tr.nextstate as usize + 1, false);
@@ -259,6 +275,16 @@ where
         // TODO: Symbol tables should be set here
         Ok(fst_out)
     }
+
+    /// Trs of a state whose counts are missing from the cache. They are recomputed if the state
+    /// has already been reached, its trs having been evicted, and an error is returned otherwise.
+    fn get_trs_evicted(&self, state: StateId) -> Result<TrsVec<W>> {
+        if (state as usize) < self.cache.num_known_states() {
+            self.get_trs(state)
+        } else {
+            Err(FstError::StateNotFound(state).into())
+        }
+    }
 }
 
 impl<W, Op, Cache> SerializableLazyFst for LazyFst<W, Op, Cache>
@@ -11,7 +11,7 @@ use crate::algorithms::lazy::{CacheStatus, FstCache};
 use crate::fst_properties::FstProperties;
 use crate::fst_traits::{CoreFst, Fst, FstIterData, FstIterator, MutableFst, StateIterator};
 use crate::semirings::Semiring;
-use crate::{StateId, SymbolTable, Trs, TrsVec};
+use crate::{FstError, StateId, SymbolTable, Trs, TrsVec};
 use std::collections::{HashSet, VecDeque};
 
 #[derive(Debug)]
@@ -58,7 +58,7 @@ impl<W: Semiring, Op: FstOp2<W>, Cache: FstCache<W>> CoreFst<W> for LazyFst2<W,
     fn num_trs(&self, s: StateId) -> Result<usize> {
         self.cache
             .num_trs(s)
-            .ok_or_else(|| format_err!("State {:?} doesn't exist", s))
+            .ok_or_else(|| FstError::StateNotFound(s).into())
     }
 
     unsafe fn num_trs_unchecked(&self, s: StateId) -> usize {
@@ -88,13 +88,13 @@ impl<W: Semiring, Op: FstOp2<W>, Cache: FstCache<W>> CoreFst<W> for LazyFst2<W,
     fn num_input_epsilons(&self, state: StateId) -> Result<usize> {
         self.cache
             .num_input_epsilons(state)
-            .ok_or_else(|| format_err!("State {:?} doesn't exist", state))
+            .ok_or_else(|| FstError::StateNotFound(state).into())
     }
 
     fn num_output_epsilons(&self, state: StateId) -> Result<usize> {
         self.cache
             .num_output_epsilons(state)
-            .ok_or_else(|| format_err!("State {:?} doesn't exist", state))
+            .ok_or_else(|| FstError::StateNotFound(state).into())
     }
 }
 
@@ -625,6 +625,7 @@ impl TrIterCompare {
 mod tests {
     use crate::prelude::*;
     use algorithms::determinize::*;
+    use anyhow::Result;
     use proptest::prelude::*;
 
     proptest! {
@@ -653,4 +654,31 @@ mod tests {
             prop_assert!(isomorphic(&det, &min_det).unwrap())
         }
     }
+
+    #[test]
+    fn test_minimize_redundant_acceptor() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(5);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(0, Tr::new(2, 2, 1.0, 2))?;
+        fst.add_tr(1, Tr::new(3, 3, 1.0, 3))?;
+        fst.add_tr(2, Tr::new(3, 3, 1.0, 4))?;
+        fst.set_final(3, TropicalWeight::one())?;
+        fst.set_final(4, TropicalWeight::one())?;
+
+        let paths_ref: Vec<_> = fst.paths_iter().collect();
+
+        minimize(&mut fst)?;
+
+        // States 1 & 2 and states 3 & 4 are merged.
+        assert_eq!(fst.num_states(), 3);
+
+        let paths: Vec<_> = fst.paths_iter().collect();
+        assert_eq!(paths.len(), paths_ref.len());
+        for path in paths_ref {
+            assert!(paths.contains(&path), "Missing path {:?}", path);
+        }
+        Ok(())
+    }
 }
@@ -1,60 +1,99 @@
 pub use self::{
     add_super_final_state::add_super_final_state,
     all_pairs_shortest_distance::all_pairs_shortest_distance,
+    apply::apply,
+    complement::complement,
     condense::condense,
-    connect::connect,
+    connect::{connect, connect_with_mapping, num_accessible, num_coaccessible},
+    dedup_states::dedup_states,
+    difference::difference,
+    epsilon_normalize::{epsilon_normalize, EpsNormalizeType},
+    equivalent::equivalent,
     fst_convert::{fst_convert, fst_convert_from_ref},
+    intersect::intersect,
     inversion::invert,
+    is_cyclic::is_cyclic,
     isomorphic::{isomorphic, isomorphic_with_config, IsomorphicConfig},
     minimize::{acceptor_minimize, minimize, minimize_with_config, MinimizeConfig},
     optimize::optimize,
     projection::{project, ProjectType},
+    prune::prune,
     push::{
-        push, push_weights, push_weights_with_config, push_with_config, PushConfig, PushType,
-        PushWeightsConfig,
+        push, push_in_place, push_weights, push_weights_with_config, push_with_config, PushConfig,
+        PushType, PushWeightsConfig,
     },
+    quantize::quantize,
     queue::{Queue, QueueType},
     relabel_pairs::relabel_pairs,
-    reverse::reverse,
-    reweight::{reweight, ReweightType},
+    relabel_tables::relabel_tables,
+    retain_trs::retain_trs,
+    reverse::{reverse, reverse_with_config, ReverseConfig},
+    reweight::{reweight, reweight_with_config, ReweightConfig, ReweightType},
     rm_final_epsilon::rm_final_epsilon,
-    shortest_distance::{shortest_distance, shortest_distance_with_config, ShortestDistanceConfig},
+    scc::scc,
+    shortest_distance::{
+        shortest_distance, shortest_distance_acyclic, shortest_distance_to_final,
+        shortest_distance_to_final_with_config, shortest_distance_with_config,
+        ShortestDistanceConfig,
+    },
     shortest_path::{shortest_path, shortest_path_with_config, ShortestPathConfig},
     state_sort::state_sort,
     top_sort::top_sort,
     tr_map::{tr_map, FinalTr, MapFinalAction, TrMapper},
-    tr_sort::tr_sort,
+    tr_sort::{tr_sort, tr_sort_unique},
     tr_sum::tr_sum,
     tr_unique::tr_unique,
+    verify::verify,
     weight_convert::{weight_convert, WeightConverter},
 };
 
+#[cfg(feature = "randgen")]
+pub use self::randgen::{
+    randgen, randgen_with_config, LogProbTrSelector, RandGenConfig, TrSelector, UniformTrSelector,
+};
+
 mod add_super_final_state;
 mod all_pairs_shortest_distance;
+mod apply;
 pub mod closure;
+mod complement;
 pub mod compose;
 pub mod concat;
 mod condense;
 mod connect;
+mod dedup_states;
 pub mod determinize;
-pub(crate) mod dfs_visit;
+/// Depth-first traversal of an FST restricted to the trs kept by a `TrFilter`.
+pub mod dfs_visit;
+mod difference;
 pub mod encode;
+mod epsilon_normalize;
+mod equivalent;
 pub mod factor_weight;
 mod fst_convert;
+mod intersect;
 mod inversion;
+mod is_cyclic;
 mod isomorphic;
 mod minimize;
 mod optimize;
 mod partition;
 mod projection;
+mod prune;
 mod push;
+mod quantize;
 mod queue;
+#[cfg(feature = "randgen")]
+mod randgen;
 mod relabel_pairs;
+mod relabel_tables;
 pub mod replace;
+mod retain_trs;
 mod reverse;
 mod reweight;
 pub mod rm_epsilon;
 mod rm_final_epsilon;
+mod scc;
 mod shortest_distance;
 mod shortest_path;
 mod state_sort;
@@ -64,6 +103,7 @@ mod tr_sort;
 mod tr_sum;
 pub(crate) mod tr_unique;
 pub mod union;
+mod verify;
 mod weight_convert;
 
 /// Module that provides different structures implementing the `Queue` trait.
@@ -75,7 +115,8 @@ pub mod tr_filters;
 /// Module that provides structures implementing the `TrMapper` trait.
 pub mod tr_mappers;
 
-pub(crate) mod visitors;
+/// Module that provides structures implementing the `Visitor` trait.
+pub mod visitors;
 
 /// Module that provides structures implementing the `WeightConverter` trait.
 pub mod weight_converters;
@@ -83,7 +124,7 @@ pub mod weight_converters;
 /// Functions to compare / sort the Trs of an FST.
 pub mod tr_compares {
     pub use super::isomorphic::tr_compare;
-    pub use super::tr_sort::{ILabelCompare, OLabelCompare, TrCompare};
+    pub use super::tr_sort::{ILabelCompare, NextStateCompare, OLabelCompare, TrCompare};
 }
 
 pub mod lazy;
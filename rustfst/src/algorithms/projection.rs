@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use crate::fst_properties::mutable_properties::project_properties;
 use crate::fst_properties::FstProperties;
 use crate::fst_traits::MutableFst;
@@ -14,6 +16,9 @@ pub enum ProjectType {
 
 /// This operation projects an FST onto its domain or range by either copying
 /// each transition input label to its output label or vice versa.
+///
+/// The symbol tables follow the labels : with an input projection, the output
+/// symbol table is replaced by the input one (and vice versa).
 /// # Example 1
 ///
 /// ## Project input
@@ -74,6 +79,12 @@ pub fn project<W: Semiring, F: MutableFst<W>>(fst: &mut F, project_type: Project
                     unsafe { it_trs.set_olabel_unchecked(idx_tr, ilabel) }
                 }
             }
+            if let Some(symt) = fst.input_symbols() {
+                let symt = Arc::clone(symt);
+                fst.set_output_symbols(symt);
+            } else {
+                fst.take_output_symbols();
+            }
         }
         ProjectType::ProjectOutput => {
             for state in fst.states_range() {
@@ -84,6 +95,12 @@ pub fn project<W: Semiring, F: MutableFst<W>>(fst: &mut F, project_type: Project
                     unsafe { it_trs.set_ilabel_unchecked(idx_tr, olabel) }
                 }
             }
+            if let Some(symt) = fst.output_symbols() {
+                let symt = Arc::clone(symt);
+                fst.set_input_symbols(symt);
+            } else {
+                fst.take_input_symbols();
+            }
         }
     };
     fst.set_properties_with_mask(
@@ -94,14 +111,41 @@ pub fn project<W: Semiring, F: MutableFst<W>>(fst: &mut F, project_type: Project
 
 #[cfg(test)]
 mod tests {
+    use anyhow::Result;
     use proptest::prelude::*;
 
     use crate::fst_properties::FstProperties;
     use crate::fst_traits::CoreFst;
     use crate::prelude::*;
+    use crate::utils::transducer;
 
     use super::*;
 
+    #[test]
+    fn test_project_input_transducer() -> Result<()> {
+        let mut fst: VectorFst<TropicalWeight> =
+            transducer(&[1, 3], &[2, 4], TropicalWeight::one());
+        let isymt: Arc<SymbolTable> = Arc::new(vec!["a", "b", "c"].into_iter().collect());
+        let osymt: Arc<SymbolTable> = Arc::new(vec!["x", "y", "z", "w"].into_iter().collect());
+        fst.set_input_symbols(Arc::clone(&isymt));
+        fst.set_output_symbols(osymt);
+        let fst_ref = fst.clone();
+
+        project(&mut fst, ProjectType::ProjectInput);
+
+        for state in fst.states_range() {
+            let trs = fst.get_trs(state)?;
+            let trs_ref = fst_ref.get_trs(state)?;
+            for (tr, tr_ref) in trs.trs().iter().zip(trs_ref.trs()) {
+                assert_eq!(tr.ilabel, tr.olabel);
+                assert_eq!(tr.ilabel, tr_ref.ilabel);
+            }
+        }
+        assert_eq!(fst.input_symbols(), Some(&isymt));
+        assert_eq!(fst.output_symbols(), Some(&isymt));
+        Ok(())
+    }
+
     proptest! {
         #[test]
         fn test_project_input_proptest(mut fst in any::<VectorFst<TropicalWeight>>()) {
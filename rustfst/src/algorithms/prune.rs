@@ -0,0 +1,141 @@
+use anyhow::Result;
+
+use crate::algorithms::shortest_path::natural_less;
+use crate::algorithms::{connect, shortest_distance};
+use crate::fst_traits::{ExpandedFst, MutableFst};
+use crate::semirings::{Semiring, SemiringProperties};
+use crate::StateId;
+
+fn distance_at<W: Semiring>(distance: &[W], state: StateId) -> W {
+    distance
+        .get(state as usize)
+        .cloned()
+        .unwrap_or_else(W::zero)
+}
+
+/// This operation deletes states and transitions in the input FST that do not belong
+/// to a successful path whose weight is no more than the weight of the shortest path
+/// ⊗ `weight_threshold`. The weights need to be commutative and have the path property.
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::fst_traits::{MutableFst, PathsIterator};
+/// # use rustfst::algorithms::prune;
+/// # use rustfst::{FstPath, Tr};
+/// # fn main() -> Result<()> {
+/// let mut fst = VectorFst::<TropicalWeight>::new();
+/// let s0 = fst.add_state();
+/// let s1 = fst.add_state();
+/// fst.set_start(s0)?;
+/// fst.add_tr(s0, Tr::new(1, 1, 1.0, s1))?;
+/// fst.add_tr(s0, Tr::new(2, 2, 5.0, s1))?;
+/// fst.set_final(s1, TropicalWeight::one())?;
+///
+/// prune(&mut fst, TropicalWeight::new(2.0))?;
+///
+/// let paths: Vec<_> = fst.paths_iter().collect();
+/// assert_eq!(paths, vec![FstPath::new(vec![1], vec![1], TropicalWeight::new(1.0))]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn prune<W, F>(fst: &mut F, weight_threshold: W) -> Result<()>
+where
+    W: Semiring,
+    F: ExpandedFst<W> + MutableFst<W>,
+{
+    if !W::properties().contains(SemiringProperties::PATH | SemiringProperties::COMMUTATIVE) {
+        bail!("Prune : Weight needs to be commutative and have the path property")
+    }
+    let start = match fst.start() {
+        Some(start) => start,
+        None => return Ok(()),
+    };
+
+    let fdistance = shortest_distance(fst, false)?;
+    let idistance = shortest_distance(fst, true)?;
+    let limit = distance_at(&idistance, start).times(&weight_threshold)?;
+
+    let mut dead_states = vec![];
+    for state in fst.states_range() {
+        let fd = distance_at(&fdistance, state);
+        if natural_less(&limit, &fd.times(&distance_at(&idistance, state))?)? {
+            dead_states.push(state);
+            continue;
+        }
+
+        let trs = unsafe { fst.pop_trs_unchecked(state) };
+        let mut kept_trs = Vec::with_capacity(trs.len());
+        for tr in trs {
+            let weight = fd
+                .times(&tr.weight)?
+                .times(&distance_at(&idistance, tr.nextstate))?;
+            if !natural_less(&limit, &weight)? {
+                kept_trs.push(tr);
+            }
+        }
+        unsafe { fst.set_trs_unchecked(state, kept_trs) };
+
+        if let Some(final_weight) = unsafe { fst.final_weight_unchecked(state) } {
+            if natural_less(&limit, &fd.times(&final_weight)?)? {
+                unsafe { fst.delete_final_weight_unchecked(state) };
+            }
+        }
+    }
+
+    fst.del_states(dead_states)?;
+    connect(fst)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::PathsIterator;
+    use crate::semirings::TropicalWeight;
+    use crate::{FstPath, Tr};
+
+    fn two_paths_fst() -> Result<VectorFst<TropicalWeight>> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(4);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(1, Tr::new(2, 2, 0.5, 3))?;
+        fst.add_tr(0, Tr::new(3, 3, 4.0, 2))?;
+        fst.add_tr(2, Tr::new(4, 4, 1.0, 3))?;
+        fst.set_final(3, 0.5)?;
+        Ok(fst)
+    }
+
+    #[test]
+    fn test_prune_tight_threshold() -> Result<()> {
+        let mut fst = two_paths_fst()?;
+
+        prune(&mut fst, TropicalWeight::new(1.0))?;
+
+        assert_eq!(fst.num_states(), 3);
+        let paths: Vec<_> = fst.paths_iter().collect();
+        assert_eq!(
+            paths,
+            vec![FstPath::new(
+                vec![1, 2],
+                vec![1, 2],
+                TropicalWeight::new(2.0)
+            )]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_prune_loose_threshold() -> Result<()> {
+        let mut fst = two_paths_fst()?;
+
+        prune(&mut fst, TropicalWeight::new(10.0))?;
+
+        assert_eq!(fst, two_paths_fst()?);
+        Ok(())
+    }
+}
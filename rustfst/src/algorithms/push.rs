@@ -65,6 +65,8 @@ impl PushWeightsConfig {
     }
 }
 
+/// Pushes the weights in FST in the direction defined by `reweight_type`,
+/// see `push_weights_with_config`.
 pub fn push_weights<W, F>(fst: &mut F, reweight_type: ReweightType) -> Result<()>
 where
     F: MutableFst<W>,
@@ -249,13 +251,17 @@ where
     F1: ExpandedFst<W>,
     F2: ExpandedFst<W> + MutableFst<W> + AllocableFst<W>,
     W: WeaklyDivisibleSemiring + WeightQuantize,
-    <W as Semiring>::ReverseWeight: 'static,
 {
     push_with_config(ifst, reweight_type, push_type, PushConfig::default())
 }
 
 /// Pushes the weights and/or labels of the input FST into the output
 /// mutable FST by pushing weights and/or labels towards the initial state or final states.
+///
+/// The behaviour is selected with the `PushType` flags. Labels pushing is performed by
+/// converting the FST to the gallic semiring, pushing the gallic weights and converting back.
+/// If pushing labels towards the initial state, the output labels common to all the paths
+/// leaving a state are moved to the transitions closer to the initial state.
 pub fn push_with_config<W, F1, F2>(
     ifst: &F1,
     reweight_type: ReweightType,
@@ -266,7 +272,6 @@ where
     F1: ExpandedFst<W>,
     F2: ExpandedFst<W> + MutableFst<W> + AllocableFst<W>,
     W: WeaklyDivisibleSemiring + WeightQuantize,
-    <W as Semiring>::ReverseWeight: 'static,
 {
     let delta = config.delta;
     if push_type.intersects(PushType::PUSH_WEIGHTS) && !push_type.intersects(PushType::PUSH_LABELS)
@@ -303,3 +308,155 @@ where
         Ok(fst_convert_from_ref(ifst))
     }
 }
+
+/// Pushes in place the weights and/or labels of an FST, see `push_with_config`.
+///
+/// Only weights pushing is performed in place. Labels pushing goes through the gallic semiring
+/// and replaces the FST with the converted back one. With `PushType::REMOVE_COMMON_AFFIX`, the
+/// labels common to all the paths are removed instead of being pushed to the start state (or the
+/// final states when pushing towards them).
+pub fn push_in_place<W, F>(
+    fst: &mut F,
+    reweight_type: ReweightType,
+    push_type: PushType,
+    config: PushConfig,
+) -> Result<()>
+where
+    F: ExpandedFst<W> + MutableFst<W> + AllocableFst<W>,
+    W: WeaklyDivisibleSemiring + WeightQuantize,
+{
+    if push_type.intersects(PushType::PUSH_LABELS) {
+        let pushed_fst: F = push_with_config(&*fst, reweight_type, push_type, config)?;
+        *fst = pushed_fst;
+    } else if push_type.intersects(PushType::PUSH_WEIGHTS) {
+        let push_weights_config = PushWeightsConfig::new(
+            config.delta,
+            push_type.intersects(PushType::REMOVE_TOTAL_WEIGHT),
+        );
+        push_weights_with_config(fst, reweight_type, push_weights_config)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    use crate::fst_traits::{CoreFst, PathsIterator};
+    use crate::semirings::TropicalWeight;
+    use crate::{FstPath, Tr, Trs, EPS_LABEL};
+
+    #[test]
+    fn test_push_weights_to_initial() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(4);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(0, Tr::new(2, 2, 2.0, 2))?;
+        fst.add_tr(1, Tr::new(3, 3, 3.0, 3))?;
+        fst.add_tr(1, Tr::new(4, 4, 5.0, 3))?;
+        fst.add_tr(2, Tr::new(5, 5, 1.5, 3))?;
+        fst.set_final(2, 4.0)?;
+        fst.set_final(3, 0.5)?;
+
+        push_weights(&mut fst, ReweightType::ReweightToInitial)?;
+
+        // The shortest path from the start state is 0 -> 2 -> 3 with weight 4.0.
+        let mut start_sum = fst.final_weight(0)?.unwrap_or_else(TropicalWeight::zero);
+        for tr in fst.get_trs(0)?.trs() {
+            start_sum.plus_assign(&tr.weight)?;
+        }
+        assert_eq!(start_sum, TropicalWeight::new(4.0));
+
+        for state in fst.states_range().filter(|s| *s != 0) {
+            let mut sum = fst
+                .final_weight(state)?
+                .unwrap_or_else(TropicalWeight::zero);
+            for tr in fst.get_trs(state)?.trs() {
+                sum.plus_assign(&tr.weight)?;
+            }
+            assert_eq!(sum, TropicalWeight::one());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_push_labels_to_initial() -> Result<()> {
+        let (a, b, c) = (10, 11, 12);
+        // Two paths whose output strings "ab" and "abc" share the prefix "ab",
+        // emitted after the first transition.
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(7);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, EPS_LABEL, 1.0, 1))?;
+        fst.add_tr(1, Tr::new(2, a, 1.0, 2))?;
+        fst.add_tr(2, Tr::new(3, b, 1.0, 3))?;
+        fst.add_tr(0, Tr::new(4, EPS_LABEL, 2.0, 4))?;
+        fst.add_tr(4, Tr::new(5, a, 1.0, 5))?;
+        fst.add_tr(5, Tr::new(6, b, 1.0, 6))?;
+        fst.add_tr(6, Tr::new(7, c, 1.0, 3))?;
+        fst.set_final(3, TropicalWeight::one())?;
+
+        let pushed_fst: VectorFst<TropicalWeight> =
+            push(&fst, ReweightType::ReweightToInitial, PushType::PUSH_LABELS)?;
+
+        let paths: HashSet<_> = fst.paths_iter().collect();
+        let pushed_paths: HashSet<_> = pushed_fst.paths_iter().collect();
+        assert_eq!(pushed_paths, paths);
+
+        // The common prefix is now emitted by the first transitions.
+        let start = pushed_fst.start().unwrap();
+        for tr in pushed_fst.get_trs(start)?.trs() {
+            assert_eq!(tr.olabel, a);
+            for next_tr in pushed_fst.get_trs(tr.nextstate)?.trs() {
+                assert_eq!(next_tr.olabel, b);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_push_in_place_remove_common_affix() -> Result<()> {
+        let (a, b, c) = (10, 11, 12);
+        // Output strings "ab", "ab" and "acb" sharing the prefix "a".
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(4);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, a, 1.0, 1))?;
+        fst.add_tr(1, Tr::new(2, b, 1.0, 3))?;
+        fst.add_tr(0, Tr::new(3, a, 2.0, 2))?;
+        fst.add_tr(2, Tr::new(4, b, 1.0, 3))?;
+        fst.add_tr(2, Tr::new(5, c, 1.0, 1))?;
+        fst.set_final(3, TropicalWeight::one())?;
+
+        let mut pushed_fst = fst.clone();
+        push_in_place(
+            &mut pushed_fst,
+            ReweightType::ReweightToInitial,
+            PushType::PUSH_LABELS,
+            PushConfig::default(),
+        )?;
+        let paths: HashSet<_> = fst.paths_iter().collect();
+        let pushed_paths: HashSet<_> = pushed_fst.paths_iter().collect();
+        assert_eq!(pushed_paths, paths);
+
+        push_in_place(
+            &mut fst,
+            ReweightType::ReweightToInitial,
+            PushType::PUSH_LABELS | PushType::REMOVE_COMMON_AFFIX,
+            PushConfig::default(),
+        )?;
+        let paths: HashSet<_> = fst.paths_iter().collect();
+        let expected_paths: HashSet<_> = vec![
+            FstPath::new(vec![1, 2], vec![b], TropicalWeight::new(2.0)),
+            FstPath::new(vec![3, 4], vec![b], TropicalWeight::new(3.0)),
+            FstPath::new(vec![3, 5, 2], vec![c, b], TropicalWeight::new(4.0)),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(paths, expected_paths);
+        Ok(())
+    }
+}
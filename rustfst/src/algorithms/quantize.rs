@@ -0,0 +1,85 @@
+use anyhow::Result;
+
+use crate::algorithms::tr_map;
+use crate::algorithms::tr_mappers::QuantizeMapper;
+use crate::fst_traits::MutableFst;
+use crate::semirings::{Semiring, WeightQuantize};
+
+/// Rounds the weights of all the transitions and final weights of an FST to the nearest
+/// multiple of `delta`. Infinite weights are left unchanged.
+///
+/// Quantizing the weights allows weights that are almost equal to be considered equal, which
+/// is useful before determinizing or minimizing an FST with float weights.
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::fst_traits::CoreFst;
+/// # use rustfst::algorithms::quantize;
+/// # use rustfst::utils::acceptor;
+/// # fn main() -> Result<()> {
+/// let mut fst: VectorFst<TropicalWeight> = acceptor(&[1], TropicalWeight::new(1.3));
+/// quantize(&mut fst, 0.5)?;
+/// assert_eq!(fst.final_weight(1)?, Some(TropicalWeight::new(1.5)));
+/// # Ok(())
+/// # }
+/// ```
+pub fn quantize<W, F>(fst: &mut F, delta: f32) -> Result<()>
+where
+    W: Semiring + WeightQuantize,
+    F: MutableFst<W>,
+{
+    tr_map(fst, &QuantizeMapper::new(delta))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::CoreFst;
+    use crate::semirings::{LogWeight, TropicalWeight};
+    use crate::{Tr, Trs};
+
+    #[test]
+    fn test_quantize_tropical() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(3);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 0.2, 1))?;
+        fst.add_tr(0, Tr::new(2, 2, 1.3, 2))?;
+        fst.add_tr(1, Tr::new(3, 3, 2.74, 2))?;
+        fst.add_tr(1, Tr::new(4, 4, TropicalWeight::zero(), 2))?;
+        fst.set_final(2, 0.8)?;
+
+        quantize(&mut fst, 0.5)?;
+
+        let weights: Vec<_> = fst
+            .get_trs(0)?
+            .trs()
+            .iter()
+            .chain(fst.get_trs(1)?.trs().iter())
+            .map(|tr| *tr.weight.value())
+            .collect();
+        assert_eq!(weights, vec![0.0, 1.5, 2.5, std::f32::INFINITY]);
+        assert_eq!(fst.final_weight(2)?, Some(TropicalWeight::new(1.0)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_quantize_log() -> Result<()> {
+        let mut fst = VectorFst::<LogWeight>::new();
+        fst.add_states(2);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 1.26, 1))?;
+        fst.set_final(1, 0.74)?;
+
+        quantize(&mut fst, 0.5)?;
+
+        assert_eq!(fst.get_trs(0)?.trs()[0].weight, LogWeight::new(1.5));
+        assert_eq!(fst.final_weight(1)?, Some(LogWeight::new(0.5)));
+        Ok(())
+    }
+}
@@ -32,7 +32,7 @@ impl AutoQueue {
         if props.contains(FstProperties::TOP_SORTED) || fst.start().is_none() {
             queue = Box::new(StateOrderQueue::default());
         } else if props.contains(FstProperties::ACYCLIC) {
-            queue = Box::new(TopOrderQueue::new(fst, tr_filter));
+            queue = Box::new(TopOrderQueue::new(fst, tr_filter)?);
         } else if props.contains(FstProperties::UNWEIGHTED)
             && W::properties().contains(SemiringProperties::IDEMPOTENT)
         {
@@ -1,10 +1,12 @@
+use anyhow::Result;
+
 use crate::algorithms::dfs_visit::dfs_visit;
 use crate::algorithms::top_sort::TopOrderVisitor;
 use crate::algorithms::tr_filters::TrFilter;
 use crate::algorithms::{Queue, QueueType};
 use crate::fst_traits::ExpandedFst;
 use crate::semirings::Semiring;
-use crate::StateId;
+use crate::{FstError, StateId};
 
 /// Topological-order queue discipline, templated on the StateId. States are
 /// ordered in the queue topologically. The FST must be acyclic.
@@ -17,13 +19,17 @@ pub struct TopOrderQueue {
 }
 
 impl TopOrderQueue {
-    pub fn new<W: Semiring, F: ExpandedFst<W>, A: TrFilter<W>>(fst: &F, tr_filter: &A) -> Self {
+    /// Returns `FstError::Cyclic` if the FST restricted to the trs kept by `tr_filter` is cyclic.
+    pub fn new<W: Semiring, F: ExpandedFst<W>, A: TrFilter<W>>(
+        fst: &F,
+        tr_filter: &A,
+    ) -> Result<Self> {
         let mut visitor = TopOrderVisitor::new();
         dfs_visit(fst, &mut visitor, tr_filter, false);
         if !visitor.acyclic {
-            panic!("Unexpectted Acyclic FST for TopOprerQueue");
+            bail!(FstError::Cyclic);
         }
-        Self::from_precomputed_order(visitor.order)
+        Ok(Self::from_precomputed_order(visitor.order))
     }
 
     pub fn from_precomputed_order(order: Vec<StateId>) -> Self {
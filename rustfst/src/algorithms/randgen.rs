@@ -0,0 +1,298 @@
+use anyhow::Result;
+use rand_chacha::rand_core::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::fst_traits::{ExpandedFst, MutableFst};
+use crate::semirings::Semiring;
+use crate::{StateId, Tr, Trs};
+
+/// Returns a random integer uniformly distributed in `[0, n)`.
+fn gen_index<R: RngCore>(rng: &mut R, n: usize) -> usize {
+    ((u128::from(rng.next_u64()) * n as u128) >> 64) as usize
+}
+
+/// Returns a random float uniformly distributed in `[0, 1)`.
+fn gen_unit_f32<R: RngCore>(rng: &mut R) -> f32 {
+    (rng.next_u32() >> 8) as f32 / (1u32 << 24) as f32
+}
+
+/// Selects the transition to follow from a state during the random generation of paths.
+///
+/// The random numbers are drawn from a `RngCore` of the `rand_core` crate.
+pub trait TrSelector<W: Semiring> {
+    /// Returns the index of the selected tr. An index equal to the number of trs of the state
+    /// means that the generation stops at this (final) state. `None` is returned if no
+    /// transition can be selected (non-final state without trs).
+    fn select_tr<F: ExpandedFst<W>, R: RngCore>(
+        &self,
+        rng: &mut R,
+        fst: &F,
+        state: StateId,
+    ) -> Result<Option<usize>>;
+}
+
+/// Selects uniformly at random among the trs and the final weight of a state.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct UniformTrSelector {}
+
+impl<W: Semiring> TrSelector<W> for UniformTrSelector {
+    fn select_tr<F: ExpandedFst<W>, R: RngCore>(
+        &self,
+        rng: &mut R,
+        fst: &F,
+        state: StateId,
+    ) -> Result<Option<usize>> {
+        let n = fst.num_trs(state)? + if fst.is_final(state)? { 1 } else { 0 };
+        if n == 0 {
+            return Ok(None);
+        }
+        Ok(Some(gen_index(rng, n)))
+    }
+}
+
+/// Selects a tr or the final weight of a state with a probability proportional to
+/// `exp(-w)`. Suitable for weights storing negative log probabilities
+/// (e.g `TropicalWeight` and `LogWeight`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct LogProbTrSelector {}
+
+impl<W: Semiring<Type = f32>> TrSelector<W> for LogProbTrSelector {
+    fn select_tr<F: ExpandedFst<W>, R: RngCore>(
+        &self,
+        rng: &mut R,
+        fst: &F,
+        state: StateId,
+    ) -> Result<Option<usize>> {
+        let trs = fst.get_trs(state)?;
+        let mut neg_log_probs: Vec<f32> = trs.trs().iter().map(|tr| *tr.weight.value()).collect();
+        if let Some(final_weight) = fst.final_weight(state)? {
+            neg_log_probs.push(*final_weight.value());
+        }
+
+        // Shifts the weights by the lowest one for numerical stability.
+        let min = neg_log_probs.iter().cloned().fold(f32::INFINITY, f32::min);
+        if !min.is_finite() {
+            return Ok(None);
+        }
+        let probs: Vec<f32> = neg_log_probs.iter().map(|w| (min - w).exp()).collect();
+        let total: f32 = probs.iter().sum();
+
+        let r = gen_unit_f32(rng) * total;
+        let mut cumulative = 0.0;
+        for (idx, p) in probs.iter().enumerate() {
+            cumulative += p;
+            if r < cumulative {
+                return Ok(Some(idx));
+            }
+        }
+        // Only reachable due to rounding errors.
+        Ok(probs.iter().rposition(|p| *p > 0.0))
+    }
+}
+
+/// Configuration of the random generation of paths.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RandGenConfig<S> {
+    selector: S,
+    npath: usize,
+    seed: u64,
+    max_length: usize,
+}
+
+impl<S> RandGenConfig<S> {
+    pub fn new(selector: S) -> Self {
+        Self {
+            selector,
+            npath: 1,
+            seed: 0,
+            max_length: std::usize::MAX,
+        }
+    }
+
+    pub fn with_npath(self, npath: usize) -> Self {
+        Self { npath, ..self }
+    }
+
+    pub fn with_seed(self, seed: u64) -> Self {
+        Self { seed, ..self }
+    }
+
+    pub fn with_max_length(self, max_length: usize) -> Self {
+        Self { max_length, ..self }
+    }
+}
+
+/// Randomly generates `npath` successful paths in the input FST, selecting at each state a
+/// transition (or the final weight) with a probability proportional to `exp(-w)`.
+/// The generation is reproducible for a given `seed`, across platforms and versions of the
+/// dependencies : the random numbers are drawn from a `ChaCha8Rng`.
+///
+/// Only available with the `randgen` feature.
+///
+/// The output FST is the union of the generated paths, each one being a new branch from the
+/// start state, with the weights of the input FST.
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::fst_traits::{MutableFst, PathsIterator};
+/// # use rustfst::algorithms::randgen;
+/// # use rustfst::{check_path_in_fst, Tr};
+/// # fn main() -> Result<()> {
+/// let mut fst = VectorFst::<TropicalWeight>::new();
+/// let s0 = fst.add_state();
+/// let s1 = fst.add_state();
+/// fst.set_start(s0)?;
+/// fst.add_tr(s0, Tr::new(1, 1, 0.5, s1))?;
+/// fst.add_tr(s0, Tr::new(2, 2, 1.5, s1))?;
+/// fst.set_final(s1, TropicalWeight::one())?;
+///
+/// let paths_fst: VectorFst<_> = randgen(&fst, 10, 42)?;
+/// for path in paths_fst.paths_iter() {
+///     assert!(check_path_in_fst(&fst, &path));
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn randgen<W, F1, F2>(fst: &F1, npath: usize, seed: u64) -> Result<F2>
+where
+    W: Semiring<Type = f32>,
+    F1: ExpandedFst<W>,
+    F2: MutableFst<W>,
+{
+    let config = RandGenConfig::new(LogProbTrSelector {})
+        .with_npath(npath)
+        .with_seed(seed);
+    randgen_with_config(fst, config)
+}
+
+/// Randomly generates successful paths in the input FST using the `TrSelector` and the
+/// parameters of the config. Paths reaching a dead end or longer than `max_length` are discarded.
+pub fn randgen_with_config<W, F1, F2, S>(fst: &F1, config: RandGenConfig<S>) -> Result<F2>
+where
+    W: Semiring,
+    F1: ExpandedFst<W>,
+    F2: MutableFst<W>,
+    S: TrSelector<W>,
+{
+    let mut ofst = F2::new();
+    let start = match fst.start() {
+        Some(start) => start,
+        None => return Ok(ofst),
+    };
+    let ostart = ofst.add_state();
+    ofst.set_start(ostart)?;
+
+    let mut rng = ChaCha8Rng::seed_from_u64(config.seed);
+
+    for _ in 0..config.npath {
+        let mut state = start;
+        let mut trs_path: Vec<Tr<W>> = vec![];
+        let final_weight = loop {
+            if trs_path.len() > config.max_length {
+                break None;
+            }
+            let idx = match config.selector.select_tr(&mut rng, fst, state)? {
+                Some(idx) => idx,
+                None => break None,
+            };
+            let trs = fst.get_trs(state)?;
+            match trs.trs().get(idx) {
+                Some(tr) => {
+                    state = tr.nextstate;
+                    trs_path.push(tr.clone());
+                }
+                None => break fst.final_weight(state)?,
+            }
+        };
+
+        if let Some(final_weight) = final_weight {
+            let mut ostate = ostart;
+            for tr in trs_path {
+                let nextstate = ofst.add_state();
+                ofst.add_tr(ostate, Tr::new(tr.ilabel, tr.olabel, tr.weight, nextstate))?;
+                ostate = nextstate;
+            }
+            ofst.set_final(ostate, final_weight)?;
+        }
+    }
+
+    ofst.set_symts_from_fst(fst);
+    Ok(ofst)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::PathsIterator;
+    use crate::semirings::TropicalWeight;
+    use crate::{check_path_in_fst, FstPath};
+
+    fn lattice() -> Result<VectorFst<TropicalWeight>> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(3);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 0.5, 1))?;
+        fst.add_tr(0, Tr::new(2, 2, 1.0, 1))?;
+        fst.add_tr(1, Tr::new(3, 3, 0.2, 2))?;
+        fst.add_tr(1, Tr::new(4, 4, 2.0, 2))?;
+        fst.add_tr(2, Tr::new(5, 5, 1.0, 0))?;
+        fst.set_final(2, 0.1)?;
+        Ok(fst)
+    }
+
+    #[test]
+    fn test_randgen_reproducible() -> Result<()> {
+        let fst = lattice()?;
+
+        let paths_1: Vec<FstPath<_>> = randgen::<_, _, VectorFst<_>>(&fst, 20, 7)?
+            .paths_iter()
+            .collect();
+        let paths_2: Vec<FstPath<_>> = randgen::<_, _, VectorFst<_>>(&fst, 20, 7)?
+            .paths_iter()
+            .collect();
+
+        assert_eq!(paths_1.len(), 20);
+        assert_eq!(paths_1, paths_2);
+        for path in paths_1.iter() {
+            assert!(check_path_in_fst(&fst, path));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_gen_index_uniform() {
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        let mut counts = [0usize; 4];
+        for _ in 0..4000 {
+            counts[gen_index(&mut rng, 4)] += 1;
+        }
+        assert!(counts.iter().all(|c| *c > 800 && *c < 1200));
+
+        for _ in 0..1000 {
+            let r = gen_unit_f32(&mut rng);
+            assert!((0.0..1.0).contains(&r));
+        }
+    }
+
+    #[test]
+    fn test_randgen_uniform_max_length() -> Result<()> {
+        let fst = lattice()?;
+        let config = RandGenConfig::new(UniformTrSelector {})
+            .with_npath(20)
+            .with_seed(3)
+            .with_max_length(2);
+
+        let paths_fst: VectorFst<_> = randgen_with_config(&fst, config)?;
+
+        for path in paths_fst.paths_iter() {
+            assert_eq!(path.ilabels.len(), 2);
+            assert!(check_path_in_fst(&fst, &path));
+        }
+        Ok(())
+    }
+}
@@ -1,33 +1,56 @@
 use std::collections::hash_map::Entry;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use anyhow::{bail, format_err, Context, Result};
 
 use crate::fst_traits::MutableFst;
 use crate::semirings::Semiring;
-use crate::StateId;
+use crate::{Label, Trs};
 
-fn iterator_to_hashmap<I>(pairs: I) -> Result<HashMap<StateId, StateId>>
+fn iterator_to_hashmap<I>(pairs: I) -> Result<HashMap<Label, Label>>
 where
-    I: IntoIterator<Item = (StateId, StateId)>,
+    I: IntoIterator<Item = (Label, Label)>,
 {
     let mut map_labels = HashMap::new();
-    for (s1, s2) in pairs {
-        match map_labels.entry(s1) {
-            Entry::Occupied(_) => bail!("State {:?} is present twice in the relabeling pairs", s1),
+    let mut new_labels = HashSet::new();
+    for (l1, l2) in pairs {
+        if !new_labels.insert(l2) {
+            bail!("Label {:?} is the target of several relabeling pairs", l2)
+        }
+        match map_labels.entry(l1) {
+            Entry::Occupied(_) => bail!("Label {:?} is present twice in the relabeling pairs", l1),
             Entry::Vacant(v) => {
-                v.insert(s2);
+                v.insert(l2);
             }
         }
     }
     Ok(map_labels)
 }
 
+/// Checks that no label is mapped to a label of the FST that is left unchanged.
+fn check_unmapped_collisions(
+    map_labels: &HashMap<Label, Label>,
+    unmapped_labels: &HashSet<Label>,
+) -> Result<()> {
+    for (l1, l2) in map_labels.iter() {
+        if unmapped_labels.contains(l2) {
+            bail!(
+                "Label {:?} is relabeled to {:?} which is already present and left unchanged",
+                l1,
+                l2
+            )
+        }
+    }
+    Ok(())
+}
+
 /// Replaces input and/or output labels using pairs of labels.
 ///
 /// This operation destructively relabels the input and/or output labels of the
 /// FST using pairs of the form (old_ID, new_ID); omitted indices are
-/// identity-mapped.
+/// identity-mapped. An error is returned if two pairs share the same old label
+/// or the same new label, or if a new label is already used by the FST and left
+/// unchanged. In that case, the FST is left untouched.
 ///
 /// # Example
 /// ```
@@ -49,8 +72,8 @@ pub fn relabel_pairs<W, F, I, J>(fst: &mut F, ipairs: I, opairs: J) -> Result<()
 where
     W: Semiring,
     F: MutableFst<W>,
-    I: IntoIterator<Item = (StateId, StateId)>,
-    J: IntoIterator<Item = (StateId, StateId)>,
+    I: IntoIterator<Item = (Label, Label)>,
+    J: IntoIterator<Item = (Label, Label)>,
 {
     let map_ilabels = iterator_to_hashmap(ipairs)
         .with_context(|| format_err!("Error while creating the HashMap for ipairs"))?;
@@ -58,6 +81,23 @@ where
     let map_olabels = iterator_to_hashmap(opairs)
         .with_context(|| format_err!("Error while creating the HashMap for opairs"))?;
 
+    let mut unmapped_ilabels = HashSet::new();
+    let mut unmapped_olabels = HashSet::new();
+    for state_id in fst.states_range() {
+        for tr in unsafe { fst.get_trs_unchecked(state_id) }.trs() {
+            if !map_ilabels.contains_key(&tr.ilabel) {
+                unmapped_ilabels.insert(tr.ilabel);
+            }
+            if !map_olabels.contains_key(&tr.olabel) {
+                unmapped_olabels.insert(tr.olabel);
+            }
+        }
+    }
+    check_unmapped_collisions(&map_ilabels, &unmapped_ilabels)
+        .with_context(|| format_err!("Error while checking the ipairs"))?;
+    check_unmapped_collisions(&map_olabels, &unmapped_olabels)
+        .with_context(|| format_err!("Error while checking the opairs"))?;
+
     for state_id in fst.states_range() {
         unsafe {
             let mut it_tr = fst.tr_iter_unchecked_mut(state_id);
@@ -81,8 +121,11 @@ where
 #[cfg(test)]
 mod tests {
     use crate::fst_impls::VectorFst;
+    use crate::fst_traits::CoreFst;
     use crate::semirings::{IntegerWeight, Semiring};
     use crate::tr::Tr;
+    use crate::utils::transducer;
+    use crate::Trs;
 
     use super::*;
 
@@ -125,4 +168,48 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_relabel_pairs_targeted_labels() -> Result<()> {
+        let mut fst = VectorFst::<IntegerWeight>::new();
+        let s0 = fst.add_state();
+        let s1 = fst.add_state();
+        fst.set_start(s0)?;
+        fst.add_tr(s0, Tr::new(1, 2, 1, s1))?;
+        fst.add_tr(s0, Tr::new(3, 4, 1, s1))?;
+        fst.set_final(s1, IntegerWeight::one())?;
+
+        relabel_pairs(&mut fst, vec![(1, 10), (10, 1)], vec![(4, 40)])?;
+
+        let trs = fst.get_trs(s0)?;
+        assert_eq!(trs.trs()[0], Tr::new(10, 2, 1, s1));
+        assert_eq!(trs.trs()[1], Tr::new(3, 40, 1, s1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_relabel_pairs_collision() {
+        let mut fst: VectorFst<IntegerWeight> = transducer(&[1, 2], &[3, 4], IntegerWeight::one());
+        let fst_ref = fst.clone();
+
+        assert!(relabel_pairs(&mut fst, vec![(1, 5), (2, 5)], vec![]).is_err());
+        assert!(relabel_pairs(&mut fst, vec![], vec![(3, 6), (3, 7)]).is_err());
+        assert_eq!(fst, fst_ref);
+    }
+
+    #[test]
+    fn test_relabel_pairs_collision_unmapped_label() -> Result<()> {
+        let mut fst: VectorFst<IntegerWeight> = transducer(&[1, 2], &[3, 4], IntegerWeight::one());
+        let fst_ref = fst.clone();
+
+        // Labels 2 and 4 are left unchanged.
+        assert!(relabel_pairs(&mut fst, vec![(1, 2)], vec![]).is_err());
+        assert!(relabel_pairs(&mut fst, vec![], vec![(3, 4)]).is_err());
+        assert_eq!(fst, fst_ref);
+
+        // Swapping the labels is fine.
+        relabel_pairs(&mut fst, vec![(1, 2), (2, 1)], vec![(3, 4), (4, 3)])?;
+        assert_eq!(fst, transducer(&[2, 1], &[4, 3], IntegerWeight::one()));
+        Ok(())
+    }
 }
@@ -0,0 +1,191 @@
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::algorithms::relabel_pairs;
+use crate::fst_traits::MutableFst;
+use crate::semirings::Semiring;
+use crate::{FstError, Label, SymbolTable, Trs, EPS_LABEL};
+
+/// Builds the relabeling pairs mapping the labels, sorted by increasing value, to `1..=n` along
+/// with the symbol table of the new labels.
+fn dense_relabeling<I>(
+    labels: I,
+    symt: Option<&Arc<SymbolTable>>,
+) -> Result<(Vec<(Label, Label)>, SymbolTable)>
+where
+    I: IntoIterator<Item = Label>,
+{
+    let labels: BTreeSet<_> = labels.into_iter().filter(|l| *l != EPS_LABEL).collect();
+    let mut pairs = Vec::with_capacity(labels.len());
+    let mut new_symt = SymbolTable::new();
+    new_symt.reserve(labels.len());
+    for label in labels {
+        let symbol = match symt {
+            Some(symt) => symt
+                .get_symbol(label)
+                .ok_or(FstError::LabelNotFound(label))?
+                .to_string(),
+            None => format!("{}", label),
+        };
+        pairs.push((label, new_symt.add_symbol(symbol)));
+    }
+    Ok((pairs, new_symt))
+}
+
+/// Renumbers densely the input and output labels of an FST, starting from 1 and keeping
+/// epsilon at 0. The used labels keep their relative order.
+///
+/// The input and output symbol tables of the FST are replaced by tables containing only the
+/// symbols of the used labels, with their new label. When the FST has no symbol table, the
+/// symbols are the old labels. The new input and output symbol tables are returned.
+///
+/// An error is returned if a label is missing from the corresponding symbol table.
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::algorithms::relabel_tables;
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::fst_traits::{CoreFst, MutableFst};
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::{Tr, Trs};
+/// # fn main() -> Result<()> {
+/// let mut fst = VectorFst::<TropicalWeight>::new();
+/// fst.add_states(2);
+/// fst.set_start(0)?;
+/// fst.add_tr(0, Tr::new(42, 0, 0.5, 1))?;
+/// fst.add_tr(0, Tr::new(7, 1000, 1.5, 1))?;
+/// fst.set_final(1, TropicalWeight::one())?;
+///
+/// let (isymt, osymt) = relabel_tables(&mut fst)?;
+///
+/// let trs = fst.get_trs(0)?.trs().to_vec();
+/// assert_eq!((trs[0].ilabel, trs[0].olabel), (2, 0));
+/// assert_eq!((trs[1].ilabel, trs[1].olabel), (1, 1));
+/// assert_eq!(isymt.get_symbol(2), Some("42"));
+/// assert_eq!(osymt.get_symbol(1), Some("1000"));
+/// # Ok(())
+/// # }
+/// ```
+pub fn relabel_tables<W, F>(fst: &mut F) -> Result<(SymbolTable, SymbolTable)>
+where
+    W: Semiring,
+    F: MutableFst<W>,
+{
+    let mut ilabels = vec![];
+    let mut olabels = vec![];
+    for state in fst.states_range() {
+        for tr in unsafe { fst.get_trs_unchecked(state) }.trs() {
+            ilabels.push(tr.ilabel);
+            olabels.push(tr.olabel);
+        }
+    }
+    let (ipairs, isymt) = dense_relabeling(ilabels, fst.input_symbols())?;
+    let (opairs, osymt) = dense_relabeling(olabels, fst.output_symbols())?;
+
+    relabel_pairs(fst, ipairs, opairs)?;
+    fst.set_input_symbols(Arc::new(isymt.clone()));
+    fst.set_output_symbols(Arc::new(osymt.clone()));
+    Ok((isymt, osymt))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::{CoreFst, Fst, PathsIterator};
+    use crate::semirings::TropicalWeight;
+    use crate::{symt, Tr};
+
+    fn symbols(labels: &[Label], symt: Option<&Arc<SymbolTable>>) -> Vec<String> {
+        labels
+            .iter()
+            .map(|l| match symt {
+                Some(symt) => symt.get_symbol(*l).unwrap().to_string(),
+                None => format!("{}", l),
+            })
+            .collect()
+    }
+
+    /// Accepted (input symbols, output symbols, weight) triples, sorted.
+    fn paths_symbols(fst: &VectorFst<TropicalWeight>) -> Vec<(Vec<String>, Vec<String>, String)> {
+        let mut paths: Vec<_> = fst
+            .paths_iter()
+            .map(|p| {
+                (
+                    symbols(&p.ilabels, fst.input_symbols()),
+                    symbols(&p.olabels, fst.output_symbols()),
+                    format!("{}", p.weight),
+                )
+            })
+            .collect();
+        paths.sort();
+        paths
+    }
+
+    fn sparse_fst() -> Result<VectorFst<TropicalWeight>> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(4);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(10, 100, 0.5, 1))?;
+        fst.add_tr(0, Tr::new(5, 0, 1.5, 2))?;
+        fst.add_tr(1, Tr::new(1000, 100, 2.5, 3))?;
+        fst.add_tr(2, Tr::new(0, 7, 3.5, 3))?;
+        fst.add_tr(2, Tr::new(10, 7, 4.5, 3))?;
+        fst.set_final(3, 0.25)?;
+        Ok(fst)
+    }
+
+    #[test]
+    fn test_relabel_tables_dense_labels() -> Result<()> {
+        let fst_ref = sparse_fst()?;
+        let mut fst = fst_ref.clone();
+
+        let (isymt, osymt) = relabel_tables(&mut fst)?;
+
+        assert_eq!(isymt.len(), 4);
+        assert_eq!(osymt.len(), 3);
+        assert_eq!(
+            isymt.iter_sorted().map(|(_, s)| s).collect::<Vec<_>>(),
+            vec!["<eps>", "5", "10", "1000"]
+        );
+        assert_eq!(
+            osymt.iter_sorted().map(|(_, s)| s).collect::<Vec<_>>(),
+            vec!["<eps>", "7", "100"]
+        );
+        for state in fst.states_range() {
+            for tr in fst.get_trs(state)?.trs() {
+                assert!(tr.ilabel < isymt.len() as Label);
+                assert!(tr.olabel < osymt.len() as Label);
+            }
+        }
+        assert_eq!(fst.input_symbols().map(|s| s.as_ref()), Some(&isymt));
+        assert_eq!(fst.output_symbols().map(|s| s.as_ref()), Some(&osymt));
+
+        assert_eq!(paths_symbols(&fst), paths_symbols(&fst_ref));
+        Ok(())
+    }
+
+    #[test]
+    fn test_relabel_tables_with_symbol_tables() -> Result<()> {
+        let mut fst_ref = sparse_fst()?;
+        let isymt: SymbolTable = (1..=2000).map(|l| format!("w{}", l)).collect();
+        fst_ref.set_input_symbols(Arc::new(isymt));
+        let mut fst = fst_ref.clone();
+
+        let (isymt, osymt) = relabel_tables(&mut fst)?;
+
+        assert_eq!(isymt, symt!["w5", "w10", "w1000"]);
+        assert_eq!(osymt, symt!["7", "100"]);
+        assert_eq!(paths_symbols(&fst), paths_symbols(&fst_ref));
+
+        // The output labels 7 and 100 are missing from the output symbol table.
+        let mut fst = sparse_fst()?;
+        fst.set_output_symbols(Arc::new(symt!["a", "b"]));
+        assert!(relabel_tables(&mut fst).is_err());
+        Ok(())
+    }
+}
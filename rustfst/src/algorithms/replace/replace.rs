@@ -1,11 +1,12 @@
 use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
 
 use anyhow::Result;
 
 use crate::algorithms::replace::ReplaceFst;
 use crate::fst_traits::{AllocableFst, Fst, MutableFst};
 use crate::semirings::Semiring;
-use crate::Label;
+use crate::{Label, Trs};
 
 /// Recursively replaces trs in the root FSTs with other FSTs.
 ///
@@ -17,9 +18,17 @@ use crate::Label;
 /// determine whether the transition is a non-terminal transition or not. A non-terminal can be
 /// any label that is not a non-zero terminal label in the output alphabet.
 ///
+/// As in OpenFst, a transition whose input label is a non-terminal but whose output label is a
+/// terminal is left unchanged. When `epsilon_on_replace` is set, both labels of the replaced
+/// transition are turned into epsilons, otherwise its input label is kept.
+///
 /// Note that input argument is a vector of pairs. These correspond to the tuple
 /// of non-terminal Label and corresponding FST.
 ///
+/// An error is returned if a non-terminal reachable from the root depends on itself
+/// (e.g left recursion) as its expansion would be infinite. `ReplaceFst` can be used
+/// to expand such grammars lazily.
+///
 /// # Example
 ///
 /// ## Root Fst
@@ -53,6 +62,178 @@ where
     F2: MutableFst<W> + AllocableFst<W>,
     B: Borrow<F1>,
 {
+    check_no_cyclic_dependencies(&fst_list, root)?;
     let fst = ReplaceFst::new(fst_list, root, epsilon_on_replace)?;
     fst.compute()
 }
+
+/// Returns an error if a non-terminal reachable from the root non-terminal depends on itself.
+fn check_no_cyclic_dependencies<W, F, B>(fst_list: &[(Label, B)], root: Label) -> Result<()>
+where
+    F: Fst<W>,
+    W: Semiring,
+    B: Borrow<F>,
+{
+    let nonterminals: HashMap<Label, &F> = fst_list
+        .iter()
+        .map(|(label, fst)| (*label, fst.borrow()))
+        .collect();
+
+    // Non-terminals referenced by each FST, i.e output labels of its trs that are non-terminals.
+    let mut dependencies: HashMap<Label, Vec<Label>> = HashMap::new();
+    for (label, fst) in nonterminals.iter() {
+        let mut deps = HashSet::new();
+        for state in fst.states_iter() {
+            for tr in fst.get_trs(state)?.trs() {
+                if nonterminals.contains_key(&tr.olabel) {
+                    deps.insert(tr.olabel);
+                }
+            }
+        }
+        dependencies.insert(*label, deps.into_iter().collect());
+    }
+
+    // Iterative DFS from the root keeping track of the non-terminals being expanded.
+    let mut visited = HashSet::new();
+    let mut in_progress = HashSet::new();
+    let mut stack = vec![(root, 0)];
+    visited.insert(root);
+    in_progress.insert(root);
+    while let Some((label, idx_dep)) = stack.pop() {
+        let deps = dependencies
+            .get(&label)
+            .map(|v| v.as_slice())
+            .unwrap_or(&[]);
+        if let Some(dep) = deps.get(idx_dep) {
+            stack.push((label, idx_dep + 1));
+            if in_progress.contains(dep) {
+                bail!(
+                    "Replace : non-terminal {:?} is recursive, its expansion would be infinite",
+                    dep
+                )
+            }
+            if visited.insert(*dep) {
+                in_progress.insert(*dep);
+                stack.push((*dep, 0));
+            }
+        } else {
+            in_progress.remove(&label);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::PathsIterator;
+    use crate::semirings::TropicalWeight;
+    use crate::{FstPath, Tr};
+
+    const ROOT: Label = 100;
+    const NAME: Label = 101;
+    const FIRSTNAME: Label = 102;
+
+    fn linear_fst(labels: &[Label]) -> Result<VectorFst<TropicalWeight>> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        let mut state = fst.add_state();
+        fst.set_start(state)?;
+        for label in labels {
+            let nextstate = fst.add_state();
+            fst.add_tr(
+                state,
+                Tr::new(*label, *label, TropicalWeight::one(), nextstate),
+            )?;
+            state = nextstate;
+        }
+        fst.set_final(state, TropicalWeight::one())?;
+        Ok(fst)
+    }
+
+    #[test]
+    fn test_replace_two_levels() -> Result<()> {
+        let root = linear_fst(&[1, NAME])?;
+        let name = linear_fst(&[FIRSTNAME, 3])?;
+        let mut firstname = VectorFst::<TropicalWeight>::new();
+        firstname.add_states(2);
+        firstname.set_start(0)?;
+        firstname.add_tr(0, Tr::new(2, 2, 0.5, 1))?;
+        firstname.add_tr(0, Tr::new(4, 4, 1.5, 1))?;
+        firstname.set_final(1, TropicalWeight::one())?;
+
+        let fst: VectorFst<TropicalWeight> = replace::<_, VectorFst<_>, _, _>(
+            vec![(ROOT, &root), (NAME, &name), (FIRSTNAME, &firstname)],
+            ROOT,
+            true,
+        )?;
+
+        let paths: HashSet<_> = fst.paths_iter().collect();
+        let mut paths_ref = HashSet::new();
+        paths_ref.insert(FstPath::new(
+            vec![1, 2, 3],
+            vec![1, 2, 3],
+            TropicalWeight::new(0.5),
+        ));
+        paths_ref.insert(FstPath::new(
+            vec![1, 4, 3],
+            vec![1, 4, 3],
+            TropicalWeight::new(1.5),
+        ));
+        assert_eq!(paths, paths_ref);
+        Ok(())
+    }
+
+    #[test]
+    fn test_replace_non_terminals_on_output_labels() -> Result<()> {
+        // The non-terminal is on the output side of the root tr : 7:NAME is replaced.
+        let mut root = VectorFst::<TropicalWeight>::new();
+        root.add_states(3);
+        root.set_start(0)?;
+        root.add_tr(0, Tr::new(1, 1, TropicalWeight::one(), 1))?;
+        root.add_tr(1, Tr::new(7, NAME, 0.5, 2))?;
+        root.set_final(2, TropicalWeight::one())?;
+
+        // NAME:3 has a terminal output label : neither replaced nor a recursion.
+        let mut name = VectorFst::<TropicalWeight>::new();
+        name.add_states(3);
+        name.set_start(0)?;
+        name.add_tr(0, Tr::new(NAME, 3, TropicalWeight::one(), 1))?;
+        name.add_tr(1, Tr::new(2, 2, 1.5, 2))?;
+        name.set_final(2, TropicalWeight::one())?;
+
+        let fst: VectorFst<TropicalWeight> =
+            replace::<_, VectorFst<_>, _, _>(vec![(ROOT, &root), (NAME, &name)], ROOT, true)?;
+
+        let paths: Vec<_> = fst.paths_iter().collect();
+        assert_eq!(
+            paths,
+            vec![FstPath::new(
+                vec![1, NAME, 2],
+                vec![1, 3, 2],
+                TropicalWeight::new(2.0)
+            )]
+        );
+
+        // 3:NAME is a recursion.
+        name.add_tr(1, Tr::new(3, NAME, TropicalWeight::one(), 2))?;
+        let res: Result<VectorFst<TropicalWeight>> =
+            replace::<_, VectorFst<_>, _, _>(vec![(ROOT, &root), (NAME, &name)], ROOT, true);
+        assert!(res.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_replace_recursive() -> Result<()> {
+        let root = linear_fst(&[1, NAME])?;
+        // NAME -> NAME 3 | 2
+        let mut name = linear_fst(&[NAME, 3])?;
+        name.add_tr(0, Tr::new(2, 2, TropicalWeight::one(), 2))?;
+
+        let res: Result<VectorFst<TropicalWeight>> =
+            replace::<_, VectorFst<_>, _, _>(vec![(ROOT, &root), (NAME, &name)], ROOT, true);
+        assert!(res.is_err());
+        Ok(())
+    }
+}
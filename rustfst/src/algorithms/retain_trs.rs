@@ -0,0 +1,90 @@
+use anyhow::Result;
+
+use crate::fst_traits::MutableFst;
+use crate::semirings::Semiring;
+use crate::{StateId, Tr, Trs};
+
+/// Keeps only the trs leaving the state `state` for which `pred` returns true, the other ones
+/// being removed. The order of the remaining trs is preserved.
+///
+/// # Errors
+///
+/// An error is raised if the state `state` doesn't exist.
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::fst_traits::{CoreFst, MutableFst};
+/// # use rustfst::algorithms::retain_trs;
+/// # use rustfst::{Tr, Trs};
+/// # fn main() -> Result<()> {
+/// let mut fst = VectorFst::<TropicalWeight>::new();
+/// let s0 = fst.add_state();
+/// let s1 = fst.add_state();
+/// fst.add_tr(s0, Tr::new(0, 0, 1.0, s1))?;
+/// fst.add_tr(s0, Tr::new(1, 2, 3.0, s1))?;
+///
+/// // Drop the epsilon trs.
+/// retain_trs(&mut fst, s0, |tr| !tr.is_epsilon())?;
+///
+/// assert_eq!(fst.get_trs(s0)?.trs(), &[Tr::new(1, 2, 3.0, s1)]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn retain_trs<W, F, P>(fst: &mut F, state: StateId, mut pred: P) -> Result<()>
+where
+    W: Semiring,
+    F: MutableFst<W>,
+    P: FnMut(&Tr<W>) -> bool,
+{
+    let trs_to_del: Vec<_> = fst
+        .get_trs(state)?
+        .trs()
+        .iter()
+        .enumerate()
+        .filter(|(_, tr)| !pred(*tr))
+        .map(|(idx, _)| idx)
+        .collect();
+    if !trs_to_del.is_empty() {
+        unsafe { fst.del_trs_id_sorted_unchecked(state, &trs_to_del) };
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::CoreFst;
+    use crate::semirings::ProbabilityWeight;
+
+    #[test]
+    fn test_retain_trs_remove_zero_weights() -> Result<()> {
+        let mut fst = VectorFst::<ProbabilityWeight>::new();
+        let s0 = fst.add_state();
+        let s1 = fst.add_state();
+        fst.add_tr(s0, Tr::new(1, 1, ProbabilityWeight::zero(), s1))?;
+        fst.add_tr(s0, Tr::new(2, 2, 0.5, s1))?;
+        fst.add_tr(s0, Tr::new(0, 0, ProbabilityWeight::zero(), s0))?;
+        fst.add_tr(s0, Tr::new(3, 3, 0.2, s0))?;
+
+        retain_trs(&mut fst, s0, |tr| !tr.weight.is_zero())?;
+
+        assert_eq!(
+            fst.get_trs(s0)?.trs(),
+            &[Tr::new(2, 2, 0.5, s1), Tr::new(3, 3, 0.2, s0)]
+        );
+        assert_eq!(fst.num_input_epsilons(s0)?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_retain_trs_missing_state() {
+        let mut fst = VectorFst::<ProbabilityWeight>::new();
+        fst.add_state();
+        assert!(retain_trs(&mut fst, 1, |_| true).is_err());
+    }
+}
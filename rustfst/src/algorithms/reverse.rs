@@ -16,7 +16,7 @@ use crate::{StateId, Trs, EPS_LABEL};
 /// left or right semiring, the output transition type must match the input transition type
 /// except having the reversed Weight type.
 ///
-/// A superinitial state is always created.
+/// A superinitial state is always created, see `reverse_with_config` to avoid it when possible.
 ///
 /// # Example
 ///
@@ -29,42 +29,97 @@ use crate::{StateId, Trs, EPS_LABEL};
 /// ![reverse_out](https://raw.githubusercontent.com/Garvys/rustfst-images-doc/master/images/reverse_out.svg?sanitize=true)
 ///
 pub fn reverse<W, F1, F2>(ifst: &F1) -> Result<F2>
+where
+    W: Semiring,
+    F1: ExpandedFst<W>,
+    F2: MutableFst<W::ReverseWeight> + AllocableFst<W::ReverseWeight>,
+{
+    reverse_with_config(ifst, ReverseConfig::default())
+}
+
+/// Configuration of `reverse_with_config`. When `require_superinitial` is false, the superinitial
+/// state is only created if it is needed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReverseConfig {
+    require_superinitial: bool,
+}
+
+impl Default for ReverseConfig {
+    fn default() -> Self {
+        Self {
+            require_superinitial: true,
+        }
+    }
+}
+
+impl ReverseConfig {
+    pub fn new(require_superinitial: bool) -> Self {
+        Self {
+            require_superinitial,
+        }
+    }
+}
+
+/// Reverses an FST. The reversed result is written to an output mutable FST.
+///
+/// If `require_superinitial` is false in the config and the input FST has a single final state
+/// with a final weight of `One()`, this state becomes the start state of the reversed FST.
+/// Otherwise, a superinitial state is created as in `reverse`.
+pub fn reverse_with_config<W, F1, F2>(ifst: &F1, config: ReverseConfig) -> Result<F2>
 where
     W: Semiring,
     F1: ExpandedFst<W>,
     F2: MutableFst<W::ReverseWeight> + AllocableFst<W::ReverseWeight>,
 {
     let mut ofst = F2::new();
-    ofst.reserve_states(ifst.num_states());
     let istart = ifst.start();
-    let ostart = ofst.add_state();
 
-    ofst.add_states(ifst.num_states());
+    let mut ostart = None;
+    if !config.require_superinitial {
+        for is in ifst.states_iter() {
+            if let Some(w) = unsafe { ifst.final_weight_unchecked(is) } {
+                if ostart.is_some() || !w.is_one() {
+                    ostart = None;
+                    break;
+                }
+                ostart = Some(is);
+            }
+        }
+    }
+    let has_superinitial = ostart.is_none();
+    let offset = if has_superinitial { 1 } else { 0 };
+    let num_ostates = ifst.num_states() + offset;
 
-    let mut c_trs = vec![0; ifst.num_states() + 1];
+    ofst.reserve_states(num_ostates);
+    ofst.add_states(num_ostates);
+    let ostart = ostart.unwrap_or(0);
+
+    let mut c_trs = vec![0; num_ostates];
     for is in ifst.states_iter() {
         for iarc in unsafe { ifst.get_trs_unchecked(is).trs() } {
-            c_trs[iarc.nextstate as usize + 1] += 1;
+            c_trs[iarc.nextstate as usize + offset] += 1;
         }
     }
 
     let mut states_trs: Vec<_> = c_trs.into_iter().map(Vec::with_capacity).collect();
 
     for is in ifst.states_iter() {
-        let os = is + 1;
+        let os = is + offset as StateId;
         if Some(is) == istart {
             ofst.set_final(os, W::ReverseWeight::one())?;
         }
-        let weight = unsafe { ifst.final_weight_unchecked(is) };
-        if let Some(w) = weight {
-            states_trs[0].push(Tr::new(EPS_LABEL, EPS_LABEL, w.reverse()?, os));
+        if has_superinitial {
+            let weight = unsafe { ifst.final_weight_unchecked(is) };
+            if let Some(w) = weight {
+                states_trs[0].push(Tr::new(EPS_LABEL, EPS_LABEL, w.reverse()?, os));
+            }
         }
 
         for itr in unsafe { ifst.get_trs_unchecked(is).trs() } {
-            let nos = itr.nextstate + 1;
+            let nos = itr.nextstate as usize + offset;
             let weight = itr.weight.reverse()?;
             let w = Tr::new(itr.ilabel, itr.olabel, weight, os);
-            states_trs[nos as usize].push(w);
+            states_trs[nos].push(w);
         }
     }
     states_trs
@@ -77,9 +132,64 @@ where
     let iprops = ifst.properties();
     let oprops = ofst.properties();
     ofst.set_properties_with_mask(
-        reverse_properties(iprops, true) | oprops,
+        reverse_properties(iprops, has_superinitial) | oprops,
         FstProperties::all_properties(),
     );
 
     Ok(ofst)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::{CoreFst, PathsIterator};
+    use crate::semirings::TropicalWeight;
+    use crate::FstPath;
+
+    fn single_final_fst() -> Result<VectorFst<TropicalWeight>> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(3);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(0, Tr::new(2, 2, 2.0, 2))?;
+        fst.add_tr(1, Tr::new(3, 3, 3.0, 2))?;
+        fst.set_final(2, TropicalWeight::one())?;
+        Ok(fst)
+    }
+
+    #[test]
+    fn test_reverse_without_superinitial() -> Result<()> {
+        let fst = single_final_fst()?;
+
+        let fst_superinitial: VectorFst<TropicalWeight> = reverse(&fst)?;
+        let fst_rev: VectorFst<TropicalWeight> =
+            reverse_with_config(&fst, ReverseConfig::new(false))?;
+
+        assert_eq!(fst_superinitial.num_states(), fst.num_states() + 1);
+        assert_eq!(fst_rev.num_states(), fst.num_states());
+        assert_eq!(fst_rev.start(), Some(2));
+
+        let mut paths: Vec<_> = fst_rev.paths_iter().collect();
+        paths.sort_by(|p1, p2| p1.ilabels.cmp(&p2.ilabels));
+        let paths_ref = vec![
+            FstPath::new(vec![2], vec![2], TropicalWeight::new(2.0)),
+            FstPath::new(vec![3, 1], vec![3, 1], TropicalWeight::new(4.0)),
+        ];
+        assert_eq!(paths, paths_ref);
+        Ok(())
+    }
+
+    #[test]
+    fn test_reverse_without_superinitial_weighted_final() -> Result<()> {
+        let mut fst = single_final_fst()?;
+        fst.set_final(2, 0.5)?;
+
+        let fst_rev: VectorFst<TropicalWeight> =
+            reverse_with_config(&fst, ReverseConfig::new(false))?;
+
+        assert_eq!(fst_rev.num_states(), fst.num_states() + 1);
+        Ok(())
+    }
+}
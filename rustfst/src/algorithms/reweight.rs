@@ -1,5 +1,6 @@
 use anyhow::Result;
 
+use crate::algorithms::tr_sum;
 use crate::fst_properties::mutable_properties::reweight_properties;
 use crate::fst_properties::FstProperties;
 use crate::fst_traits::MutableFst;
@@ -15,6 +16,31 @@ pub enum ReweightType {
     ReweightToFinal,
 }
 
+/// Reweights an FST according to a vector of potentials in a given direction,
+/// see `reweight_with_config`.
+pub fn reweight<W, F>(fst: &mut F, potentials: &[W], reweight_type: ReweightType) -> Result<()>
+where
+    F: MutableFst<W>,
+    W: WeaklyDivisibleSemiring,
+{
+    reweight_with_config(fst, potentials, reweight_type, ReweightConfig::default())
+}
+
+#[derive(Clone, Debug, Copy, PartialOrd, PartialEq, Default)]
+pub struct ReweightConfig {
+    sum_trs: bool,
+}
+
+impl ReweightConfig {
+    pub fn new(sum_trs: bool) -> Self {
+        Self { sum_trs }
+    }
+
+    pub fn with_sum_trs(self, sum_trs: bool) -> Self {
+        Self { sum_trs }
+    }
+}
+
 /// Reweights an FST according to a vector of potentials in a given direction.
 /// The weight must be left distributive when reweighting towards the initial
 /// state and right distributive when reweighting towards the final states.
@@ -23,11 +49,23 @@ pub enum ReweightType {
 /// of potential q, is reweighted by p^-1 \otimes (w \otimes q) when reweighting
 /// torwards the initial state, and by (p \otimes w) \otimes q^-1 when
 /// reweighting towards the final states.
-pub fn reweight<W, F>(fst: &mut F, potentials: &[W], reweight_type: ReweightType) -> Result<()>
+///
+/// If `sum_trs` is set in the config, the trs with the same labels and destination state are
+/// first summed with `tr_sum`, so that parallel trs are reweighted as a single one.
+pub fn reweight_with_config<W, F>(
+    fst: &mut F,
+    potentials: &[W],
+    reweight_type: ReweightType,
+    config: ReweightConfig,
+) -> Result<()>
 where
     F: MutableFst<W>,
     W: WeaklyDivisibleSemiring,
 {
+    if config.sum_trs {
+        tr_sum(fst);
+    }
+
     let zero = W::zero();
     let num_states = fst.num_states();
 
@@ -151,3 +189,61 @@ where
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::CoreFst;
+    use crate::semirings::{LogWeight, Semiring};
+
+    fn parallel_trs_fst() -> Result<VectorFst<LogWeight>> {
+        let mut fst = VectorFst::<LogWeight>::new();
+        fst.add_states(2);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(0, Tr::new(2, 2, 3.0, 1))?;
+        fst.add_tr(0, Tr::new(1, 1, 2.0, 1))?;
+        fst.set_final(1, 0.5)?;
+        Ok(fst)
+    }
+
+    #[test]
+    fn test_reweight_with_sum_trs() -> Result<()> {
+        let potentials = vec![LogWeight::new(1.0), LogWeight::new(0.5)];
+        let config = ReweightConfig::default().with_sum_trs(true);
+
+        let mut fst_1 = parallel_trs_fst()?;
+        reweight_with_config(
+            &mut fst_1,
+            &potentials,
+            ReweightType::ReweightToFinal,
+            config,
+        )?;
+
+        let mut fst_2 = parallel_trs_fst()?;
+        fst_2.add_tr(0, Tr::new(2, 2, LogWeight::zero(), 1))?;
+        reweight_with_config(
+            &mut fst_2,
+            &potentials,
+            ReweightType::ReweightToFinal,
+            config,
+        )?;
+
+        assert_eq!(fst_1.num_trs(0)?, 2);
+        assert_eq!(fst_1, fst_2);
+
+        // Same as reweighting the FST with the parallel trs summed by hand.
+        let mut fst_ref = VectorFst::<LogWeight>::new();
+        fst_ref.add_states(2);
+        fst_ref.set_start(0)?;
+        let w = LogWeight::new(1.0).plus(LogWeight::new(2.0))?;
+        fst_ref.add_tr(0, Tr::new(1, 1, w, 1))?;
+        fst_ref.add_tr(0, Tr::new(2, 2, 3.0, 1))?;
+        fst_ref.set_final(1, 0.5)?;
+        reweight(&mut fst_ref, &potentials, ReweightType::ReweightToFinal)?;
+        assert_eq!(fst_1, fst_ref);
+        Ok(())
+    }
+}
@@ -176,3 +176,40 @@ pub(crate) fn rm_epsilon_with_internal_config<W: Semiring, F: MutableFst<W>, Q:
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::{CoreFst, ExpandedFst, PathsIterator};
+    use crate::semirings::TropicalWeight;
+    use crate::{FstPath, Tr};
+
+    #[test]
+    fn test_rm_epsilon_self_loop_and_chain() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(4);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(EPS_LABEL, EPS_LABEL, 1.0, 1))?;
+        fst.add_tr(1, Tr::new(EPS_LABEL, EPS_LABEL, 0.5, 1))?;
+        fst.add_tr(1, Tr::new(EPS_LABEL, EPS_LABEL, 2.0, 2))?;
+        fst.add_tr(2, Tr::new(1, 1, 3.0, 3))?;
+        fst.set_final(3, TropicalWeight::one())?;
+
+        rm_epsilon(&mut fst)?;
+
+        for s in fst.states_range() {
+            for tr in fst.get_trs(s)?.trs() {
+                assert!(tr.ilabel != EPS_LABEL || tr.olabel != EPS_LABEL);
+            }
+        }
+
+        let paths: Vec<_> = fst.paths_iter().collect();
+        assert_eq!(
+            paths,
+            vec![FstPath::new(vec![1], vec![1], TropicalWeight::new(6.0))]
+        );
+        Ok(())
+    }
+}
@@ -12,6 +12,8 @@ use crate::semirings::Semiring;
 use crate::{Trs, EPS_LABEL};
 
 /// Removes final states that have epsilon-only input trs.
+///
+/// Only trs and states are deleted, the symbol tables of the FST are left unchanged.
 pub fn rm_final_epsilon<W, F>(ifst: &mut F) -> Result<()>
 where
     W: Semiring,
@@ -74,3 +76,43 @@ where
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::{CoreFst, ExpandedFst, Fst};
+    use crate::semirings::TropicalWeight;
+    use crate::{SymbolTable, Tr};
+
+    #[test]
+    fn test_rm_final_epsilon_preserves_symts() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(3);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 2, 1.0, 1))?;
+        fst.add_tr(1, Tr::new(EPS_LABEL, EPS_LABEL, 2.0, 2))?;
+        fst.set_final(2, 0.5)?;
+
+        let mut isymt = SymbolTable::new();
+        isymt.add_symbol("a");
+        let isymt = Arc::new(isymt);
+        let mut osymt = SymbolTable::new();
+        osymt.add_symbol("x");
+        osymt.add_symbol("y");
+        let osymt = Arc::new(osymt);
+        fst.set_input_symbols(Arc::clone(&isymt));
+        fst.set_output_symbols(Arc::clone(&osymt));
+
+        rm_final_epsilon(&mut fst)?;
+
+        assert_eq!(fst.num_states(), 2);
+        assert_eq!(fst.final_weight(1)?, Some(TropicalWeight::new(2.5)));
+        assert_eq!(fst.input_symbols(), Some(&isymt));
+        assert_eq!(fst.output_symbols(), Some(&osymt));
+        Ok(())
+    }
+}
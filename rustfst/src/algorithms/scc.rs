@@ -0,0 +1,104 @@
+use anyhow::Result;
+
+use crate::algorithms::dfs_visit::dfs_visit_all_states;
+use crate::algorithms::tr_filters::AnyTrFilter;
+use crate::algorithms::visitors::SccVisitor;
+use crate::fst_traits::ExpandedFst;
+use crate::semirings::Semiring;
+
+/// Computes the strongly connected components of an FST.
+///
+/// Returns the id of the component of each state as well as the number of components. All the
+/// states are assigned a component, including the states not accessible from the start state
+/// and the states of an FST without start state.
+/// The components are numbered in topological order : a transition always goes from a
+/// component to a component with a greater or equal id.
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::fst_traits::MutableFst;
+/// # use rustfst::algorithms::scc;
+/// # use rustfst::Tr;
+/// # fn main() -> Result<()> {
+/// let mut fst = VectorFst::<TropicalWeight>::new();
+/// fst.add_states(2);
+/// fst.set_start(0)?;
+/// fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+///
+/// let (sccs, nscc) = scc(&fst)?;
+///
+/// assert_eq!(sccs, vec![0, 1]);
+/// assert_eq!(nscc, 2);
+/// # Ok(())
+/// # }
+/// ```
+pub fn scc<W: Semiring, F: ExpandedFst<W>>(fst: &F) -> Result<(Vec<usize>, usize)> {
+    let mut visitor = SccVisitor::new(fst, true, false);
+    dfs_visit_all_states(fst, &mut visitor, &AnyTrFilter {});
+    let sccs = visitor
+        .scc
+        .ok_or_else(|| format_err!("SCC : Components were not computed"))?;
+    Ok((
+        sccs.into_iter().map(|c| c as usize).collect(),
+        visitor.nscc as usize,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::MutableFst;
+    use crate::semirings::TropicalWeight;
+    use crate::Tr;
+
+    #[test]
+    fn test_scc_two_components() -> Result<()> {
+        // Two cycles {0, 1, 2} and {3, 4} linked by a transition from 2 to 3.
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(5);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(1, Tr::new(2, 2, 1.0, 2))?;
+        fst.add_tr(2, Tr::new(3, 3, 1.0, 0))?;
+        fst.add_tr(2, Tr::new(4, 4, 1.0, 3))?;
+        fst.add_tr(3, Tr::new(5, 5, 1.0, 4))?;
+        fst.add_tr(4, Tr::new(6, 6, 1.0, 3))?;
+        fst.set_final(4, TropicalWeight::one())?;
+
+        let (sccs, nscc) = scc(&fst)?;
+
+        assert_eq!(nscc, 2);
+        assert_eq!(sccs, vec![0, 0, 0, 1, 1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_scc_empty_fst() -> Result<()> {
+        let fst = VectorFst::<TropicalWeight>::new();
+        let (sccs, nscc) = scc(&fst)?;
+        assert!(sccs.is_empty());
+        assert_eq!(nscc, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_scc_no_start_state() -> Result<()> {
+        // A cycle {0, 1} reached from the state 2, without start state.
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(3);
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(1, Tr::new(2, 2, 1.0, 0))?;
+        fst.add_tr(2, Tr::new(3, 3, 1.0, 0))?;
+
+        let (sccs, nscc) = scc(&fst)?;
+
+        assert_eq!(nscc, 2);
+        assert_eq!(sccs, vec![1, 1, 0]);
+        Ok(())
+    }
+}
@@ -2,13 +2,16 @@ use std::marker::PhantomData;
 
 use anyhow::Result;
 
+use crate::algorithms::dfs_visit::dfs_visit;
+use crate::algorithms::is_cyclic;
 use crate::algorithms::queues::AutoQueue;
+use crate::algorithms::top_sort::TopOrderVisitor;
 use crate::algorithms::tr_filters::{AnyTrFilter, TrFilter};
 use crate::algorithms::Queue;
 use crate::fst_impls::VectorFst;
-use crate::fst_traits::{ExpandedFst, MutableFst};
-use crate::semirings::{ReverseBack, Semiring, SemiringProperties, WeightQuantize};
-use crate::{StateId, Trs, KSHORTESTDELTA};
+use crate::fst_traits::ExpandedFst;
+use crate::semirings::{ReverseBack, Semiring, SemiringProperties};
+use crate::{FstError, StateId, Trs, KSHORTESTDELTA};
 use std::borrow::Borrow;
 
 pub(crate) struct ShortestDistanceInternalConfig<W: Semiring, Q: Queue, A: TrFilter<W>> {
@@ -333,41 +336,209 @@ pub fn shortest_distance_with_config<W: Semiring, F: ExpandedFst<W>>(
     }
 }
 
-#[allow(unused)]
-/// Return the sum of the weight of all successful paths in an FST, i.e., the
-/// shortest-distance from the initial state to the final states..
-fn shortest_distance_3<W: Semiring + WeightQuantize, F: MutableFst<W>>(
+/// This operation computes the shortest distance from the initial state to every state of an
+/// acyclic FST. The states are visited once in topological order, making it linear in the size
+/// of the FST. `FstError::Cyclic` is returned if the FST is cyclic.
+///
+/// # Example
+/// ```
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::fst_traits::MutableFst;
+/// # use rustfst::algorithms::shortest_distance_acyclic;
+/// # use rustfst::Tr;
+/// # use anyhow::Result;
+/// # fn main() -> Result<()> {
+/// let mut fst = VectorFst::<TropicalWeight>::new();
+/// fst.add_states(3);
+/// fst.set_start(0)?;
+/// fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+/// fst.add_tr(0, Tr::new(2, 2, 4.0, 2))?;
+/// fst.add_tr(1, Tr::new(3, 3, 2.0, 2))?;
+///
+/// let dists = shortest_distance_acyclic(&fst)?;
+///
+/// assert_eq!(dists, vec![
+///     TropicalWeight::one(),
+///     TropicalWeight::new(1.0),
+///     TropicalWeight::new(3.0),
+/// ]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn shortest_distance_acyclic<W: Semiring, F: ExpandedFst<W>>(fst: &F) -> Result<Vec<W>> {
+    if is_cyclic(fst)? {
+        bail!(FstError::Cyclic)
+    }
+    let num_states = fst.num_states();
+    let mut distance = vec![W::zero(); num_states];
+    let start = match fst.start() {
+        Some(start) => start,
+        None => return Ok(distance),
+    };
+
+    let mut visitor = TopOrderVisitor::new();
+    dfs_visit(fst, &mut visitor, &AnyTrFilter {}, false);
+    let mut states = vec![0; num_states];
+    for (s, order) in visitor.order.iter().enumerate() {
+        states[*order as usize] = s as StateId;
+    }
+
+    distance[start as usize] = W::one();
+    for s in states {
+        let d_s = distance[s as usize].clone();
+        if d_s.is_zero() {
+            continue;
+        }
+        for tr in fst.get_trs(s)?.trs() {
+            distance[tr.nextstate as usize].plus_assign(d_s.times(&tr.weight)?)?;
+        }
+    }
+    Ok(distance)
+}
+
+/// Returns the ⊕-sum of the weights of all the successful paths of the FST, i.e. the shortest
+/// distance from the start state to the final states. This is the weight of the best path for
+/// the tropical semiring and the total weight of the FST for the log semiring.
+///
+/// The shortest distance is computed forward when the semiring is right distributive and
+/// backward otherwise. `W::zero()` is returned if the FST has no start state.
+///
+/// # Example
+/// ```
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::fst_traits::MutableFst;
+/// # use rustfst::algorithms::shortest_distance_to_final;
+/// # use rustfst::Tr;
+/// # use anyhow::Result;
+/// # fn main() -> Result<()> {
+/// let mut fst = VectorFst::<TropicalWeight>::new();
+/// fst.add_states(2);
+/// fst.set_start(0)?;
+/// fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+/// fst.add_tr(0, Tr::new(2, 2, 4.0, 1))?;
+/// fst.set_final(1, 0.5)?;
+///
+/// assert_eq!(shortest_distance_to_final(&fst)?, TropicalWeight::new(1.5));
+/// # Ok(())
+/// # }
+/// ```
+pub fn shortest_distance_to_final<W: Semiring, F: ExpandedFst<W>>(fst: &F) -> Result<W> {
+    shortest_distance_to_final_with_config(fst, ShortestDistanceConfig::default())
+}
+
+/// Same as `shortest_distance_to_final` but with a configurable delta.
+pub fn shortest_distance_to_final_with_config<W: Semiring, F: ExpandedFst<W>>(
     fst: &F,
-    delta: f32,
-) -> Result<W>
-where
-    W::ReverseWeight: WeightQuantize,
-{
-    let weight_properties = W::properties();
-
-    if weight_properties.contains(SemiringProperties::RIGHT_SEMIRING) {
-        let distance =
-            shortest_distance_with_config(fst, false, ShortestDistanceConfig::new(delta))?;
+    config: ShortestDistanceConfig,
+) -> Result<W> {
+    if W::properties().contains(SemiringProperties::RIGHT_SEMIRING) {
+        let distance = shortest_distance_with_config(fst, false, config)?;
         let mut sum = W::zero();
-        for state in 0..distance.len() {
-            sum.plus_assign(
-                distance[state]
-                    .times(fst.final_weight(state as StateId)?.unwrap_or_else(W::zero))?,
-            )?;
+        for (state, d) in distance.iter().enumerate() {
+            if let Some(final_weight) = fst.final_weight(state as StateId)? {
+                sum.plus_assign(d.times(final_weight)?)?;
+            }
         }
         Ok(sum)
     } else {
-        let distance =
-            shortest_distance_with_config(fst, true, ShortestDistanceConfig::new(delta))?;
-        if let Some(state) = fst.start() {
-            let state = state as usize;
-            if state < distance.len() {
-                Ok(distance[state].clone())
-            } else {
-                Ok(W::zero())
-            }
-        } else {
-            Ok(W::zero())
-        }
+        let distance = shortest_distance_with_config(fst, true, config)?;
+        Ok(fst
+            .start()
+            .and_then(|start| distance.get(start as usize).cloned())
+            .unwrap_or_else(W::zero))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fst_traits::MutableFst;
+    use crate::semirings::{LogWeight, TropicalWeight};
+    use crate::Tr;
+
+    fn lattice_fst<W: Semiring>() -> Result<VectorFst<W>>
+    where
+        f32: Into<W>,
+    {
+        let mut fst = VectorFst::<W>::new();
+        fst.add_states(6);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(0, Tr::new(2, 2, 2.5, 2))?;
+        fst.add_tr(1, Tr::new(3, 3, 1.0, 2))?;
+        fst.add_tr(1, Tr::new(4, 4, 3.0, 3))?;
+        fst.add_tr(2, Tr::new(5, 5, 0.5, 3))?;
+        fst.add_tr(2, Tr::new(6, 6, 4.0, 4))?;
+        fst.add_tr(3, Tr::new(7, 7, 1.5, 4))?;
+        fst.add_tr(3, Tr::new(8, 8, 2.0, 5))?;
+        fst.set_final(4, 0.5)?;
+        Ok(fst)
+    }
+
+    #[test]
+    fn test_shortest_distance_acyclic_tropical() -> Result<()> {
+        let fst: VectorFst<TropicalWeight> = lattice_fst()?;
+        assert_eq!(
+            shortest_distance_acyclic(&fst)?,
+            shortest_distance(&fst, false)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_shortest_distance_acyclic_log() -> Result<()> {
+        let fst: VectorFst<LogWeight> = lattice_fst()?;
+        assert_eq!(
+            shortest_distance_acyclic(&fst)?,
+            shortest_distance(&fst, false)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_shortest_distance_acyclic_cyclic_fst() -> Result<()> {
+        let mut fst: VectorFst<TropicalWeight> = lattice_fst()?;
+        fst.add_tr(4, Tr::new(9, 9, 1.0, 0))?;
+        let err = shortest_distance_acyclic(&fst).unwrap_err();
+        assert_eq!(err.downcast_ref::<FstError>(), Some(&FstError::Cyclic));
+        Ok(())
+    }
+
+    #[test]
+    fn test_shortest_distance_to_final_tropical() -> Result<()> {
+        let fst: VectorFst<TropicalWeight> = lattice_fst()?;
+        // Best path 0 -> 1 -> 2 -> 3 -> 4 with final weight 0.5.
+        assert_eq!(shortest_distance_to_final(&fst)?, TropicalWeight::new(4.5));
+        Ok(())
+    }
+
+    #[test]
+    fn test_shortest_distance_to_final_log() -> Result<()> {
+        let mut fst = VectorFst::<LogWeight>::new();
+        fst.add_states(3);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(0, Tr::new(2, 2, 2.0, 2))?;
+        fst.set_final(1, LogWeight::one())?;
+        fst.set_final(2, 0.5)?;
+
+        let expected = LogWeight::new(1.0).plus(LogWeight::new(2.5))?;
+        assert_eq!(shortest_distance_to_final(&fst)?, expected);
+        // Both directions give the same result.
+        assert_eq!(
+            shortest_distance(&fst, true)?[0],
+            shortest_distance_to_final(&fst)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_shortest_distance_to_final_no_start() -> Result<()> {
+        let fst = VectorFst::<TropicalWeight>::new();
+        assert_eq!(shortest_distance_to_final(&fst)?, TropicalWeight::zero());
+        Ok(())
     }
 }
@@ -511,3 +511,97 @@ where
     );
     Ok(ofst)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    use crate::fst_traits::PathsIterator;
+    use crate::semirings::TropicalWeight;
+    use crate::FstPath;
+
+    fn lattice() -> Result<VectorFst<TropicalWeight>> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(3);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(0, Tr::new(1, 1, 1.5, 1))?;
+        fst.add_tr(0, Tr::new(2, 2, 2.0, 1))?;
+        fst.add_tr(1, Tr::new(3, 3, 1.0, 2))?;
+        fst.add_tr(1, Tr::new(4, 4, 3.0, 2))?;
+        fst.set_final(2, TropicalWeight::one())?;
+        Ok(fst)
+    }
+
+    fn paths(fst: &VectorFst<TropicalWeight>) -> HashSet<FstPath<TropicalWeight>> {
+        fst.paths_iter().collect()
+    }
+
+    #[test]
+    fn test_shortest_path_single() -> Result<()> {
+        let fst: VectorFst<_> = shortest_path(&lattice()?)?;
+
+        let mut paths_ref = HashSet::new();
+        paths_ref.insert(FstPath::new(
+            vec![1, 3],
+            vec![1, 3],
+            TropicalWeight::new(2.0),
+        ));
+        assert_eq!(paths(&fst), paths_ref);
+        Ok(())
+    }
+
+    #[test]
+    fn test_shortest_path_nshortest() -> Result<()> {
+        let config = ShortestPathConfig::default().with_nshortest(3);
+        let fst: VectorFst<_> = shortest_path_with_config(&lattice()?, config)?;
+
+        let mut paths_ref = HashSet::new();
+        paths_ref.insert(FstPath::new(
+            vec![1, 3],
+            vec![1, 3],
+            TropicalWeight::new(2.0),
+        ));
+        paths_ref.insert(FstPath::new(
+            vec![1, 3],
+            vec![1, 3],
+            TropicalWeight::new(2.5),
+        ));
+        paths_ref.insert(FstPath::new(
+            vec![2, 3],
+            vec![2, 3],
+            TropicalWeight::new(3.0),
+        ));
+        assert_eq!(paths(&fst), paths_ref);
+        Ok(())
+    }
+
+    #[test]
+    fn test_shortest_path_nshortest_unique() -> Result<()> {
+        let config = ShortestPathConfig::default()
+            .with_nshortest(3)
+            .with_unique(true);
+        let fst: VectorFst<_> = shortest_path_with_config(&lattice()?, config)?;
+
+        let mut paths_ref = HashSet::new();
+        paths_ref.insert(FstPath::new(
+            vec![1, 3],
+            vec![1, 3],
+            TropicalWeight::new(2.0),
+        ));
+        paths_ref.insert(FstPath::new(
+            vec![2, 3],
+            vec![2, 3],
+            TropicalWeight::new(3.0),
+        ));
+        paths_ref.insert(FstPath::new(
+            vec![1, 4],
+            vec![1, 4],
+            TropicalWeight::new(4.0),
+        ));
+        assert_eq!(paths(&fst), paths_ref);
+        Ok(())
+    }
+}
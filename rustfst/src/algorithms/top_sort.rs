@@ -63,6 +63,9 @@ impl<'a, W: Semiring, F: 'a + Fst<W>> Visitor<'a, W, F> for TopOrderVisitor {
 /// This operation topologically sorts its input. When sorted, all transitions are from
 /// lower to higher state IDs.
 ///
+/// Returns `false` if the FST is cyclic, in which case it is left unchanged
+/// (apart from its properties), `true` otherwise.
+///
 /// # Example
 ///
 /// ## Input
@@ -73,7 +76,7 @@ impl<'a, W: Semiring, F: 'a + Fst<W>> Visitor<'a, W, F> for TopOrderVisitor {
 ///
 /// ![topsort_out](https://raw.githubusercontent.com/Garvys/rustfst-images-doc/master/images/topsort_out.svg?sanitize=true)
 ///
-pub fn top_sort<W, F>(fst: &mut F) -> Result<()>
+pub fn top_sort<W, F>(fst: &mut F) -> Result<bool>
 where
     W: Semiring,
     F: MutableFst<W>,
@@ -90,5 +93,56 @@ where
         fst.set_properties_with_mask(props, props);
     }
 
-    Ok(())
+    Ok(visitor.acyclic)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::{CoreFst, ExpandedFst};
+    use crate::semirings::TropicalWeight;
+    use crate::Trs;
+
+    #[test]
+    fn test_top_sort_acyclic() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(4);
+        fst.set_start(2)?;
+        fst.add_tr(2, Tr::new(1, 1, 1.0, 0))?;
+        fst.add_tr(0, Tr::new(2, 2, 1.0, 3))?;
+        fst.add_tr(2, Tr::new(3, 3, 1.0, 3))?;
+        fst.add_tr(3, Tr::new(4, 4, 1.0, 1))?;
+        fst.set_final(1, TropicalWeight::one())?;
+
+        assert!(top_sort(&mut fst)?);
+
+        assert_eq!(fst.start(), Some(0));
+        for s in fst.states_range() {
+            for tr in fst.get_trs(s)?.trs() {
+                assert!(s < tr.nextstate);
+            }
+        }
+        assert!(fst.properties().contains(FstProperties::TOP_SORTED));
+        Ok(())
+    }
+
+    #[test]
+    fn test_top_sort_cyclic() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(3);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(1, Tr::new(2, 2, 1.0, 2))?;
+        fst.add_tr(2, Tr::new(3, 3, 1.0, 1))?;
+        fst.set_final(2, TropicalWeight::one())?;
+        let fst_ref = fst.clone();
+
+        assert!(!top_sort(&mut fst)?);
+
+        assert_eq!(fst, fst_ref);
+        assert!(fst.properties().contains(FstProperties::CYCLIC));
+        Ok(())
+    }
 }
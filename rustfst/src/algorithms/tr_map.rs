@@ -184,3 +184,56 @@ where
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::algorithms::tr_mappers::{InvertWeightMapper, PowerMapper};
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::{CoreFst, ExpandedFst};
+    use crate::semirings::TropicalWeight;
+    use crate::Trs;
+
+    fn weighted_fst() -> Result<VectorFst<TropicalWeight>> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(2);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 2, 1.5, 1))?;
+        fst.add_tr(0, Tr::new(3, 4, 0.5, 1))?;
+        fst.set_final(1, 2.0)?;
+        Ok(fst)
+    }
+
+    #[test]
+    fn test_tr_map_invert_weight() -> Result<()> {
+        let fst_ref = weighted_fst()?;
+        let mut fst = fst_ref.clone();
+
+        tr_map(&mut fst, &InvertWeightMapper {})?;
+
+        assert_eq!(fst.num_states(), fst_ref.num_states());
+        for s in fst.states_range() {
+            let trs = fst.get_trs(s)?;
+            let trs_ref = fst_ref.get_trs(s)?;
+            for (tr, tr_ref) in trs.trs().iter().zip(trs_ref.trs()) {
+                assert_eq!(tr.weight, TropicalWeight::new(-*tr_ref.weight.value()));
+            }
+        }
+        assert_eq!(fst.final_weight(1)?, Some(TropicalWeight::new(-2.0)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_tr_map_power() -> Result<()> {
+        let mut fst = weighted_fst()?;
+
+        tr_map(&mut fst, &PowerMapper::new(3))?;
+
+        let trs = fst.get_trs(0)?;
+        assert_eq!(trs.trs()[0].weight, TropicalWeight::new(4.5));
+        assert_eq!(trs.trs()[1].weight, TropicalWeight::new(1.5));
+        assert_eq!(fst.final_weight(1)?, Some(TropicalWeight::new(6.0)));
+        Ok(())
+    }
+}
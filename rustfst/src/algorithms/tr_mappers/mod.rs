@@ -6,6 +6,7 @@ mod input_epsilon_mapper;
 mod invert_weight_mapper;
 mod output_epsilon_mapper;
 mod plus_mapper;
+mod power_mapper;
 mod quantize_mapper;
 mod rm_weight_mapper;
 mod times_mapper;
@@ -15,6 +16,7 @@ pub use self::input_epsilon_mapper::InputEpsilonMapper;
 pub use self::invert_weight_mapper::InvertWeightMapper;
 pub use self::output_epsilon_mapper::OutputEpsilonMapper;
 pub use self::plus_mapper::PlusMapper;
+pub use self::power_mapper::PowerMapper;
 pub use self::quantize_mapper::QuantizeMapper;
 pub use self::rm_weight_mapper::RmWeightMapper;
 pub use self::times_mapper::TimesMapper;
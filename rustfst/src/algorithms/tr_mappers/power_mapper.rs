@@ -0,0 +1,43 @@
+use anyhow::Result;
+
+use crate::algorithms::{FinalTr, MapFinalAction, TrMapper, WeightConverter};
+use crate::fst_properties::FstProperties;
+use crate::semirings::Semiring;
+use crate::Tr;
+
+/// Mapper to raise all weights to a given power, i.e. multiply them `power` times with
+/// themselves. Raising to the power `0` maps every weight to `One()`.
+pub struct PowerMapper {
+    power: usize,
+}
+
+impl PowerMapper {
+    pub fn new(power: usize) -> Self {
+        PowerMapper { power }
+    }
+
+    pub fn map_weight<W: Semiring>(&self, weight: &mut W) -> Result<()> {
+        *weight = weight.pow(self.power)?;
+        Ok(())
+    }
+}
+
+impl<S: Semiring> TrMapper<S> for PowerMapper {
+    fn tr_map(&self, tr: &mut Tr<S>) -> Result<()> {
+        self.map_weight(&mut tr.weight)
+    }
+
+    fn final_tr_map(&self, final_tr: &mut FinalTr<S>) -> Result<()> {
+        self.map_weight(&mut final_tr.weight)
+    }
+
+    fn final_action(&self) -> MapFinalAction {
+        MapFinalAction::MapNoSuperfinal
+    }
+
+    fn properties(&self, inprops: FstProperties) -> FstProperties {
+        inprops & FstProperties::weight_invariant_properties()
+    }
+}
+
+tr_mapper_to_weight_convert_mapper!(PowerMapper);
@@ -1,9 +1,11 @@
 use std::cmp::Ordering;
 
+use anyhow::Result;
+
 use crate::fst_properties::FstProperties;
 use crate::fst_traits::MutableFst;
 use crate::semirings::Semiring;
-use crate::{StateId, Tr};
+use crate::{StateId, Tr, Trs};
 
 pub trait TrCompare {
     fn compare<W: Semiring>(a: &Tr<W>, b: &Tr<W>) -> Ordering;
@@ -46,7 +48,23 @@ impl TrCompare for OLabelCompare {
     }
 }
 
-/// Sorts trs leaving each state of the FST using a compare function
+/// Compare only next states.
+pub struct NextStateCompare {}
+
+impl TrCompare for NextStateCompare {
+    fn compare<W: Semiring>(a: &Tr<W>, b: &Tr<W>) -> Ordering {
+        a.nextstate.cmp(&b.nextstate)
+    }
+
+    fn properties(inprops: FstProperties) -> FstProperties {
+        inprops & FstProperties::arcsort_properties()
+    }
+}
+
+/// Sorts trs leaving each state of the FST using a compare function.
+///
+/// The sort is stable: the trs that are equal under the compare function keep their relative
+/// order.
 // The compare function could be passed only with the generic parameters but it seems less intuitive.
 pub fn tr_sort<W, F, C>(fst: &mut F, _comp: C)
 where
@@ -60,3 +78,156 @@ where
     }
     fst.set_properties_with_mask(C::properties(props), FstProperties::all_properties());
 }
+
+/// Sorts trs leaving each state of the FST using a compare function, then plus-sums the weights
+/// of the trs that are equal under the compare function and have the same input label, output
+/// label and next state.
+///
+/// The merged trs take the position of the first of them, so the order remains stable.
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::fst_traits::{CoreFst, MutableFst};
+/// # use rustfst::algorithms::tr_sort_unique;
+/// # use rustfst::algorithms::tr_compares::ILabelCompare;
+/// # use rustfst::{Tr, Trs};
+/// # fn main() -> Result<()> {
+/// let mut fst = VectorFst::<TropicalWeight>::new();
+/// let s0 = fst.add_state();
+/// let s1 = fst.add_state();
+/// fst.set_start(s0)?;
+/// fst.set_final(s1, TropicalWeight::one())?;
+/// fst.add_tr(s0, Tr::new(2, 2, 1.0, s1))?;
+/// fst.add_tr(s0, Tr::new(1, 2, 3.0, s1))?;
+/// fst.add_tr(s0, Tr::new(1, 2, 2.0, s1))?;
+///
+/// tr_sort_unique(&mut fst, ILabelCompare {})?;
+///
+/// assert_eq!(
+///     fst.get_trs(s0)?.trs(),
+///     &[Tr::new(1, 2, 2.0, s1), Tr::new(2, 2, 1.0, s1)]
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn tr_sort_unique<W, F, C>(fst: &mut F, comp: C) -> Result<()>
+where
+    W: Semiring,
+    F: MutableFst<W>,
+    C: TrCompare,
+{
+    tr_sort(fst, comp);
+    let props = fst.properties();
+    for state in fst.states_range() {
+        let trs = unsafe { fst.get_trs_unchecked(state) };
+        let mut unique_trs: Vec<Tr<W>> = Vec::with_capacity(trs.len());
+        // Index of the first tr of the current run of trs equal under the compare function.
+        let mut run_start = 0;
+        for tr in trs.trs() {
+            if let Some(last) = unique_trs.last() {
+                if C::compare(last, tr) != Ordering::Equal {
+                    run_start = unique_trs.len();
+                }
+            }
+            match unique_trs[run_start..].iter_mut().find(|t| {
+                t.ilabel == tr.ilabel && t.olabel == tr.olabel && t.nextstate == tr.nextstate
+            }) {
+                Some(t) => t.weight.plus_assign(&tr.weight)?,
+                None => unique_trs.push(tr.clone()),
+            }
+        }
+        if unique_trs.len() < trs.len() {
+            drop(trs);
+            unsafe { fst.set_trs_unchecked(state, unique_trs) };
+        }
+    }
+    let mut outprops = props
+        & FstProperties::delete_arcs_properties()
+        & FstProperties::weight_invariant_properties();
+    if fst.num_states() == 0 {
+        outprops |= FstProperties::null_properties();
+    }
+    fst.set_properties_with_mask(outprops, FstProperties::all_properties());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anyhow::Result;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::CoreFst;
+    use crate::semirings::{ProbabilityWeight, TropicalWeight};
+
+    #[test]
+    fn test_tr_sort_nextstate() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(4);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 0.5, 3))?;
+        fst.add_tr(0, Tr::new(2, 2, 1.5, 1))?;
+        fst.add_tr(0, Tr::new(3, 3, 2.5, 2))?;
+        fst.add_tr(0, Tr::new(4, 4, 3.5, 1))?;
+
+        tr_sort(&mut fst, NextStateCompare {});
+
+        let trs = fst.get_trs(0)?;
+        let nextstates: Vec<_> = trs.trs().iter().map(|tr| tr.nextstate).collect();
+        assert_eq!(nextstates, vec![1, 1, 2, 3]);
+        assert!(!fst.properties().contains(FstProperties::I_LABEL_SORTED));
+        Ok(())
+    }
+
+    #[test]
+    fn test_tr_sort_stable() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(4);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(2, 1, 0.5, 1))?;
+        fst.add_tr(0, Tr::new(1, 3, 1.5, 2))?;
+        fst.add_tr(0, Tr::new(2, 2, 2.5, 3))?;
+        fst.add_tr(0, Tr::new(1, 1, 3.5, 1))?;
+        fst.add_tr(0, Tr::new(2, 3, 4.5, 2))?;
+
+        tr_sort(&mut fst, ILabelCompare {});
+
+        let trs = fst.get_trs(0)?;
+        let labels: Vec<_> = trs.trs().iter().map(|tr| (tr.ilabel, tr.olabel)).collect();
+        assert_eq!(labels, vec![(1, 3), (1, 1), (2, 1), (2, 2), (2, 3)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_tr_sort_unique() -> Result<()> {
+        let mut fst = VectorFst::<ProbabilityWeight>::new();
+        fst.add_states(3);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(2, 2, 0.1, 1))?;
+        fst.add_tr(0, Tr::new(1, 1, 0.2, 2))?;
+        fst.add_tr(0, Tr::new(2, 3, 0.3, 1))?;
+        fst.add_tr(0, Tr::new(2, 2, 0.4, 1))?;
+        fst.add_tr(0, Tr::new(1, 1, 0.5, 1))?;
+        fst.add_tr(0, Tr::new(1, 1, 0.25, 2))?;
+        fst.set_final(1, ProbabilityWeight::one())?;
+
+        tr_sort_unique(&mut fst, ILabelCompare {})?;
+
+        let trs = fst.get_trs(0)?;
+        assert_eq!(
+            trs.trs(),
+            &[
+                Tr::new(1, 1, 0.45, 2),
+                Tr::new(1, 1, 0.5, 1),
+                Tr::new(2, 2, 0.5, 1),
+                Tr::new(2, 3, 0.3, 1),
+            ]
+        );
+        assert!(fst.properties().contains(FstProperties::I_LABEL_SORTED));
+        Ok(())
+    }
+}
@@ -4,6 +4,33 @@ use crate::semirings::Semiring;
 
 /// Plus-Sum weights of trs leaving the same state, going to the same state
 /// and with the same input and output labels.
+///
+/// The trs of each state end up sorted by (ilabel, olabel, nextstate).
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::fst_traits::{CoreFst, MutableFst};
+/// # use rustfst::algorithms::tr_sum;
+/// # use rustfst::{Tr, Trs};
+/// # fn main() -> Result<()> {
+/// let mut fst = VectorFst::<TropicalWeight>::new();
+/// let s0 = fst.add_state();
+/// let s1 = fst.add_state();
+/// fst.set_start(s0)?;
+/// fst.set_final(s1, TropicalWeight::one())?;
+/// fst.add_tr(s0, Tr::new(1, 2, 3.0, s1))?;
+/// fst.add_tr(s0, Tr::new(1, 2, 1.0, s1))?;
+/// fst.add_tr(s0, Tr::new(1, 2, 2.0, s1))?;
+///
+/// tr_sum(&mut fst);
+///
+/// assert_eq!(fst.get_trs(s0)?.trs(), &[Tr::new(1, 2, 1.0, s1)]);
+/// # Ok(())
+/// # }
+/// ```
 pub fn tr_sum<W: Semiring, F: MutableFst<W>>(ifst: &mut F) {
     let props = ifst.properties();
     unsafe {
@@ -87,7 +87,7 @@ where
 
     let start1 = fst_1.start();
     if start1.is_none() {
-        unsafe { fst_1.set_start_unchecked(start2) };
+        unsafe { fst_1.set_start_unchecked(start2 + numstates1) };
         fst_1.set_properties_with_mask(props2, FstProperties::copy_properties());
         return Ok(());
     }
@@ -117,3 +117,63 @@ where
     );
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+    use std::sync::Arc;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::{CoreFst, Fst, PathsIterator};
+    use crate::semirings::TropicalWeight;
+    use crate::utils::acceptor;
+    use crate::{FstPath, SymbolTable};
+
+    #[test]
+    fn test_union_accepts_both_inputs() -> Result<()> {
+        let mut fst_1: VectorFst<TropicalWeight> = acceptor(&[1, 2], TropicalWeight::new(1.5));
+        let fst_2: VectorFst<TropicalWeight> = acceptor(&[3], TropicalWeight::new(2.0));
+        let symt: Arc<SymbolTable> = Arc::new(vec!["a", "b", "c"].into_iter().collect());
+        fst_1.set_input_symbols(Arc::clone(&symt));
+        fst_1.set_output_symbols(Arc::clone(&symt));
+
+        union(&mut fst_1, &fst_2)?;
+
+        let paths: HashSet<_> = fst_1.paths_iter().collect();
+        let mut paths_ref = HashSet::new();
+        paths_ref.insert(FstPath::new(
+            vec![1, 2],
+            vec![1, 2],
+            TropicalWeight::new(1.5),
+        ));
+        paths_ref.insert(FstPath::new(vec![3], vec![3], TropicalWeight::new(2.0)));
+        assert_eq!(paths, paths_ref);
+
+        assert_eq!(fst_1.input_symbols(), Some(&symt));
+        assert_eq!(fst_1.output_symbols(), Some(&symt));
+        Ok(())
+    }
+
+    #[test]
+    fn test_union_no_start_state() -> Result<()> {
+        let mut fst_1 = VectorFst::<TropicalWeight>::new();
+        fst_1.add_states(2);
+        let fst_2: VectorFst<TropicalWeight> = acceptor(&[3, 4], TropicalWeight::new(2.0));
+
+        union(&mut fst_1, &fst_2)?;
+
+        assert_eq!(fst_1.start(), Some(2));
+        let paths: Vec<_> = fst_1.paths_iter().collect();
+        assert_eq!(
+            paths,
+            vec![FstPath::new(
+                vec![3, 4],
+                vec![3, 4],
+                TropicalWeight::new(2.0)
+            )]
+        );
+        Ok(())
+    }
+}
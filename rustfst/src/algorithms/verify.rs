@@ -0,0 +1,117 @@
+use anyhow::Result;
+
+use crate::fst_traits::ExpandedFst;
+use crate::semirings::Semiring;
+use crate::Trs;
+
+/// Checks the sanity of an FST : the start state and the next state of every transition
+/// must be valid states of the FST and no final weight must be `Zero()` (a non-final state
+/// doesn't have any final weight).
+///
+/// An error describing the first issue found is returned if the FST is not valid.
+///
+/// # Example
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::fst_traits::MutableFst;
+/// # use rustfst::algorithms::verify;
+/// # use rustfst::Tr;
+/// # fn main() -> Result<()> {
+/// let mut fst = VectorFst::<TropicalWeight>::new();
+/// let s0 = fst.add_state();
+/// let s1 = fst.add_state();
+/// fst.set_start(s0)?;
+/// fst.add_tr(s0, Tr::new(1, 2, 1.0, s1))?;
+/// fst.set_final(s1, TropicalWeight::one())?;
+/// assert!(verify(&fst).is_ok());
+///
+/// fst.add_tr(s1, Tr::new(1, 2, 1.0, 5))?;
+/// assert!(verify(&fst).is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn verify<W: Semiring, F: ExpandedFst<W>>(fst: &F) -> Result<()> {
+    let num_states = fst.num_states();
+
+    if let Some(start) = fst.start() {
+        if start as usize >= num_states {
+            bail!(
+                "Verify : Fst start state {:?} exceeds number of states {:?}",
+                start,
+                num_states
+            )
+        }
+    }
+
+    for state in fst.states_range() {
+        let trs = unsafe { fst.get_trs_unchecked(state) };
+        for (idx_tr, tr) in trs.trs().iter().enumerate() {
+            if tr.nextstate as usize >= num_states {
+                bail!(
+                    "Verify : Fst destination state {:?} of tr {:?} at position {:?} of state {:?} exceeds number of states {:?}",
+                    tr.nextstate,
+                    tr,
+                    idx_tr,
+                    state,
+                    num_states
+                )
+            }
+        }
+        if let Some(final_weight) = unsafe { fst.final_weight_unchecked(state) } {
+            if final_weight.is_zero() {
+                bail!(
+                    "Verify : Fst final weight of state {:?} is Zero(), the state should not be final",
+                    state
+                )
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::MutableFst;
+    use crate::semirings::TropicalWeight;
+    use crate::Tr;
+
+    #[test]
+    fn test_verify_dangling_nextstate() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(2);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.set_final(1, TropicalWeight::one())?;
+        verify(&fst)?;
+
+        fst.add_tr(1, Tr::new(2, 2, 1.0, 2))?;
+        let err = verify(&fst).unwrap_err();
+        assert!(err.to_string().contains("state 1"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_start_out_of_range() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(2);
+        unsafe { fst.set_start_unchecked(3) };
+        assert!(verify(&fst).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_zero_final_weight() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(1);
+        fst.set_start(0)?;
+        fst.set_final(0, TropicalWeight::zero())?;
+        assert!(verify(&fst).is_err());
+        Ok(())
+    }
+}
@@ -1,2 +1,2 @@
 mod scc_visitors;
-pub(crate) use scc_visitors::SccVisitor;
+pub use scc_visitors::SccVisitor;
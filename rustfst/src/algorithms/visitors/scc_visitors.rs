@@ -9,9 +9,15 @@ use crate::semirings::Semiring;
 use crate::Tr;
 use crate::{StateId, NO_STATE_ID};
 
+/// Visitor computing the strongly connected components of an FST with Tarjan's algorithm, along
+/// with the accessibility, coaccessibility and cyclicity properties.
 pub struct SccVisitor<'a, W: Semiring, F: Fst<W>> {
+    /// Strongly connected component of each state, if requested. The components are numbered in
+    /// topological order.
     pub scc: Option<Vec<i32>>,
+    /// Whether each state is accessible, if requested.
     pub access: Option<Vec<bool>>,
+    /// Whether each state is coaccessible.
     pub coaccess: Vec<bool>,
     start: StateId,
     fst: &'a F,
@@ -20,7 +26,9 @@ pub struct SccVisitor<'a, W: Semiring, F: Fst<W>> {
     lowlink: Vec<i32>,
     onstack: Vec<bool>,
     scc_stack: Vec<StateId>,
+    /// Number of strongly connected components.
     pub nscc: i32,
+    /// Properties computed during the visit.
     pub props: FstProperties,
     w: PhantomData<W>,
 }
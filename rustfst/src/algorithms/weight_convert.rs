@@ -133,3 +133,37 @@ where
 
     Ok(fst_out)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::algorithms::weight_converters::SimpleWeightConverter;
+    use crate::fst_impls::VectorFst;
+    use crate::semirings::{LogWeight, TropicalWeight};
+
+    #[test]
+    fn test_weight_convert_log_to_tropical() -> Result<()> {
+        let mut fst_log = VectorFst::<LogWeight>::new();
+        fst_log.add_states(3);
+        fst_log.set_start(0)?;
+        fst_log.add_tr(0, Tr::new(1, 2, 0.5, 1))?;
+        fst_log.add_tr(0, Tr::new(3, 3, 1.5, 2))?;
+        fst_log.add_tr(1, Tr::new(4, 5, 2.0, 2))?;
+        fst_log.set_final(2, 0.7)?;
+
+        let fst_tropical: VectorFst<TropicalWeight> =
+            weight_convert(&fst_log, &mut SimpleWeightConverter {})?;
+
+        let mut fst_ref = VectorFst::<TropicalWeight>::new();
+        fst_ref.add_states(3);
+        fst_ref.set_start(0)?;
+        fst_ref.add_tr(0, Tr::new(1, 2, 0.5, 1))?;
+        fst_ref.add_tr(0, Tr::new(3, 3, 1.5, 2))?;
+        fst_ref.add_tr(1, Tr::new(4, 5, 2.0, 2))?;
+        fst_ref.set_final(2, 0.7)?;
+
+        assert_eq!(fst_tropical, fst_ref);
+        Ok(())
+    }
+}
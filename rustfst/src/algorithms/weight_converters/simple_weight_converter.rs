@@ -7,6 +7,9 @@ use crate::Tr;
 
 /// Mapper that leaves labels and nextstate unchanged and constructs a new weight
 /// from the underlying value of the transition weight.
+///
+/// For instance, converts a `LogWeight` into the `TropicalWeight` storing the same value
+/// (and conversely), i.e the same conversion as the `From` implementations between them.
 pub struct SimpleWeightConverter {}
 
 impl<SI, SO> WeightConverter<SI, SO> for SimpleWeightConverter
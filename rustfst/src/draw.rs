@@ -0,0 +1,242 @@
+use std::io::Write;
+
+use anyhow::{Context, Result};
+
+use crate::fst_traits::ExpandedFst;
+use crate::semirings::SerializableSemiring;
+use crate::{DrawingConfig, FstError, Label, StateId, SymbolTable, Trs};
+
+/// Returns the Graphviz (DOT) representation of an FST.
+///
+/// The start state is drawn bold and the final states as double circles. The trs are labelled
+/// `ilabel:olabel/weight` (`ilabel/weight` in acceptor mode), printing the symbols of the symbol
+/// tables when they are provided and the labels otherwise. A label missing from its symbol table
+/// is an error, unless `config.numeric_labels_fallback` is set.
+///
+/// # Example
+///
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::draw::draw;
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::fst_traits::MutableFst;
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::{DrawingConfig, SymbolTable, Tr};
+/// # fn main() -> Result<()> {
+/// let mut fst = VectorFst::<TropicalWeight>::new();
+/// fst.add_states(2);
+/// fst.set_start(0)?;
+/// fst.set_final(1, TropicalWeight::one())?;
+/// fst.add_tr(0, Tr::new(1, 2, 0.5, 1))?;
+///
+/// let mut symt = SymbolTable::new();
+/// symt.add_symbols(vec!["a", "b"]);
+///
+/// let dot = draw(&fst, Some(&symt), Some(&symt), &DrawingConfig::default())?;
+/// assert!(dot.contains("\t0 -> 1 [label = \"a:b/0.5\", fontsize = 14];"));
+/// # Ok(())
+/// # }
+/// ```
+pub fn draw<W, F>(
+    fst: &F,
+    isymt: Option<&SymbolTable>,
+    osymt: Option<&SymbolTable>,
+    config: &DrawingConfig,
+) -> Result<String>
+where
+    W: SerializableSemiring,
+    F: ExpandedFst<W>,
+{
+    let mut buffer = Vec::<u8>::new();
+    write_dot(fst, &mut buffer, isymt, osymt, config)?;
+    Ok(String::from_utf8(buffer)?)
+}
+
+pub(crate) fn write_dot<W, F, O>(
+    fst: &F,
+    f: &mut O,
+    isymt: Option<&SymbolTable>,
+    osymt: Option<&SymbolTable>,
+    config: &DrawingConfig,
+) -> Result<()>
+where
+    W: SerializableSemiring,
+    F: ExpandedFst<W>,
+    O: Write,
+{
+    if let Some(start_state) = fst.start() {
+        writeln!(f, "digraph FST {{")?;
+
+        if config.vertical {
+            writeln!(f, "rankdir = BT;")?;
+        } else {
+            writeln!(f, "rankdir = LR;")?;
+        }
+
+        if let Some((width, height)) = config.size {
+            writeln!(f, "size = \"{},{}\";", width, height)?;
+        }
+
+        writeln!(f, "label = \"{}\";", config.title)?;
+        writeln!(f, "center = 1;")?;
+
+        if config.portrait {
+            writeln!(f, "orientation = Portrait;")?;
+        } else {
+            writeln!(f, "orientation = Landscape;")?;
+        }
+
+        if let Some(ranksep) = config.ranksep {
+            writeln!(f, "ranksep = {}", ranksep)?;
+        }
+
+        if let Some(nodesep) = config.nodesep {
+            writeln!(f, "nodesep = {}", nodesep)?;
+        }
+
+        // Start state first
+        draw_single_state(fst, &mut *f, start_state, isymt, osymt, config)?;
+
+        for state in fst.states_iter() {
+            if state != start_state {
+                draw_single_state(fst, &mut *f, state, isymt, osymt, config)?;
+            }
+        }
+
+        writeln!(f, "}}")?;
+    }
+    Ok(())
+}
+
+fn label_to_text(
+    label: Label,
+    symt: Option<&SymbolTable>,
+    side: &str,
+    config: &DrawingConfig,
+) -> Result<String> {
+    match symt.map(|symt| symt.get_symbol(label)) {
+        Some(Some(symbol)) => Ok(symbol.to_string()),
+        Some(None) if !config.numeric_labels_fallback => Err(FstError::LabelNotFound(label))
+            .with_context(|| format!("Missing {} in {} SymbolTable", label, side)),
+        _ => Ok(format!("{}", label)),
+    }
+}
+
+fn draw_single_state<W, F, O>(
+    fst: &F,
+    writer: &mut O,
+    state_id: StateId,
+    isymt: Option<&SymbolTable>,
+    osymt: Option<&SymbolTable>,
+    config: &DrawingConfig,
+) -> Result<()>
+where
+    W: SerializableSemiring,
+    F: ExpandedFst<W>,
+    O: Write,
+{
+    write!(writer, "{}", state_id)?;
+    write!(writer, " [label = \"{}", state_id)?;
+    if let Some(final_weight) = fst.final_weight(state_id)? {
+        if config.print_weight && (config.show_weight_one || !final_weight.is_one()) {
+            write!(writer, "/{}", final_weight)?;
+        }
+        write!(writer, "\", shape = doublecircle,")?;
+    } else {
+        write!(writer, "\", shape = circle,")?;
+    }
+
+    if fst.is_start(state_id) {
+        write!(writer, " style = bold,")?;
+    } else {
+        write!(writer, " style = solid,")?;
+    }
+
+    writeln!(writer, " fontsize = {}]", config.fontsize)?;
+
+    for tr in fst.get_trs(state_id)?.trs() {
+        write!(writer, "\t{} -> {}", state_id, tr.nextstate)?;
+
+        let ilabel = label_to_text(tr.ilabel, isymt, "input", config)?;
+        write!(writer, " [label = \"{}", ilabel)?;
+        if !config.acceptor {
+            let olabel = label_to_text(tr.olabel, osymt, "output", config)?;
+            write!(writer, ":{}", olabel)?;
+        }
+
+        if config.print_weight && (config.show_weight_one || !tr.weight.is_one()) {
+            write!(writer, "/{}", tr.weight)?;
+        }
+        writeln!(writer, "\", fontsize = {}];", config.fontsize)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::MutableFst;
+    use crate::semirings::{Semiring, TropicalWeight};
+    use crate::Tr;
+
+    fn two_states_acceptor() -> Result<VectorFst<TropicalWeight>> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(2);
+        fst.set_start(0)?;
+        fst.set_final(1, TropicalWeight::new(0.25))?;
+        fst.add_tr(0, Tr::new(1, 1, 0.5, 1))?;
+        fst.add_tr(0, Tr::new(2, 2, TropicalWeight::one(), 1))?;
+        Ok(fst)
+    }
+
+    #[test]
+    fn test_draw_acceptor() -> Result<()> {
+        let fst = two_states_acceptor()?;
+        let mut symt = SymbolTable::new();
+        symt.add_symbols(vec!["a", "b"]);
+        let config = DrawingConfig {
+            acceptor: true,
+            vertical: true,
+            show_weight_one: false,
+            ..DrawingConfig::default()
+        };
+
+        let dot = draw(&fst, Some(&symt), None, &config)?;
+
+        assert!(dot.starts_with("digraph FST {\nrankdir = BT;\n"));
+        assert!(dot.contains("0 [label = \"0\", shape = circle, style = bold, fontsize = 14]"));
+        assert!(dot.contains(
+            "1 [label = \"1/0.25\", shape = doublecircle, style = solid, fontsize = 14]"
+        ));
+        assert!(dot.contains("\t0 -> 1 [label = \"a/0.5\", fontsize = 14];"));
+        assert!(dot.contains("\t0 -> 1 [label = \"b\", fontsize = 14];"));
+        assert!(dot.ends_with("}\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_draw_missing_labels() -> Result<()> {
+        let fst = two_states_acceptor()?;
+        let mut symt = SymbolTable::new();
+        symt.add_symbol("a");
+
+        let config = DrawingConfig::default();
+        let err = draw(&fst, Some(&symt), None, &config).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<FstError>(),
+            Some(&FstError::LabelNotFound(2))
+        );
+
+        let config = DrawingConfig {
+            numeric_labels_fallback: true,
+            ..DrawingConfig::default()
+        };
+        let dot = draw(&fst, Some(&symt), None, &config)?;
+        assert!(dot.contains("\t0 -> 1 [label = \"a:1/0.5\", fontsize = 14];"));
+        assert!(dot.contains("\t0 -> 1 [label = \"2:2/0\", fontsize = 14];"));
+        Ok(())
+    }
+}
@@ -21,6 +21,8 @@ pub struct DrawingConfig {
     pub show_weight_one: bool,
     /// Print/draw transition weights and final weights.
     pub print_weight: bool,
+    /// Print the labels missing from the symbol tables instead of failing.
+    pub numeric_labels_fallback: bool,
 }
 
 impl Default for DrawingConfig {
@@ -36,6 +38,7 @@ impl Default for DrawingConfig {
             acceptor: false,
             show_weight_one: true,
             print_weight: true,
+            numeric_labels_fallback: false,
         }
     }
 }
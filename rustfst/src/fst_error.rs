@@ -0,0 +1,53 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::{Label, StateId, Symbol};
+
+/// Errors that can be matched on programmatically.
+///
+/// Most functions of the crate return an `anyhow::Result`. When the failure is one of the
+/// following, the `anyhow::Error` wraps an `FstError` which can be retrieved with
+/// `downcast_ref`. The other failures are only described by their message.
+///
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::prelude::*;
+/// # use rustfst::FstError;
+/// # fn main() -> Result<()> {
+/// let fst = VectorFst::<TropicalWeight>::new();
+/// let err = fst.num_trs(3).unwrap_err();
+/// assert_eq!(err.downcast_ref::<FstError>(), Some(&FstError::StateNotFound(3)));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FstError {
+    /// The state doesn't exist in the FST.
+    StateNotFound(StateId),
+    /// The label is not in the symbol table.
+    LabelNotFound(Label),
+    /// The symbol is not in the symbol table.
+    SymbolNotFound(Symbol),
+    /// The FST is non-deterministic and can't be determinized.
+    NonDeterministic,
+    /// The FST is cyclic while the operation requires an acyclic FST.
+    Cyclic,
+}
+
+impl fmt::Display for FstError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FstError::StateNotFound(state) => write!(f, "State {:?} doesn't exist", state),
+            FstError::LabelNotFound(label) => {
+                write!(f, "Label {:?} is not in the symbol table", label)
+            }
+            FstError::SymbolNotFound(symbol) => {
+                write!(f, "Symbol {:?} is not in the symbol table", symbol)
+            }
+            FstError::NonDeterministic => write!(f, "The FST can't be determinized"),
+            FstError::Cyclic => write!(f, "The FST must be acyclic"),
+        }
+    }
+}
+
+impl Error for FstError {}
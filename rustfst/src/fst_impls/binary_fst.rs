@@ -0,0 +1,188 @@
+use std::fs::read;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::fst_impls::const_fst::parse_const_fst;
+use crate::fst_impls::vector_fst::parse_vector_fst;
+use crate::fst_impls::{ConstFst, VectorFst};
+use crate::fst_properties::FstProperties;
+use crate::fst_traits::SerializableFst;
+use crate::parsers::bin_fst::fst_header::{FstFlags, FstHeader};
+use crate::parsers::bin_fst::utils_parsing::parse_start_state;
+use crate::semirings::{Semiring, SerializableSemiring};
+use crate::StateId;
+
+/// Header of an FST serialized in the OpenFST binary format. These are the fields displayed
+/// by `fstinfo`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BinaryFstHeader {
+    /// Type of the FST, e.g `vector` or `const`.
+    pub fst_type: String,
+    /// Type of the trs, e.g `standard` for the tropical semiring.
+    pub tr_type: String,
+    /// Version of the file format.
+    pub version: i32,
+    pub properties: FstProperties,
+    pub start: Option<StateId>,
+    pub num_states: usize,
+    pub num_trs: usize,
+    pub has_input_symbols: bool,
+    pub has_output_symbols: bool,
+}
+
+impl From<FstHeader> for BinaryFstHeader {
+    fn from(header: FstHeader) -> Self {
+        Self {
+            fst_type: header.fst_type.as_str().to_string(),
+            tr_type: header.tr_type.as_str().to_string(),
+            version: header.version,
+            properties: FstProperties::from_bits_truncate(header.properties),
+            start: parse_start_state(header.start),
+            num_states: header.num_states as usize,
+            num_trs: header.num_trs as usize,
+            has_input_symbols: header.flags.contains(FstFlags::HAS_ISYMBOLS),
+            has_output_symbols: header.flags.contains(FstFlags::HAS_OSYMBOLS),
+        }
+    }
+}
+
+/// FST read from a file in the OpenFST binary format by `read_fst`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BinaryFst<W: Semiring> {
+    Vector(VectorFst<W>),
+    Const(ConstFst<W>),
+}
+
+fn parse_header(data: &[u8]) -> Result<BinaryFstHeader> {
+    let (_, header) = FstHeader::parse_any(data)
+        .map_err(|e| format_err!("Error while parsing binary FST header : {:?}", e))?;
+    Ok(header.into())
+}
+
+/// Reads the header of an FST serialized in the OpenFST binary format, whatever its type.
+pub fn read_fst_header<P: AsRef<Path>>(path_bin_fst: P) -> Result<BinaryFstHeader> {
+    let data = read(path_bin_fst.as_ref())
+        .with_context(|| format!("Can't open binary FST file : {:?}", path_bin_fst.as_ref()))?;
+    parse_header(&data)
+}
+
+/// Reads an FST serialized in the OpenFST binary format. The type of the FST (`vector` or
+/// `const`) is detected from the header of the file.
+///
+/// # Example
+///
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::fst_impls::{read_fst, BinaryFst, ConstFst, VectorFst};
+/// # use rustfst::fst_traits::SerializableFst;
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::utils::acceptor;
+/// # fn main() -> Result<()> {
+/// # let dir = tempfile::tempdir()?;
+/// # let path = dir.path().join("fst.bin");
+/// let fst: VectorFst<TropicalWeight> = acceptor(&[1, 2], TropicalWeight::one());
+/// let const_fst: ConstFst<_> = fst.into();
+/// const_fst.write(&path)?;
+///
+/// assert_eq!(read_fst(&path)?, BinaryFst::Const(const_fst));
+/// # Ok(())
+/// # }
+/// ```
+pub fn read_fst<W, P>(path_bin_fst: P) -> Result<BinaryFst<W>>
+where
+    W: SerializableSemiring,
+    P: AsRef<Path>,
+{
+    let data = read(path_bin_fst.as_ref())
+        .with_context(|| format!("Can't open binary FST file : {:?}", path_bin_fst.as_ref()))?;
+    let header = parse_header(&data)?;
+    let fst_type = header.fst_type.as_str();
+    if fst_type == VectorFst::<W>::fst_type() {
+        let (_, fst) = parse_vector_fst(&data)
+            .map_err(|e| format_err!("Error while parsing binary VectorFst : {:?}", e))?;
+        Ok(BinaryFst::Vector(fst))
+    } else if fst_type == ConstFst::<W>::fst_type() {
+        let (_, fst) = parse_const_fst(&data)
+            .map_err(|e| format_err!("Error while parsing binary ConstFst : {:?}", e))?;
+        Ok(BinaryFst::Const(fst))
+    } else {
+        bail!("Unsupported FST type : {:?}", fst_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    use tempfile::tempdir;
+
+    use crate::fst_traits::{CoreFst, Fst, MutableFst};
+    use crate::semirings::{LogWeight, TropicalWeight};
+    use crate::{SymbolTable, Tr};
+
+    fn build_fst() -> Result<VectorFst<TropicalWeight>> {
+        let mut fst = VectorFst::new();
+        let s0 = fst.add_state();
+        let s1 = fst.add_state();
+        let s2 = fst.add_state();
+        fst.set_start(s1)?;
+        fst.set_final(s2, 0.5)?;
+        fst.add_tr(s1, Tr::new(1, 2, 1.0, s2))?;
+        fst.add_tr(s1, Tr::new(2, 2, 1.5, s0))?;
+        fst.add_tr(s0, Tr::new(3, 3, 0.2, s2))?;
+        let mut symt = SymbolTable::new();
+        symt.add_symbol("a");
+        fst.set_input_symbols(Arc::new(symt));
+        Ok(fst)
+    }
+
+    #[test]
+    fn test_read_fst_header() -> Result<()> {
+        let fst = build_fst()?;
+        let dir = tempdir()?;
+        let path = dir.path().join("fst.bin");
+        fst.write(&path)?;
+
+        let header = read_fst_header(&path)?;
+        assert_eq!(header.fst_type, "vector");
+        assert_eq!(header.tr_type, "standard");
+        assert_eq!(header.start, Some(1));
+        assert_eq!(header.num_states, 3);
+        assert_eq!(header.num_trs, 3);
+        assert!(header.properties.contains(fst.properties()));
+        assert!(header.has_input_symbols);
+        assert!(!header.has_output_symbols);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_fst_detects_type() -> Result<()> {
+        let fst = build_fst()?;
+        let dir = tempdir()?;
+
+        let path_vector = dir.path().join("vector.fst");
+        fst.write(&path_vector)?;
+        assert_eq!(read_fst(&path_vector)?, BinaryFst::Vector(fst.clone()));
+
+        let path_const = dir.path().join("const.fst");
+        let const_fst: ConstFst<_> = fst.into();
+        const_fst.write(&path_const)?;
+        assert_eq!(read_fst_header(&path_const)?.fst_type, "const");
+        assert_eq!(read_fst(&path_const)?, BinaryFst::Const(const_fst));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_fst_wrong_tr_type() -> Result<()> {
+        let fst = build_fst()?;
+        let dir = tempdir()?;
+        let path = dir.path().join("fst.bin");
+        fst.write(&path)?;
+
+        assert!(read_fst::<LogWeight, _>(&path).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt::Debug;
+
+use anyhow::Result;
+
+use crate::semirings::Semiring;
+use crate::{Label, StateId, Tr};
+
+/// Defines how the trs of a `CompactFst` are stored in memory. A tr leaving a state is
+/// compacted into an `Element` when the `CompactFst` is built and expanded back every time
+/// the trs of the state are accessed.
+pub trait Compactor<W: Semiring>: Debug + Clone + PartialEq + 'static {
+    type Element: Debug + Clone + PartialEq + 'static;
+
+    /// Compacts a tr leaving `state`. An error is returned if the tr can't be represented
+    /// by this compactor.
+    fn compact(state: StateId, tr: &Tr<W>) -> Result<Self::Element>;
+
+    /// Expands an element stored for a tr leaving `state` into the original tr.
+    fn expand(state: StateId, element: &Self::Element) -> Tr<W>;
+}
+
+/// Compactor for weighted acceptors : the output label is not stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AcceptorCompactor {}
+
+impl<W: Semiring> Compactor<W> for AcceptorCompactor {
+    type Element = (Label, W, StateId);
+
+    fn compact(state: StateId, tr: &Tr<W>) -> Result<Self::Element> {
+        if tr.ilabel != tr.olabel {
+            bail!(
+                "AcceptorCompactor : tr {:?} leaving state {:?} has different input and output labels",
+                tr,
+                state
+            )
+        }
+        Ok((tr.ilabel, tr.weight.clone(), tr.nextstate))
+    }
+
+    fn expand(_state: StateId, element: &Self::Element) -> Tr<W> {
+        Tr::new(element.0, element.0, element.1.clone(), element.2)
+    }
+}
+
+/// Compactor for unweighted acceptors : only the label and the next state are stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UnweightedAcceptorCompactor {}
+
+impl<W: Semiring> Compactor<W> for UnweightedAcceptorCompactor {
+    type Element = (Label, StateId);
+
+    fn compact(state: StateId, tr: &Tr<W>) -> Result<Self::Element> {
+        if tr.ilabel != tr.olabel || !tr.weight.is_one() {
+            bail!(
+                "UnweightedAcceptorCompactor : tr {:?} leaving state {:?} is weighted or has different input and output labels",
+                tr,
+                state
+            )
+        }
+        Ok((tr.ilabel, tr.nextstate))
+    }
+
+    fn expand(_state: StateId, element: &Self::Element) -> Tr<W> {
+        Tr::new(element.0, element.0, W::one(), element.1)
+    }
+}
+
+/// Compactor for unweighted strings : every tr leaving a state `s` goes to the state `s + 1`
+/// so that only the label is stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StringCompactor {}
+
+impl<W: Semiring> Compactor<W> for StringCompactor {
+    type Element = Label;
+
+    fn compact(state: StateId, tr: &Tr<W>) -> Result<Self::Element> {
+        if tr.ilabel != tr.olabel || !tr.weight.is_one() || tr.nextstate != state + 1 {
+            bail!(
+                "StringCompactor : tr {:?} leaving state {:?} is not part of an unweighted string",
+                tr,
+                state
+            )
+        }
+        Ok(tr.ilabel)
+    }
+
+    fn expand(state: StateId, element: &Self::Element) -> Tr<W> {
+        Tr::new(*element, *element, W::one(), state + 1)
+    }
+}
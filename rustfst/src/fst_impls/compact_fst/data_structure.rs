@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::fst_impls::compact_fst::Compactor;
+use crate::fst_properties::{compute_fst_properties, FstProperties};
+use crate::fst_traits::ExpandedFst;
+use crate::semirings::Semiring;
+use crate::{StateId, SymbolTable, Tr, Trs};
+
+/// Immutable FST whose trs are stored in a compacted form defined by a `Compactor`.
+/// The trs of a state are expanded on the fly when they are accessed.
+#[derive(Debug, Clone)]
+pub struct CompactFst<W: Semiring, C: Compactor<W>> {
+    pub(crate) states: Vec<CompactState<W>>,
+    pub(crate) compact_trs: Arc<Vec<C::Element>>,
+    pub(crate) start: Option<StateId>,
+    pub(crate) isymt: Option<Arc<SymbolTable>>,
+    pub(crate) osymt: Option<Arc<SymbolTable>>,
+    pub(crate) properties: FstProperties,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CompactState<W> {
+    /// Final Weight
+    pub(crate) final_weight: Option<W>,
+    /// Start of state's trs in `compact_trs`.
+    pub(crate) pos: usize,
+    /// Number of trs (per state).
+    pub(crate) ntrs: usize,
+    /// Number of input epsilons
+    pub(crate) niepsilons: usize,
+    /// Number of output epsilons
+    pub(crate) noepsilons: usize,
+}
+
+impl<W: Semiring, C: Compactor<W>> CompactFst<W, C> {
+    /// Builds a `CompactFst` from any expanded FST. An error is returned if one of its trs
+    /// can't be compacted.
+    ///
+    /// # Example
+    /// ```
+    /// # use anyhow::Result;
+    /// # use rustfst::semirings::{Semiring, TropicalWeight};
+    /// # use rustfst::fst_impls::{CompactFst, StringCompactor, VectorFst};
+    /// # use rustfst::fst_traits::{CoreFst, ExpandedFst};
+    /// # use rustfst::utils::acceptor;
+    /// # fn main() -> Result<()> {
+    /// let fst: VectorFst<TropicalWeight> = acceptor(&[1, 2, 3], TropicalWeight::one());
+    /// let compact_fst = CompactFst::<_, StringCompactor>::from_fst(&fst)?;
+    ///
+    /// assert_eq!(compact_fst.num_states(), 4);
+    /// assert_eq!(compact_fst.get_trs(1)?.trs(), fst.get_trs(1)?.trs());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_fst<F: ExpandedFst<W>>(fst: &F) -> Result<Self> {
+        let mut known = FstProperties::empty();
+        let properties =
+            compute_fst_properties(fst, FstProperties::all_properties(), &mut known, true)?;
+
+        let mut states = Vec::with_capacity(fst.num_states());
+        let mut compact_trs = vec![];
+        for state in fst.states_range() {
+            let trs = fst.get_trs(state)?;
+            states.push(CompactState {
+                final_weight: fst.final_weight(state)?,
+                pos: compact_trs.len(),
+                ntrs: trs.len(),
+                niepsilons: fst.num_input_epsilons(state)?,
+                noepsilons: fst.num_output_epsilons(state)?,
+            });
+            for tr in trs.trs() {
+                compact_trs.push(C::compact(state, tr)?);
+            }
+        }
+
+        Ok(Self {
+            states,
+            compact_trs: Arc::new(compact_trs),
+            start: fst.start(),
+            isymt: fst.input_symbols().cloned(),
+            osymt: fst.output_symbols().cloned(),
+            properties,
+        })
+    }
+
+    pub(crate) fn expand_trs(&self, state_id: StateId, state: &CompactState<W>) -> Vec<Tr<W>> {
+        self.compact_trs[state.pos..state.pos + state.ntrs]
+            .iter()
+            .map(|element| C::expand(state_id, element))
+            .collect()
+    }
+}
+
+impl<W: Semiring, C: Compactor<W>> PartialEq for CompactFst<W, C> {
+    fn eq(&self, other: &Self) -> bool {
+        // Indended: Doesn't check symt and properties
+        self.states == other.states
+            && self.compact_trs == other.compact_trs
+            && self.start == other.start
+    }
+}
@@ -0,0 +1,9 @@
+use crate::fst_impls::{CompactFst, Compactor};
+use crate::fst_traits::ExpandedFst;
+use crate::semirings::Semiring;
+
+impl<W: Semiring, C: Compactor<W>> ExpandedFst<W> for CompactFst<W, C> {
+    fn num_states(&self) -> usize {
+        self.states.len()
+    }
+}
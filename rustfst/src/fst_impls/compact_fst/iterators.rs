@@ -0,0 +1,61 @@
+use std::ops::Range;
+
+use crate::fst_impls::{CompactFst, Compactor};
+use crate::fst_traits::FstIterData;
+use crate::fst_traits::{FstIntoIterator, FstIterator, StateIterator};
+use crate::semirings::Semiring;
+use crate::{StateId, Tr, TrsVec};
+
+impl<W: Semiring, C: Compactor<W>> FstIntoIterator<W> for CompactFst<W, C> {
+    type TrsIter = std::vec::IntoIter<Tr<W>>;
+    // TODO: Change this to impl once the feature has been stabilized
+    // #![feature(type_alias_impl_trait)]
+    // https://github.com/rust-lang/rust/issues/63063)
+    type FstIter = Box<dyn Iterator<Item = FstIterData<W, Self::TrsIter>>>;
+
+    fn fst_into_iter(self) -> Self::FstIter {
+        let compact_trs = self.compact_trs;
+        Box::new(
+            self.states
+                .into_iter()
+                .enumerate()
+                .map(move |(state_id, state)| {
+                    let state_id = state_id as StateId;
+                    let trs = compact_trs[state.pos..state.pos + state.ntrs]
+                        .iter()
+                        .map(|element| C::expand(state_id, element))
+                        .collect::<Vec<_>>();
+                    FstIterData {
+                        state_id,
+                        trs: trs.into_iter(),
+                        final_weight: state.final_weight,
+                        num_trs: state.ntrs,
+                    }
+                }),
+        )
+    }
+}
+
+impl<'a, W: Semiring, C: Compactor<W>> StateIterator<'a> for CompactFst<W, C> {
+    type Iter = Range<StateId>;
+    fn states_iter(&'a self) -> Self::Iter {
+        0..(self.states.len() as StateId)
+    }
+}
+
+impl<'a, W: Semiring, C: Compactor<W>> FstIterator<'a, W> for CompactFst<W, C> {
+    type FstIter = Box<dyn Iterator<Item = FstIterData<W, Self::TRS>> + 'a>;
+    fn fst_iter(&'a self) -> Self::FstIter {
+        Box::new(
+            self.states
+                .iter()
+                .enumerate()
+                .map(move |(state_id, state)| FstIterData {
+                    state_id: state_id as StateId,
+                    trs: TrsVec::from(self.expand_trs(state_id as StateId, state)),
+                    final_weight: state.final_weight.clone(),
+                    num_trs: state.ntrs,
+                }),
+        )
+    }
+}
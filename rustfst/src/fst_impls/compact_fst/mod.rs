@@ -0,0 +1,11 @@
+pub use self::compactors::{
+    AcceptorCompactor, Compactor, StringCompactor, UnweightedAcceptorCompactor,
+};
+pub use self::data_structure::CompactFst;
+
+mod compactors;
+mod data_structure;
+mod expanded_fst;
+mod fst;
+mod iterators;
+mod test;
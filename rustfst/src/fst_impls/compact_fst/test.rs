@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::algorithms::fst_convert_from_ref;
+    use crate::fst_impls::{
+        AcceptorCompactor, CompactFst, StringCompactor, UnweightedAcceptorCompactor, VectorFst,
+    };
+    use crate::fst_traits::{CoreFst, ExpandedFst, MutableFst, PathsIterator};
+    use crate::semirings::{Semiring, TropicalWeight};
+    use crate::tr::Tr;
+    use crate::utils::{acceptor, transducer};
+    use crate::Trs;
+
+    fn weighted_acceptor() -> Result<VectorFst<TropicalWeight>> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(3);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(0, Tr::new(2, 2, 2.0, 2))?;
+        fst.add_tr(1, Tr::new(3, 3, 3.0, 2))?;
+        fst.add_tr(2, Tr::new(4, 4, 4.0, 0))?;
+        fst.set_final(2, 0.5)?;
+        Ok(fst)
+    }
+
+    #[test]
+    fn test_acceptor_compactor_round_trip() -> Result<()> {
+        let fst = weighted_acceptor()?;
+
+        let compact_fst = CompactFst::<_, AcceptorCompactor>::from_fst(&fst)?;
+        assert_eq!(compact_fst.num_states(), fst.num_states());
+        assert_eq!(compact_fst.start(), fst.start());
+        for state in fst.states_range() {
+            assert_eq!(compact_fst.get_trs(state)?.trs(), fst.get_trs(state)?.trs());
+            assert_eq!(compact_fst.final_weight(state)?, fst.final_weight(state)?);
+        }
+
+        let fst_back: VectorFst<_> = fst_convert_from_ref(&compact_fst);
+        assert_eq!(fst_back, fst);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unweighted_acceptor_compactor() -> Result<()> {
+        let mut fst: VectorFst<TropicalWeight> = acceptor(&[1, 2], TropicalWeight::one());
+        fst.add_tr(1, Tr::new(3, 3, TropicalWeight::one(), 0))?;
+
+        let compact_fst = CompactFst::<_, UnweightedAcceptorCompactor>::from_fst(&fst)?;
+        let fst_back: VectorFst<_> = fst_convert_from_ref(&compact_fst);
+        assert_eq!(fst_back, fst);
+
+        assert!(
+            CompactFst::<_, UnweightedAcceptorCompactor>::from_fst(&weighted_acceptor()?).is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_string_compactor() -> Result<()> {
+        let fst: VectorFst<TropicalWeight> = acceptor(&[1, 2, 3], TropicalWeight::new(2.0));
+
+        let compact_fst = CompactFst::<_, StringCompactor>::from_fst(&fst)?;
+        let paths: Vec<_> = compact_fst.paths_iter().collect();
+        let paths_ref: Vec<_> = fst.paths_iter().collect();
+        assert_eq!(paths, paths_ref);
+
+        assert!(CompactFst::<_, StringCompactor>::from_fst(&weighted_acceptor()?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_acceptor_compactor_transducer() -> Result<()> {
+        let fst: VectorFst<TropicalWeight> = transducer(&[1], &[2], TropicalWeight::one());
+        assert!(CompactFst::<_, AcceptorCompactor>::from_fst(&fst).is_err());
+        Ok(())
+    }
+}
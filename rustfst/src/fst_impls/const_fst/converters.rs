@@ -1,7 +1,9 @@
 use crate::fst_impls::const_fst::data_structure::ConstState;
+use crate::fst_impls::vector_fst::VectorFstState;
 use crate::fst_impls::{ConstFst, VectorFst};
 use crate::fst_traits::{ExpandedFst, MutableFst};
 use crate::semirings::Semiring;
+use crate::TrsVec;
 use std::sync::Arc;
 
 impl<W: Semiring> From<VectorFst<W>> for ConstFst<W> {
@@ -35,3 +37,37 @@ impl<W: Semiring> From<VectorFst<W>> for ConstFst<W> {
         }
     }
 }
+
+impl<W: Semiring> From<ConstFst<W>> for VectorFst<W> {
+    fn from(ifst: ConstFst<W>) -> Self {
+        // The trs of a state are read at its position as they are not necessarily stored in
+        // the order of the states, for instance in a ConstFst read from a file.
+        let trs = &ifst.trs;
+        let states = ifst
+            .states
+            .into_iter()
+            .map(|s| VectorFstState {
+                final_weight: s.final_weight,
+                trs: TrsVec::from(trs[s.pos..s.pos + s.ntrs].to_vec()),
+                niepsilons: s.niepsilons,
+                noepsilons: s.noepsilons,
+            })
+            .collect();
+
+        VectorFst {
+            states,
+            start_state: ifst.start,
+            isymt: ifst.isymt,
+            osymt: ifst.osymt,
+            properties: ifst.properties,
+        }
+    }
+}
+
+impl<W: Semiring> ConstFst<W> {
+    /// Builds a `VectorFst` with the same states, trs, final weights, symbol tables and
+    /// properties, leaving the `ConstFst` untouched.
+    pub fn to_vector_fst(&self) -> VectorFst<W> {
+        self.clone().into()
+    }
+}
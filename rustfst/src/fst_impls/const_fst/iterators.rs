@@ -1,7 +1,9 @@
-use std::iter::{Enumerate, Map, Zip};
+use std::iter::{Enumerate, Map, Rev, Zip};
 use std::ops::Range;
+use std::slice;
 use std::sync::Arc;
 
+use anyhow::Result;
 use itertools::Itertools;
 use itertools::{izip, repeat_n, RepeatN};
 
@@ -10,8 +12,9 @@ use crate::fst_impls::ConstFst;
 use crate::fst_traits::FstIterData;
 use crate::fst_traits::{FstIntoIterator, FstIterator, StateIterator};
 use crate::semirings::Semiring;
+use crate::trs_iter_mut::TrsWeightsIterMut;
 use crate::Tr;
-use crate::{StateId, TrsConst};
+use crate::{FstError, StateId, TrsConst};
 
 impl<W: Semiring> FstIntoIterator<W> for ConstFst<W>
 where
@@ -23,22 +26,23 @@ where
     // https://github.com/rust-lang/rust/issues/63063)
     type FstIter = Box<dyn Iterator<Item = FstIterData<W, Self::TrsIter>>>;
 
-    fn fst_into_iter(mut self) -> Self::FstIter {
-        // Here the contiguous trs are moved into multiple vectors in order to be able to create
-        // iterator for each states.
-        // TODO: Find a way to avoid this allocation.
-        let mut v_trs = Vec::with_capacity(self.states.len());
-        let trs = Arc::make_mut(&mut self.trs);
-        for const_state in &self.states {
-            v_trs.push(trs.drain(0..const_state.ntrs).collect_vec())
-        }
+    fn fst_into_iter(self) -> Self::FstIter {
+        // The contiguous trs are consumed with a single cursor : the trs of a state are only
+        // moved into their own vector once the iterator reaches this state.
+        let trs = Arc::try_unwrap(self.trs).unwrap_or_else(|trs| (*trs).clone());
+        let mut trs_iter = trs.into_iter();
 
         Box::new(
-            izip!(self.states.into_iter(), v_trs.into_iter())
+            self.states
+                .into_iter()
                 .enumerate()
-                .map(|(state_id, (const_state, trs_from_state))| FstIterData {
+                .map(move |(state_id, const_state)| FstIterData {
                     state_id: state_id as StateId,
-                    trs: trs_from_state.into_iter(),
+                    trs: trs_iter
+                        .by_ref()
+                        .take(const_state.ntrs)
+                        .collect_vec()
+                        .into_iter(),
                     final_weight: const_state.final_weight,
                     num_trs: const_state.ntrs,
                 }),
@@ -46,6 +50,39 @@ where
     }
 }
 
+impl<W: Semiring> ConstFst<W> {
+    /// Iterates over the trs leaving a state, without cloning them. As the trs of a `ConstFst`
+    /// are stored contiguously, the iterator is double-ended.
+    pub fn tr_iter(&self, state_id: StateId) -> Result<slice::Iter<Tr<W>>> {
+        let state = self
+            .states
+            .get(state_id as usize)
+            .ok_or(FstError::StateNotFound(state_id))?;
+        Ok(self.trs[state.pos..state.pos + state.ntrs].iter())
+    }
+
+    /// Iterates over the trs leaving a state from the last one to the first one.
+    pub fn tr_iter_rev(&self, state_id: StateId) -> Result<Rev<slice::Iter<Tr<W>>>> {
+        Ok(self.tr_iter(state_id)?.rev())
+    }
+
+    /// Gives a mutable access to the weights of the trs leaving a state. The trs of a `ConstFst`
+    /// are immutable otherwise : their labels and nextstates can't be modified as the number of
+    /// epsilons of each state is precomputed. The trs are cloned first if they are shared with
+    /// another `ConstFst`.
+    pub fn tr_weights_iter_mut(&mut self, state_id: StateId) -> Result<TrsWeightsIterMut<W>> {
+        let state = self
+            .states
+            .get(state_id as usize)
+            .ok_or(FstError::StateNotFound(state_id))?;
+        let trs = Arc::make_mut(&mut self.trs);
+        Ok(TrsWeightsIterMut::new(
+            &mut trs[state.pos..state.pos + state.ntrs],
+            &mut self.properties,
+        ))
+    }
+}
+
 impl<'a, W> StateIterator<'a> for ConstFst<W> {
     type Iter = Range<StateId>;
     fn states_iter(&'a self) -> Self::Iter {
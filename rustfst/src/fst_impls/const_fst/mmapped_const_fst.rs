@@ -0,0 +1,378 @@
+use std::fs::File;
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use memmap2::Mmap;
+use nom::multi::count;
+use unsafe_unwrap::UnsafeUnwrap;
+
+use crate::fst_impls::const_fst::data_structure::ConstState;
+use crate::fst_impls::const_fst::serializable_fst::parse_const_state;
+use crate::fst_impls::const_fst::{
+    CONST_ALIGNED_FILE_VERSION, CONST_ARCH_ALIGNMENT, CONST_MIN_FILE_VERSION,
+};
+use crate::fst_impls::ConstFst;
+use crate::fst_properties::FstProperties;
+use crate::fst_traits::{
+    CoreFst, ExpandedFst, Fst, FstIntoIterator, FstIterData, FstIterator, SerializableFst,
+    StateIterator,
+};
+use crate::parsers::bin_fst::fst_header::FstHeader;
+use crate::parsers::bin_fst::utils_parsing::{parse_bin_fst_tr, parse_start_state};
+use crate::semirings::SerializableSemiring;
+use crate::{FstError, StateId, SymbolTable, Tr, Trs, TrsVec};
+
+/// Read-only FST whose states and trs are read on demand from a memory-mapped `ConstFst` binary
+/// file instead of being loaded in RAM. Returned by `ConstFst::read_mmap`.
+///
+/// The states and the trs are stored in the file as arrays of fixed size records in the OpenFST
+/// layout, which differs from the in-memory layout of `ConstState<W>` and `Tr<W>`. As a result,
+/// no slice of `ConstState<W>` or `Tr<W>` is borrowed from the file : the records are decoded
+/// every time they are accessed and no alignment of the mapped regions is required. However, the
+/// binary representation of the weights must have a constant size (see
+/// `SerializableSemiring::binary_size`) to locate a record from its index. When it is not the
+/// case, the whole file is read and parsed into a `ConstFst` as done by `ConstFst::read`.
+///
+/// The states are checked when the file is mapped : the trs of every state must be in the file.
+#[derive(Debug, Clone)]
+pub struct MmappedConstFst<W: SerializableSemiring> {
+    repr: Repr<W>,
+}
+
+#[derive(Debug, Clone)]
+enum Repr<W: SerializableSemiring> {
+    Mapped(MappedConstFst<W>),
+    InMemory(ConstFst<W>),
+}
+
+#[derive(Debug, Clone)]
+struct MappedConstFst<W> {
+    mmap: Arc<Mmap>,
+    states_offset: usize,
+    state_size: usize,
+    trs_offset: usize,
+    tr_size: usize,
+    num_states: usize,
+    start: Option<StateId>,
+    isymt: Option<Arc<SymbolTable>>,
+    osymt: Option<Arc<SymbolTable>>,
+    properties: FstProperties,
+    w: PhantomData<W>,
+}
+
+fn align(pos: usize, aligned: bool) -> usize {
+    if aligned && pos % CONST_ARCH_ALIGNMENT > 0 {
+        pos + CONST_ARCH_ALIGNMENT - (pos % CONST_ARCH_ALIGNMENT)
+    } else {
+        pos
+    }
+}
+
+impl<W: SerializableSemiring> MappedConstFst<W> {
+    /// Returns `None` if the records can't be located in the file and an error if they
+    /// don't fit in it.
+    fn new(mmap: Mmap) -> Result<Option<Self>> {
+        let (i, hdr) = FstHeader::parse(
+            &mmap,
+            CONST_MIN_FILE_VERSION,
+            ConstFst::<W>::fst_type(),
+            Tr::<W>::tr_type(),
+        )
+        .map_err(|_| format_err!("Error while parsing header of binary ConstFst"))?;
+        let aligned = hdr.version == CONST_ALIGNED_FILE_VERSION;
+        let num_states = hdr.num_states as usize;
+        let num_trs = hdr.num_trs as usize;
+
+        // Variable size weights : the records can't be located from their index.
+        let weight_size = match W::binary_size() {
+            Some(weight_size) => weight_size,
+            None => return Ok(None),
+        };
+        let state_size = weight_size + 4 * 4;
+        let tr_size = weight_size + 3 * 4;
+
+        let header_size = mmap.len() - i.len();
+        let states_offset = if num_states > 0 {
+            align(header_size, aligned)
+        } else {
+            header_size
+        };
+        let states_end = states_offset + num_states * state_size;
+        let trs_offset = if num_trs > 0 {
+            align(states_end, aligned)
+        } else {
+            states_end
+        };
+        if trs_offset + num_trs * tr_size > mmap.len() {
+            bail!(
+                "ConstFst binary file is too short for {} states and {} trs",
+                num_states,
+                num_trs
+            );
+        }
+
+        let fst = Self {
+            mmap: Arc::new(mmap),
+            states_offset,
+            state_size,
+            trs_offset,
+            tr_size,
+            num_states,
+            start: parse_start_state(hdr.start),
+            isymt: hdr.isymt,
+            osymt: hdr.osymt,
+            properties: FstProperties::from_bits_truncate(hdr.properties),
+            w: PhantomData,
+        };
+
+        // All the states are checked here so that the unchecked accessors never fail.
+        for state in 0..num_states as StateId {
+            let const_state = fst.state(state)?;
+            match const_state.pos.checked_add(const_state.ntrs) {
+                Some(end) if end <= num_trs => {}
+                _ => bail!("Trs of state {:?} are out of the file", state),
+            }
+        }
+
+        Ok(Some(fst))
+    }
+
+    fn state(&self, state: StateId) -> Result<ConstState<W>> {
+        if state as usize >= self.num_states {
+            bail!(FstError::StateNotFound(state));
+        }
+        let pos = self.states_offset + state as usize * self.state_size;
+        let (_, const_state) = parse_const_state::<W>(&self.mmap[pos..pos + self.state_size])
+            .map_err(|_| format_err!("Error while parsing state {:?} of ConstFst", state))?;
+        Ok(const_state)
+    }
+
+    fn trs(&self, state: StateId) -> Result<TrsVec<W>> {
+        let const_state = self.state(state)?;
+        let pos = self.trs_offset + const_state.pos * self.tr_size;
+        let data = self
+            .mmap
+            .get(pos..pos + const_state.ntrs * self.tr_size)
+            .ok_or_else(|| format_err!("Trs of state {:?} are out of the file", state))?;
+        let (_, trs) = count(parse_bin_fst_tr::<W>, const_state.ntrs)(data)
+            .map_err(|_| format_err!("Error while parsing trs of state {:?} of ConstFst", state))?;
+        Ok(TrsVec::from(trs))
+    }
+}
+
+impl<W: SerializableSemiring> ConstFst<W> {
+    /// Memory-maps a `ConstFst` binary file. See `MmappedConstFst` for the constraints on the
+    /// binary layout.
+    ///
+    /// # Example
+    /// ```
+    /// # use anyhow::Result;
+    /// # use rustfst::semirings::{Semiring, TropicalWeight};
+    /// # use rustfst::fst_impls::{ConstFst, VectorFst};
+    /// # use rustfst::fst_traits::{CoreFst, ExpandedFst, SerializableFst};
+    /// # use rustfst::utils::acceptor;
+    /// # fn main() -> Result<()> {
+    /// let fst: VectorFst<TropicalWeight> = acceptor(&[1, 2, 3], TropicalWeight::one());
+    /// let fst: ConstFst<_> = fst.into();
+    /// let dir = tempfile::tempdir()?;
+    /// let path = dir.path().join("fst.bin");
+    /// fst.write(&path)?;
+    ///
+    /// let mmapped_fst = ConstFst::<TropicalWeight>::read_mmap(&path)?;
+    /// assert_eq!(mmapped_fst.num_states(), 4);
+    /// assert_eq!(mmapped_fst.get_trs(0)?.trs(), fst.get_trs(0)?.trs());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_mmap<P: AsRef<Path>>(path: P) -> Result<MmappedConstFst<W>> {
+        let file = File::open(path.as_ref())
+            .with_context(|| format!("Can't open ConstFst binary file : {:?}", path.as_ref()))?;
+        // Safety : the file must not be modified while it is mapped.
+        let mmap = unsafe { Mmap::map(&file)? };
+        let repr = match MappedConstFst::new(mmap)? {
+            Some(mapped) => Repr::Mapped(mapped),
+            None => Repr::InMemory(ConstFst::read(path)?),
+        };
+        Ok(MmappedConstFst { repr })
+    }
+}
+
+impl<W: SerializableSemiring> MmappedConstFst<W> {
+    /// Returns whether the FST is memory-mapped or has been loaded in RAM.
+    pub fn is_mapped(&self) -> bool {
+        match &self.repr {
+            Repr::Mapped(_) => true,
+            Repr::InMemory(_) => false,
+        }
+    }
+}
+
+impl<W: SerializableSemiring> CoreFst<W> for MmappedConstFst<W> {
+    type TRS = TrsVec<W>;
+
+    fn start(&self) -> Option<StateId> {
+        match &self.repr {
+            Repr::Mapped(fst) => fst.start,
+            Repr::InMemory(fst) => fst.start(),
+        }
+    }
+
+    fn final_weight(&self, state_id: StateId) -> Result<Option<W>> {
+        match &self.repr {
+            Repr::Mapped(fst) => Ok(fst.state(state_id)?.final_weight),
+            Repr::InMemory(fst) => fst.final_weight(state_id),
+        }
+    }
+
+    unsafe fn final_weight_unchecked(&self, state_id: StateId) -> Option<W> {
+        self.final_weight(state_id).unsafe_unwrap()
+    }
+
+    fn num_trs(&self, s: StateId) -> Result<usize> {
+        match &self.repr {
+            Repr::Mapped(fst) => Ok(fst.state(s)?.ntrs),
+            Repr::InMemory(fst) => fst.num_trs(s),
+        }
+    }
+
+    unsafe fn num_trs_unchecked(&self, s: StateId) -> usize {
+        self.num_trs(s).unsafe_unwrap()
+    }
+
+    fn get_trs(&self, state_id: StateId) -> Result<Self::TRS> {
+        match &self.repr {
+            Repr::Mapped(fst) => fst.trs(state_id),
+            Repr::InMemory(fst) => Ok(TrsVec::from(fst.get_trs(state_id)?.trs().to_vec())),
+        }
+    }
+
+    unsafe fn get_trs_unchecked(&self, state_id: StateId) -> Self::TRS {
+        self.get_trs(state_id).unsafe_unwrap()
+    }
+
+    fn properties(&self) -> FstProperties {
+        match &self.repr {
+            Repr::Mapped(fst) => fst.properties,
+            Repr::InMemory(fst) => fst.properties(),
+        }
+    }
+
+    fn num_input_epsilons(&self, state: StateId) -> Result<usize> {
+        match &self.repr {
+            Repr::Mapped(fst) => Ok(fst.state(state)?.niepsilons),
+            Repr::InMemory(fst) => fst.num_input_epsilons(state),
+        }
+    }
+
+    fn num_output_epsilons(&self, state: StateId) -> Result<usize> {
+        match &self.repr {
+            Repr::Mapped(fst) => Ok(fst.state(state)?.noepsilons),
+            Repr::InMemory(fst) => fst.num_output_epsilons(state),
+        }
+    }
+}
+
+impl<W: SerializableSemiring> Fst<W> for MmappedConstFst<W> {
+    fn input_symbols(&self) -> Option<&Arc<SymbolTable>> {
+        match &self.repr {
+            Repr::Mapped(fst) => fst.isymt.as_ref(),
+            Repr::InMemory(fst) => fst.input_symbols(),
+        }
+    }
+
+    fn output_symbols(&self) -> Option<&Arc<SymbolTable>> {
+        match &self.repr {
+            Repr::Mapped(fst) => fst.osymt.as_ref(),
+            Repr::InMemory(fst) => fst.output_symbols(),
+        }
+    }
+
+    fn set_input_symbols(&mut self, symt: Arc<SymbolTable>) {
+        match &mut self.repr {
+            Repr::Mapped(fst) => fst.isymt = Some(symt),
+            Repr::InMemory(fst) => fst.set_input_symbols(symt),
+        }
+    }
+
+    fn set_output_symbols(&mut self, symt: Arc<SymbolTable>) {
+        match &mut self.repr {
+            Repr::Mapped(fst) => fst.osymt = Some(symt),
+            Repr::InMemory(fst) => fst.set_output_symbols(symt),
+        }
+    }
+
+    fn take_input_symbols(&mut self) -> Option<Arc<SymbolTable>> {
+        match &mut self.repr {
+            Repr::Mapped(fst) => fst.isymt.take(),
+            Repr::InMemory(fst) => fst.take_input_symbols(),
+        }
+    }
+
+    fn take_output_symbols(&mut self) -> Option<Arc<SymbolTable>> {
+        match &mut self.repr {
+            Repr::Mapped(fst) => fst.osymt.take(),
+            Repr::InMemory(fst) => fst.take_output_symbols(),
+        }
+    }
+}
+
+impl<W: SerializableSemiring> ExpandedFst<W> for MmappedConstFst<W> {
+    fn num_states(&self) -> usize {
+        match &self.repr {
+            Repr::Mapped(fst) => fst.num_states,
+            Repr::InMemory(fst) => fst.num_states(),
+        }
+    }
+}
+
+impl<'a, W: SerializableSemiring> StateIterator<'a> for MmappedConstFst<W> {
+    type Iter = Range<StateId>;
+    fn states_iter(&'a self) -> Self::Iter {
+        0..(self.num_states() as StateId)
+    }
+}
+
+impl<'a, W: SerializableSemiring> FstIterator<'a, W> for MmappedConstFst<W> {
+    type FstIter = Box<dyn Iterator<Item = FstIterData<W, Self::TRS>> + 'a>;
+    fn fst_iter(&'a self) -> Self::FstIter {
+        Box::new(self.states_iter().map(move |state_id| unsafe {
+            FstIterData {
+                state_id,
+                trs: self.get_trs_unchecked(state_id),
+                final_weight: self.final_weight_unchecked(state_id),
+                num_trs: self.num_trs_unchecked(state_id),
+            }
+        }))
+    }
+}
+
+impl<W: SerializableSemiring> FstIntoIterator<W> for MmappedConstFst<W> {
+    type TrsIter = std::vec::IntoIter<Tr<W>>;
+    type FstIter = Box<dyn Iterator<Item = FstIterData<W, Self::TrsIter>>>;
+
+    fn fst_into_iter(self) -> Self::FstIter {
+        Box::new(self.states_iter().map(move |state_id| unsafe {
+            FstIterData {
+                state_id,
+                trs: self.get_trs_unchecked(state_id).trs().to_vec().into_iter(),
+                final_weight: self.final_weight_unchecked(state_id),
+                num_trs: self.num_trs_unchecked(state_id),
+            }
+        }))
+    }
+}
+
+impl<W: SerializableSemiring> PartialEq for MmappedConstFst<W> {
+    fn eq(&self, other: &Self) -> bool {
+        // Indended: Doesn't check symt and properties
+        self.start() == other.start()
+            && self.num_states() == other.num_states()
+            && self.states_iter().all(|s| unsafe {
+                self.final_weight_unchecked(s) == other.final_weight_unchecked(s)
+                    && self.get_trs_unchecked(s).trs() == other.get_trs_unchecked(s).trs()
+            })
+    }
+}
@@ -1,4 +1,6 @@
 pub use self::data_structure::ConstFst;
+pub use self::mmapped_const_fst::MmappedConstFst;
+pub(crate) use self::serializable_fst::parse_const_fst;
 
 mod converters;
 mod data_structure;
@@ -6,7 +8,11 @@ mod expanded_fst;
 mod fst;
 mod iterators;
 mod misc;
+mod mmapped_const_fst;
+#[cfg(feature = "rayon")]
+mod par_iterators;
 mod serializable_fst;
+mod test;
 
 pub(super) static CONST_MIN_FILE_VERSION: i32 = 1;
 pub(super) static CONST_ALIGNED_FILE_VERSION: i32 = 1;
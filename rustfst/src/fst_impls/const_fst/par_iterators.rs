@@ -0,0 +1,30 @@
+use rayon::prelude::*;
+
+use crate::fst_impls::ConstFst;
+use crate::fst_traits::FstIterData;
+use crate::semirings::Semiring;
+use crate::{StateId, Tr};
+
+impl<W: Semiring + Send> ConstFst<W> {
+    /// Parallel iterator over the states of the FST.
+    pub fn par_states_iter(&self) -> rayon::range::Iter<StateId> {
+        (0..(self.states.len() as StateId)).into_par_iter()
+    }
+
+    /// Parallel iterator over the states of the FST along with their final weight and their trs.
+    /// The trs are borrowed from the contiguous buffer of the FST.
+    pub fn par_fst_iter(
+        &self,
+    ) -> impl IndexedParallelIterator<Item = FstIterData<W, &[Tr<W>]>> + '_ {
+        let trs = self.trs.as_slice();
+        self.states
+            .par_iter()
+            .enumerate()
+            .map(move |(state_id, state)| FstIterData {
+                state_id: state_id as StateId,
+                final_weight: state.final_weight.clone(),
+                trs: &trs[state.pos..state.pos + state.ntrs],
+                num_trs: state.ntrs,
+            })
+    }
+}
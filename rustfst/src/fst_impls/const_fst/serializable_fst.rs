@@ -187,7 +187,7 @@ impl<W: SerializableSemiring> SerializableFst<W> for ConstFst<W> {
     }
 }
 
-fn parse_const_state<W: SerializableSemiring>(
+pub(crate) fn parse_const_state<W: SerializableSemiring>(
     i: &[u8],
 ) -> IResult<&[u8], ConstState<W>, NomCustomError<&[u8]>> {
     let (i, final_weight) = W::parse_binary(i)?;
@@ -208,7 +208,7 @@ fn parse_const_state<W: SerializableSemiring>(
     ))
 }
 
-fn parse_const_fst<W: SerializableSemiring>(
+pub(crate) fn parse_const_fst<W: SerializableSemiring>(
     i: &[u8],
 ) -> IResult<&[u8], ConstFst<W>, NomCustomError<&[u8]>> {
     let stream_len = i.len();
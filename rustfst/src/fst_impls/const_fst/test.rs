@@ -0,0 +1,309 @@
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use tempfile::tempdir;
+
+    use crate::fst_impls::{ConstFst, VectorFst};
+    use crate::fst_properties::FstProperties;
+    use crate::fst_traits::{
+        CoreFst, ExpandedFst, Fst, FstIntoIterator, FstIterator, MutableFst, SerializableFst,
+        StateIterator,
+    };
+    use crate::semirings::{Semiring, TropicalWeight};
+    use crate::tr::Tr;
+    use crate::Trs;
+    use crate::{Label, StateId, SymbolTable};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_tr_iter_rev() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(3);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(0, Tr::new(2, 2, 2.0, 2))?;
+        fst.add_tr(0, Tr::new(3, 3, 3.0, 2))?;
+        fst.add_tr(1, Tr::new(4, 4, 4.0, 2))?;
+        fst.set_final(2, TropicalWeight::one())?;
+        let fst: ConstFst<_> = fst.into();
+
+        for state in 0..3 {
+            let mut trs_forward: Vec<_> = fst.tr_iter(state)?.collect();
+            let trs_backward: Vec<_> = fst.tr_iter_rev(state)?.collect();
+            trs_forward.reverse();
+            assert_eq!(trs_backward, trs_forward);
+        }
+        assert_eq!(fst.tr_iter_rev(0)?.next(), Some(&Tr::new(3, 3, 3.0, 2)));
+        assert!(fst.tr_iter_rev(3).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_tr_weights_iter_mut() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(3);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, TropicalWeight::one(), 1))?;
+        fst.add_tr(0, Tr::new(2, 2, TropicalWeight::one(), 2))?;
+        fst.add_tr(1, Tr::new(3, 0, TropicalWeight::one(), 2))?;
+        fst.set_final(2, TropicalWeight::one())?;
+
+        let mut fst_ref = fst.clone();
+        for state in 0..3 {
+            let mut it_tr = fst_ref.tr_iter_mut(state)?;
+            for idx in 0..it_tr.len() {
+                it_tr.set_weight(
+                    idx,
+                    TropicalWeight::new((state as f32) + (idx as f32) + 1.0),
+                )?;
+            }
+        }
+        let fst_ref: ConstFst<_> = fst_ref.into();
+
+        let const_fst: ConstFst<_> = fst.into();
+        let mut fst = const_fst.clone();
+        assert!(fst.properties().contains(FstProperties::UNWEIGHTED));
+        for state in 0..3 {
+            let mut it_tr = fst.tr_weights_iter_mut(state)?;
+            for idx in 0..it_tr.len() {
+                it_tr.set_weight(
+                    idx,
+                    TropicalWeight::new((state as f32) + (idx as f32) + 1.0),
+                )?;
+            }
+        }
+
+        assert_eq!(fst, fst_ref);
+        assert!(fst.properties().contains(FstProperties::WEIGHTED));
+        assert!(!fst.properties().contains(FstProperties::UNWEIGHTED));
+        assert_eq!(fst.num_input_epsilons(1)?, 0);
+        assert_eq!(fst.num_output_epsilons(1)?, 1);
+        // The trs shared with the original ConstFst are left untouched.
+        assert_eq!(
+            const_fst.get_trs(0)?.trs()[0],
+            Tr::new(1, 1, TropicalWeight::one(), 1)
+        );
+        assert!(fst.tr_weights_iter_mut(3).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_mmap() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(4);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 2, 1.0, 1))?;
+        fst.add_tr(0, Tr::new(0, 3, 2.0, 2))?;
+        fst.add_tr(1, Tr::new(4, 0, 3.0, 3))?;
+        fst.add_tr(2, Tr::new(5, 5, 4.0, 3))?;
+        fst.add_tr(3, Tr::new(6, 6, 5.0, 0))?;
+        fst.set_final(3, 0.5)?;
+        let fst: ConstFst<_> = fst.into();
+
+        let dir = tempdir()?;
+        let path = dir.path().join("fst.bin");
+        fst.write(&path)?;
+        let mmapped_fst = ConstFst::<TropicalWeight>::read_mmap(&path)?;
+
+        assert!(mmapped_fst.is_mapped());
+        assert_eq!(mmapped_fst.start(), fst.start());
+        assert_eq!(mmapped_fst.num_states(), fst.num_states());
+        assert_eq!(mmapped_fst.properties(), fst.properties());
+        for (data_mmap, data) in mmapped_fst.fst_iter().zip(fst.fst_iter()) {
+            assert_eq!(data_mmap.state_id, data.state_id);
+            assert_eq!(data_mmap.final_weight, data.final_weight);
+            assert_eq!(data_mmap.num_trs, data.num_trs);
+            assert_eq!(data_mmap.trs.trs(), data.trs.trs());
+        }
+        for state in fst.states_iter() {
+            assert_eq!(
+                mmapped_fst.num_input_epsilons(state)?,
+                fst.num_input_epsilons(state)?
+            );
+        }
+        assert!(mmapped_fst.get_trs(4).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_mmap_corrupted_file() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(2);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 2, 1.0, 1))?;
+        fst.set_final(1, 0.5)?;
+        let fst: ConstFst<_> = fst.into();
+
+        let dir = tempdir()?;
+        let path = dir.path().join("fst.bin");
+        fst.write(&path)?;
+        let bytes = std::fs::read(&path)?;
+
+        // Truncated trs.
+        std::fs::write(&path, &bytes[..bytes.len() - 1])?;
+        assert!(ConstFst::<TropicalWeight>::read_mmap(&path).is_err());
+
+        // The number of trs of the last state, stored right before the trs, is too large.
+        let mut corrupted = bytes.clone();
+        let states_end = bytes.len() - 16;
+        corrupted[states_end - 12..states_end - 8].copy_from_slice(&2i32.to_le_bytes());
+        std::fs::write(&path, &corrupted)?;
+        assert!(ConstFst::<TropicalWeight>::read_mmap(&path).is_err());
+
+        std::fs::write(&path, &bytes)?;
+        assert!(ConstFst::<TropicalWeight>::read_mmap(&path)?.is_mapped());
+        Ok(())
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_fst_iter() -> Result<()> {
+        use rayon::prelude::*;
+
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(100);
+        fst.set_start(0)?;
+        for state in 0..100 {
+            for nextstate in 0..(state % 7) {
+                fst.add_tr(state, Tr::new(1, 2, 1.0, nextstate))?;
+            }
+        }
+        fst.set_final(99, TropicalWeight::one())?;
+        let fst: ConstFst<_> = fst.into();
+
+        let num_trs_seq: usize = (0..fst.num_states() as StateId)
+            .map(|s| fst.num_trs(s).unwrap())
+            .sum();
+        let num_trs_par: usize = fst.par_fst_iter().map(|data| data.trs.len()).sum();
+        assert_eq!(num_trs_par, num_trs_seq);
+        assert_eq!(fst.par_states_iter().count(), fst.num_states());
+
+        let num_finals = fst
+            .par_fst_iter()
+            .filter(|data| data.final_weight.is_some())
+            .count();
+        assert_eq!(num_finals, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_num_trs_missing_state() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        let s0 = fst.add_state();
+        let s1 = fst.add_state();
+        fst.add_tr(s0, Tr::new(1, 1, 0.5, s1))?;
+        fst.add_tr(s0, Tr::new(2, 2, 0.5, s1))?;
+        let fst: ConstFst<_> = fst.into();
+
+        assert_eq!(fst.num_trs(s0)?, 2);
+        assert_eq!(fst.num_trs(s1)?, 0);
+        assert_eq!(unsafe { fst.num_trs_unchecked(s0) }, 2);
+        let err = fst.num_trs(2).unwrap_err();
+        assert_eq!(err.to_string(), "State 2 doesn't exist");
+        Ok(())
+    }
+
+    #[test]
+    fn test_fst_into_iter_large() -> Result<()> {
+        let num_states = 10_000;
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(num_states);
+        fst.set_start(0)?;
+        for s in 0..num_states {
+            // A varying number of trs per state, some states having none.
+            for i in 0..(s % 4) {
+                let nextstate = ((s + i + 1) % num_states) as StateId;
+                let label = (i + 1) as Label;
+                fst.add_tr(s as StateId, Tr::new(label, label, s as f32, nextstate))?;
+            }
+            if s % 7 == 0 {
+                fst.set_final(s as StateId, TropicalWeight::new((s % 3) as f32))?;
+            }
+        }
+        let const_fst: ConstFst<_> = fst.clone().into();
+
+        let mut num_states_seen = 0;
+        for (fst_iter_data, state) in const_fst.fst_into_iter().zip(fst.states_iter()) {
+            assert_eq!(fst_iter_data.state_id, state);
+            assert_eq!(fst_iter_data.num_trs, fst.num_trs(state)?);
+            assert_eq!(fst_iter_data.final_weight, fst.final_weight(state)?);
+            assert_eq!(
+                fst_iter_data.trs.collect::<Vec<_>>(),
+                fst.get_trs(state)?.trs().to_vec()
+            );
+            num_states_seen += 1;
+        }
+        assert_eq!(num_states_seen, num_states);
+        Ok(())
+    }
+
+    #[test]
+    fn test_vector_fst_round_trip() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(4);
+        fst.set_start(1)?;
+        fst.add_tr(1, Tr::new(1, 0, 1.0, 0))?;
+        fst.add_tr(1, Tr::new(2, 2, 2.0, 2))?;
+        fst.add_tr(0, Tr::new(0, 3, 3.0, 3))?;
+        fst.add_tr(2, Tr::new(4, 4, 4.0, 1))?;
+        fst.set_final(3, 0.5)?;
+        fst.set_final(2, TropicalWeight::one())?;
+
+        let mut symt = SymbolTable::new();
+        symt.add_symbols(vec!["a", "b", "c", "d"]);
+        let symt = Arc::new(symt);
+        fst.set_input_symbols(Arc::clone(&symt));
+
+        let const_fst: ConstFst<_> = fst.clone().into();
+        let vector_fst = const_fst.to_vector_fst();
+        assert_eq!(vector_fst, fst);
+        assert_eq!(vector_fst.properties(), const_fst.properties());
+        assert_eq!(vector_fst.input_symbols(), Some(&symt));
+        assert_eq!(vector_fst.output_symbols(), None);
+
+        let mut vector_fst: VectorFst<_> = const_fst.into();
+        assert_eq!(vector_fst, fst);
+        for state in vector_fst.states_iter() {
+            assert_eq!(
+                vector_fst.num_input_epsilons(state)?,
+                fst.num_input_epsilons(state)?
+            );
+            assert_eq!(
+                vector_fst.num_output_epsilons(state)?,
+                fst.num_output_epsilons(state)?
+            );
+        }
+
+        // The converted FST can be modified.
+        vector_fst.add_tr(3, Tr::new(5, 5, 5.0, 0))?;
+        assert_eq!(vector_fst.num_trs(3)?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_vector_fst_from_const_fst_trs_not_in_state_order() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(3);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(0, Tr::new(2, 2, 2.0, 2))?;
+        fst.add_tr(2, Tr::new(3, 3, 3.0, 1))?;
+        fst.set_final(1, 0.5)?;
+
+        // Stores the trs of the last state first, as a ConstFst read from a file may do.
+        let mut const_fst: ConstFst<_> = fst.clone().into();
+        let mut trs = vec![];
+        for state in const_fst.states.iter_mut().rev() {
+            let pos = trs.len();
+            trs.extend_from_slice(&const_fst.trs[state.pos..state.pos + state.ntrs]);
+            state.pos = pos;
+        }
+        const_fst.trs = Arc::new(trs);
+        assert_eq!(const_fst.get_trs(0)?.trs(), fst.get_trs(0)?.trs());
+
+        let vector_fst: VectorFst<_> = const_fst.into();
+        assert_eq!(vector_fst, fst);
+        Ok(())
+    }
+}
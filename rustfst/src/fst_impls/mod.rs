@@ -1,6 +1,12 @@
 mod arc;
+mod binary_fst;
+pub(crate) mod compact_fst;
 pub(crate) mod const_fst;
 pub(crate) mod vector_fst;
 
-pub use self::const_fst::ConstFst;
+pub use self::binary_fst::{read_fst, read_fst_header, BinaryFst, BinaryFstHeader};
+pub use self::compact_fst::{
+    AcceptorCompactor, CompactFst, Compactor, StringCompactor, UnweightedAcceptorCompactor,
+};
+pub use self::const_fst::{ConstFst, MmappedConstFst};
 pub use self::vector_fst::VectorFst;
@@ -1,5 +1,8 @@
 use std::sync::Arc;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use crate::fst_properties::mutable_properties::add_tr_properties;
 use crate::fst_properties::properties::{EXPANDED, MUTABLE};
 use crate::fst_properties::FstProperties;
@@ -13,6 +16,7 @@ use crate::{StateId, Tr, Trs, TrsVec, EPS_LABEL};
 /// All states are stored in a vector of states.
 /// In each state, there is a vector of trs containing the outgoing transitions.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct VectorFst<W: Semiring> {
     pub(crate) states: Vec<VectorFstState<W>>,
     pub(crate) start_state: Option<StateId>,
@@ -26,6 +30,7 @@ pub struct VectorFst<W: Semiring> {
 // when the object is modified. Which is not trivial with the MutableTrIterator API for instance.
 // Same goes for TrMap. For not-mutable fst however, it is usefull.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VectorFstState<W: Semiring> {
     pub(crate) final_weight: Option<W>,
     pub(crate) trs: TrsVec<W>,
@@ -33,6 +38,61 @@ pub struct VectorFstState<W: Semiring> {
     pub(crate) noepsilons: usize,
 }
 
+/// Fields of a serialized `VectorFst`, checked before building the FST.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct UncheckedVectorFst<W: Semiring> {
+    states: Vec<VectorFstState<W>>,
+    start_state: Option<StateId>,
+    isymt: Option<Arc<SymbolTable>>,
+    osymt: Option<Arc<SymbolTable>>,
+    properties: FstProperties,
+}
+
+/// The start state and the destination of every tr must be an existing state. The epsilon counts
+/// of the states are recomputed rather than trusted.
+#[cfg(feature = "serde")]
+impl<'de, W: Semiring + Deserialize<'de>> Deserialize<'de> for VectorFst<W> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let mut fst = UncheckedVectorFst::<W>::deserialize(deserializer)?;
+        let num_states = fst.states.len();
+        let check_state = |s: StateId| -> std::result::Result<(), D::Error> {
+            if (s as usize) < num_states {
+                Ok(())
+            } else {
+                Err(serde::de::Error::custom(crate::FstError::StateNotFound(s)))
+            }
+        };
+        if let Some(start) = fst.start_state {
+            check_state(start)?;
+        }
+        for state in fst.states.iter_mut() {
+            let mut niepsilons = 0;
+            let mut noepsilons = 0;
+            for tr in state.trs.trs() {
+                check_state(tr.nextstate)?;
+                if tr.ilabel == EPS_LABEL {
+                    niepsilons += 1;
+                }
+                if tr.olabel == EPS_LABEL {
+                    noepsilons += 1;
+                }
+            }
+            state.niepsilons = niepsilons;
+            state.noepsilons = noepsilons;
+        }
+        Ok(VectorFst {
+            states: fst.states,
+            start_state: fst.start_state,
+            isymt: fst.isymt,
+            osymt: fst.osymt,
+            properties: fst.properties,
+        })
+    }
+}
+
 impl<W: Semiring> PartialEq for VectorFst<W> {
     fn eq(&self, other: &Self) -> bool {
         // Indended: Doesn't check properties and symbol tables.
@@ -6,7 +6,7 @@ use crate::fst_impls::VectorFst;
 use crate::fst_properties::FstProperties;
 use crate::fst_traits::{CoreFst, Fst};
 use crate::semirings::Semiring;
-use crate::{StateId, SymbolTable, Trs, TrsVec};
+use crate::{FstError, StateId, SymbolTable, Trs, TrsVec};
 
 impl<W: Semiring> Fst<W> for VectorFst<W> {
     fn input_symbols(&self) -> Option<&Arc<SymbolTable>> {
@@ -45,7 +45,7 @@ impl<W: Semiring> CoreFst<W> for VectorFst<W> {
         let s = self
             .states
             .get(state_id as usize)
-            .ok_or_else(|| format_err!("State {:?} doesn't exist", state_id))?;
+            .ok_or(FstError::StateNotFound(state_id))?;
         Ok(s.final_weight.clone())
     }
 
@@ -61,7 +61,7 @@ impl<W: Semiring> CoreFst<W> for VectorFst<W> {
         Ok(self
             .states
             .get(s as usize)
-            .ok_or_else(|| format_err!("State {:?} doesn't exist", s))?
+            .ok_or(FstError::StateNotFound(s))?
             .trs
             .len())
     }
@@ -74,7 +74,7 @@ impl<W: Semiring> CoreFst<W> for VectorFst<W> {
         let state = self
             .states
             .get(state_id as usize)
-            .ok_or_else(|| format_err!("State {:?} doesn't exist", state_id))?;
+            .ok_or(FstError::StateNotFound(state_id))?;
         // Data is not copied, only Arc
         Ok(state.trs.shallow_clone())
     }
@@ -93,7 +93,7 @@ impl<W: Semiring> CoreFst<W> for VectorFst<W> {
         Ok(self
             .states
             .get(state as usize)
-            .ok_or_else(|| format_err!("State {:?} doesn't exist", state))?
+            .ok_or(FstError::StateNotFound(state))?
             .niepsilons)
     }
 
@@ -101,7 +101,7 @@ impl<W: Semiring> CoreFst<W> for VectorFst<W> {
         Ok(self
             .states
             .get(state as usize)
-            .ok_or_else(|| format_err!("State {:?} doesn't exist", state))?
+            .ok_or(FstError::StateNotFound(state))?
             .noepsilons)
     }
 }
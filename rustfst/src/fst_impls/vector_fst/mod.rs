@@ -1,5 +1,6 @@
 pub use data_structure::VectorFst;
 pub(crate) use data_structure::VectorFstState;
+pub(crate) use serializable_fst::parse_vector_fst;
 
 mod allocable_fst;
 mod data_structure;
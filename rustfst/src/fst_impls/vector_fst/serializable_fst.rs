@@ -156,7 +156,7 @@ fn parse_vector_fst_state<W: SerializableSemiring>(
     ))
 }
 
-fn parse_vector_fst<W: SerializableSemiring>(
+pub(crate) fn parse_vector_fst<W: SerializableSemiring>(
     i: &[u8],
 ) -> IResult<&[u8], VectorFst<W>, NomCustomError<&[u8]>> {
     let (i, header) = FstHeader::parse(
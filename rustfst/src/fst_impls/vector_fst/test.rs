@@ -5,12 +5,15 @@ mod tests {
     use anyhow::Result;
 
     use crate::fst_impls::VectorFst;
+    use crate::fst_properties::FstProperties;
     use crate::fst_traits::{
-        CoreFst, ExpandedFst, Fst, MutableFst, SerializableFst, StateIterator,
+        AllocableFst, CoreFst, ExpandedFst, Fst, MutableFst, SerializableFst, StateIterator,
     };
+    #[cfg(feature = "serde")]
+    use crate::semirings::{GallicWeight, LogWeight, ProductWeight};
     use crate::semirings::{ProbabilityWeight, Semiring, TropicalWeight};
     use crate::tr::Tr;
-    use crate::{SymbolTable, Trs};
+    use crate::{DrawingConfig, FstError, StateId, SymbolTable, Trs};
     use std::sync::Arc;
 
     #[test]
@@ -249,6 +252,54 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_shrink_to_fit_after_deletion() -> Result<()> {
+        let n_states: StateId = 1000;
+        let n_states_to_keep: StateId = 100;
+
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(n_states as usize);
+        fst.set_start(0)?;
+        for s in 0..(n_states - 1) {
+            fst.add_tr(s, Tr::new(1, 2, 0.5, s + 1))?;
+            fst.add_tr(s, Tr::new(2, 1, 1.5, s))?;
+        }
+        fst.set_final(n_states_to_keep - 1, 0.3)?;
+
+        fst.del_states(n_states_to_keep..n_states)?;
+        let fst_ref = fst.clone();
+
+        fst.shrink_to_fit();
+
+        assert!(fst.states_capacity() >= n_states_to_keep as usize);
+        assert!(fst.states_capacity() < n_states as usize);
+        for s in fst.states_range() {
+            assert!(fst.trs_capacity(s)? >= fst.num_trs(s)?);
+        }
+        assert_eq!(fst, fst_ref);
+        assert_eq!(fst.properties(), fst_ref.properties());
+        Ok(())
+    }
+
+    #[test]
+    fn test_reserve_trs() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        let s0 = fst.add_state();
+        let s1 = fst.add_state();
+
+        fst.reserve_trs(s0, 10)?;
+        let capacity = fst.trs_capacity(s0)?;
+        assert!(capacity >= 10);
+        for _ in 0..10 {
+            fst.add_tr(s0, Tr::new(1, 1, 0.5, s1))?;
+        }
+        assert_eq!(fst.trs_capacity(s0)?, capacity);
+        assert_eq!(fst.num_trs(s0)?, 10);
+
+        assert!(fst.reserve_trs(42, 10).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_parse_single_final_state() -> Result<()> {
         let parsed_fst = VectorFst::<TropicalWeight>::from_text_string("0\tInfinity\n")?;
@@ -263,6 +314,71 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_text_round_trip() -> Result<()> {
+        let mut isymt = SymbolTable::new();
+        isymt.add_symbol("a");
+        isymt.add_symbol("b");
+        let mut osymt = SymbolTable::new();
+        osymt.add_symbol("x");
+
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(3);
+        fst.set_start(1)?;
+        fst.add_tr(1, Tr::new(1, 1, 0.5, 0))?;
+        fst.add_tr(0, Tr::new(2, 0, TropicalWeight::one(), 2))?;
+        fst.add_tr(0, Tr::new(0, 1, 1.5, 1))?;
+        fst.set_final(2, 0.25)?;
+
+        let text = fst.text_with_symbols(Some(&isymt), Some(&osymt))?;
+        assert_eq!(
+            text,
+            "1\t0\ta\tx\t0.5\n0\t2\tb\t<eps>\t0\n0\t1\t<eps>\tx\t1.5\n2\t0.25\n"
+        );
+
+        let isymt = Arc::new(isymt);
+        let osymt = Arc::new(osymt);
+        let parsed_fst = VectorFst::<TropicalWeight>::from_text_string_with_symbols(
+            &text,
+            Some(Arc::clone(&isymt)),
+            Some(Arc::clone(&osymt)),
+        )?;
+        assert_eq!(parsed_fst, fst);
+        assert_eq!(parsed_fst.input_symbols(), Some(&isymt));
+        assert_eq!(parsed_fst.output_symbols(), Some(&osymt));
+
+        // Without symbol tables, the labels are printed.
+        let text = fst.text_with_symbols(None, None)?;
+        assert_eq!(text, fst.text()?);
+        let parsed_fst =
+            VectorFst::<TropicalWeight>::from_text_string_with_symbols(&text, None, None)?;
+        assert_eq!(parsed_fst, fst);
+
+        // Labels missing from the symbol tables can't be printed.
+        assert!(fst
+            .text_with_symbols(Some(&SymbolTable::new()), None)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_final_weights_iter() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(5);
+        fst.set_start(0)?;
+        fst.set_final(1, 0.5)?;
+        fst.set_final(3, TropicalWeight::one())?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(0, Tr::new(2, 2, 1.0, 3))?;
+
+        let final_weights: Vec<_> = fst.final_weights_iter().collect();
+        assert_eq!(
+            final_weights,
+            vec![(1, TropicalWeight::new(0.5)), (3, TropicalWeight::one())]
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_del_all_states() -> Result<()> {
         let mut fst = VectorFst::<ProbabilityWeight>::new();
@@ -328,4 +444,213 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_draw_to_string() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        let s0 = fst.add_state();
+        let s1 = fst.add_state();
+        fst.set_start(s0)?;
+        fst.set_final(s1, TropicalWeight::one())?;
+        fst.add_tr(s0, Tr::new(1, 1, 0.5, s1))?;
+
+        let mut symt = SymbolTable::new();
+        symt.add_symbol("a");
+        fst.set_input_symbols(Arc::new(symt));
+
+        let config = DrawingConfig {
+            acceptor: true,
+            ..DrawingConfig::default()
+        };
+        let dot = fst.draw_to_string(&config)?;
+
+        assert!(dot.starts_with("digraph FST {"));
+        assert!(dot.contains("rankdir = LR;"));
+        assert!(dot.contains("0 [label = \"0\", shape = circle, style = bold, fontsize = 14]"));
+        assert!(
+            dot.contains("1 [label = \"1/0\", shape = doublecircle, style = solid, fontsize = 14]")
+        );
+        assert!(dot.contains("\t0 -> 1 [label = \"a/0.5\", fontsize = 14];"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_tr_checked() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        let s0 = fst.add_state();
+        let s1 = fst.add_state();
+
+        fst.add_tr_checked(s0, Tr::new(1, 1, 0.5, s1))?;
+        assert_eq!(fst.num_trs(s0)?, 1);
+
+        // Dangling destination.
+        assert!(fst.add_tr_checked(s0, Tr::new(1, 1, 0.5, 2)).is_err());
+        // Missing source.
+        assert!(fst.add_tr_checked(2, Tr::new(1, 1, 0.5, s1)).is_err());
+        assert_eq!(fst.num_trs(s0)?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_trs() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        let s0 = fst.add_state();
+        let s1 = fst.add_state();
+        fst.add_tr(s0, Tr::new(1, 1, 0.5, s1))?;
+        fst.add_tr(s0, Tr::new(0, 0, 0.5, s0))?;
+
+        assert!(fst.properties().contains(FstProperties::EPSILONS));
+
+        let new_trs = vec![Tr::new(2, 3, 1.5, s1)];
+        fst.set_trs(s0, new_trs.clone())?;
+        assert_eq!(fst.get_trs(s0)?.trs(), new_trs.as_slice());
+        assert_eq!(fst.num_input_epsilons(s0)?, 0);
+        assert_eq!(fst.num_output_epsilons(s0)?, 0);
+
+        // The properties due to the removed epsilon self-loop are gone.
+        let properties = fst.properties();
+        assert!(!properties.intersects(
+            FstProperties::EPSILONS
+                | FstProperties::I_EPSILONS
+                | FstProperties::O_EPSILONS
+                | FstProperties::CYCLIC
+        ));
+        assert!(properties.contains(FstProperties::NOT_ACCEPTOR));
+        let computed_properties = fst.clone().compute_and_update_properties_all()?;
+        assert!(computed_properties.contains(properties));
+
+        // Invalid source or destination : the trs are left untouched.
+        assert!(fst.set_trs(2, vec![]).is_err());
+        assert!(fst.set_trs(s0, vec![Tr::new(1, 1, 0.5, 5)]).is_err());
+        assert_eq!(fst.get_trs(s0)?.trs(), new_trs.as_slice());
+        Ok(())
+    }
+
+    #[test]
+    fn test_num_trs_missing_state() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        let s0 = fst.add_state();
+        let s1 = fst.add_state();
+        fst.add_tr(s0, Tr::new(1, 1, 0.5, s1))?;
+        fst.add_tr(s0, Tr::new(2, 2, 0.5, s1))?;
+
+        assert_eq!(fst.num_trs(s0)?, 2);
+        assert_eq!(fst.num_trs(s1)?, 0);
+        assert_eq!(unsafe { fst.num_trs_unchecked(s0) }, 2);
+        let err = fst.num_trs(2).unwrap_err();
+        assert_eq!(err.to_string(), "State 2 doesn't exist");
+        Ok(())
+    }
+
+    #[test]
+    fn test_state_not_found_error() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        let s0 = fst.add_state();
+
+        let errors = vec![
+            fst.set_start(3).unwrap_err(),
+            fst.set_final(3, 0.5).unwrap_err(),
+            fst.add_tr(3, Tr::new(1, 1, 0.5, s0)).unwrap_err(),
+            fst.get_trs(3).unwrap_err(),
+            fst.del_state(3).unwrap_err(),
+        ];
+        for err in errors {
+            match err.downcast_ref::<FstError>() {
+                Some(FstError::StateNotFound(s)) => assert_eq!(*s, 3),
+                _ => panic!("Unexpected error : {:?}", err),
+            }
+        }
+
+        // The error is kept when context is added, e.g the line of a text FST.
+        let mut symt = SymbolTable::new();
+        symt.add_symbol("a");
+        let err = VectorFst::<TropicalWeight>::from_text_string_with_symbols(
+            "0\t1\tb\tb\n1\n",
+            Some(Arc::new(symt)),
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<FstError>(),
+            Some(&FstError::SymbolNotFound("b".to_string()))
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_json_round_trip() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        let s0 = fst.add_state();
+        let s1 = fst.add_state();
+        fst.set_start(s0)?;
+        fst.set_final(s1, 0.3)?;
+        fst.add_tr(s0, Tr::new(1, 2, 0.5, s1))?;
+        fst.add_tr(s1, Tr::new(2, 1, 1.5, s0))?;
+
+        let mut symt = SymbolTable::new();
+        symt.add_symbol("a");
+        symt.add_symbol("b");
+        let symt = Arc::new(symt);
+        fst.set_input_symbols(Arc::clone(&symt));
+
+        let serialized = serde_json::to_string(&fst)?;
+        let deserialized: VectorFst<TropicalWeight> = serde_json::from_str(&serialized)?;
+
+        assert_eq!(deserialized, fst);
+        assert_eq!(deserialized.properties(), fst.properties());
+        assert_eq!(deserialized.input_symbols(), Some(&symt));
+        assert_eq!(deserialized.output_symbols(), None);
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_json_round_trip_composite_weights() -> Result<()> {
+        let mut fst = VectorFst::<GallicWeight<TropicalWeight>>::new();
+        let s0 = fst.add_state();
+        let s1 = fst.add_state();
+        fst.set_start(s0)?;
+        let w1 = GallicWeight::from((vec![1, 2], TropicalWeight::new(0.5)));
+        let w2 = GallicWeight::from((vec![3], TropicalWeight::new(1.5)));
+        fst.add_tr(s0, Tr::new(1, 0, w1.plus(&w2)?, s1))?;
+        fst.set_final(s1, GallicWeight::from((2, TropicalWeight::new(0.25))))?;
+
+        let serialized = serde_json::to_string(&fst)?;
+        let deserialized: VectorFst<GallicWeight<TropicalWeight>> =
+            serde_json::from_str(&serialized)?;
+        assert_eq!(deserialized, fst);
+        assert_eq!(deserialized.num_output_epsilons(s0)?, 1);
+
+        let mut fst = VectorFst::<ProductWeight<TropicalWeight, LogWeight>>::new();
+        let s0 = fst.add_state();
+        fst.set_start(s0)?;
+        fst.set_final(s0, (TropicalWeight::new(0.5), LogWeight::new(1.5)))?;
+
+        let serialized = serde_json::to_string(&fst)?;
+        let deserialized: VectorFst<ProductWeight<TropicalWeight, LogWeight>> =
+            serde_json::from_str(&serialized)?;
+        assert_eq!(deserialized, fst);
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_json_rejects_missing_states() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        let s0 = fst.add_state();
+        fst.set_start(s0)?;
+        fst.add_tr(s0, Tr::new(1, 2, 0.5, s0))?;
+        let serialized = serde_json::to_string(&fst)?;
+
+        let bad_nextstate = serialized.replace("\"nextstate\":0", "\"nextstate\":3");
+        assert_ne!(bad_nextstate, serialized);
+        let err = serde_json::from_str::<VectorFst<TropicalWeight>>(&bad_nextstate).unwrap_err();
+        assert!(err.to_string().contains("State 3 doesn't exist"));
+
+        let bad_start = serialized.replace("\"start_state\":0", "\"start_state\":1");
+        assert_ne!(bad_start, serialized);
+        assert!(serde_json::from_str::<VectorFst<TropicalWeight>>(&bad_start).is_err());
+        Ok(())
+    }
 }
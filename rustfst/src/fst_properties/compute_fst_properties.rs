@@ -207,3 +207,50 @@ pub fn compute_fst_properties<W: Semiring, F: ExpandedFst<W>>(
     *known = known_properties(comp_props);
     Ok(comp_props)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::MutableFst;
+    use crate::semirings::TropicalWeight;
+
+    fn compute_all(fst: &VectorFst<TropicalWeight>) -> Result<FstProperties> {
+        let mut known = FstProperties::empty();
+        let props =
+            compute_fst_properties(fst, FstProperties::all_properties(), &mut known, false)?;
+        assert_eq!(known, known_properties(props));
+        Ok(props)
+    }
+
+    #[test]
+    fn test_compute_fst_properties_acyclic_acceptor() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(3);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 0.5, 1))?;
+        fst.add_tr(1, Tr::new(2, 2, 1.5, 2))?;
+        fst.set_final(2, TropicalWeight::one())?;
+
+        let props = compute_all(&fst)?;
+        assert!(props.contains(FstProperties::ACCEPTOR | FstProperties::ACYCLIC));
+        assert!(!props.intersects(FstProperties::NOT_ACCEPTOR | FstProperties::CYCLIC));
+        Ok(())
+    }
+
+    #[test]
+    fn test_compute_fst_properties_cyclic_transducer() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(2);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 2, 0.5, 1))?;
+        fst.add_tr(1, Tr::new(2, 2, 1.5, 0))?;
+        fst.set_final(1, TropicalWeight::one())?;
+
+        let props = compute_all(&fst)?;
+        assert!(props.contains(FstProperties::NOT_ACCEPTOR | FstProperties::CYCLIC));
+        assert!(!props.intersects(FstProperties::ACCEPTOR | FstProperties::ACYCLIC));
+        Ok(())
+    }
+}
@@ -553,3 +553,19 @@ impl Shr<usize> for FstProperties {
         Self::from_bits_truncate(self.bits() >> rhs)
     }
 }
+
+/// The properties are serialized as their bits.
+#[cfg(feature = "serde")]
+impl serde::Serialize for FstProperties {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.bits())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FstProperties {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bits = <u64 as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(Self::from_bits_truncate(bits))
+    }
+}
@@ -66,6 +66,11 @@ pub trait CoreFst<W: Semiring> {
 
     /// Number of trs leaving a specific state in the wFST.
     ///
+    /// # Errors
+    ///
+    /// An error is raised if the state `s` doesn't exist. Use `num_trs_unchecked` to skip the
+    /// check.
+    ///
     /// # Example
     ///
     /// ```
@@ -80,6 +85,7 @@ pub trait CoreFst<W: Semiring> {
     /// assert_eq!(fst.num_trs(s1).unwrap(), 0);
     /// fst.add_tr(s1, Tr::new(3, 5, BooleanWeight::new(true), s2));
     /// assert_eq!(fst.num_trs(s1).unwrap(), 1);
+    /// assert!(fst.num_trs(5).is_err());
     /// ```
     fn num_trs(&self, s: StateId) -> Result<usize>;
 
@@ -268,6 +274,37 @@ pub trait Fst<W: Semiring>:
             w: PhantomData,
         }
     }
+
+    /// Returns an iterator over the final states of the wFST along with their final weight. The
+    /// non-final states are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use rustfst::fst_impls::VectorFst;
+    /// # use rustfst::fst_traits::{Fst, MutableFst};
+    /// # use rustfst::semirings::{Semiring, TropicalWeight};
+    /// # fn main() -> Result<()> {
+    /// let mut fst = VectorFst::<TropicalWeight>::new();
+    /// fst.add_states(3);
+    /// fst.set_final(1, 0.5)?;
+    ///
+    /// let final_weights: Vec<_> = fst.final_weights_iter().collect();
+    /// assert_eq!(final_weights, vec![(1, TropicalWeight::new(0.5))]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn final_weights_iter(&self) -> FinalWeightsIterator<W, Self>
+    where
+        Self: std::marker::Sized,
+    {
+        FinalWeightsIterator {
+            fst: self,
+            state_iter: self.states_iter(),
+            w: PhantomData,
+        }
+    }
 }
 
 pub struct FinalStatesIterator<'a, W, F>
@@ -299,3 +336,30 @@ where
         }
     }
 }
+
+pub struct FinalWeightsIterator<'a, W, F>
+where
+    W: Semiring,
+    F: Fst<W>,
+{
+    fst: &'a F,
+    state_iter: <F as StateIterator<'a>>::Iter,
+    w: PhantomData<W>,
+}
+
+impl<'a, W, F> Iterator for FinalWeightsIterator<'a, W, F>
+where
+    W: Semiring,
+    F: Fst<W>,
+{
+    type Item = (StateId, W);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for s in &mut self.state_iter {
+            if let Some(final_weight) = unsafe { self.fst.final_weight_unchecked(s) } {
+                return Some((s, final_weight));
+            }
+        }
+        None
+    }
+}
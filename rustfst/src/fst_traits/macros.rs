@@ -1,33 +1,76 @@
 macro_rules! display_single_state {
     ($fst:expr, $state_id:expr, $f: expr, $show_weight_one: expr) => {
+        display_single_state!($fst, $state_id, $f, $show_weight_one, |l| l, |l| l)
+    };
+    (
+        $fst:expr,
+        $state_id:expr,
+        $f: expr,
+        $show_weight_one: expr,
+        |$il:ident| $ilabel: expr,
+        |$ol:ident| $olabel: expr
+    ) => {
         for tr in $fst.get_trs($state_id).unwrap().trs() {
+            let ilabel = {
+                let $il = tr.ilabel;
+                $ilabel
+            };
+            let olabel = {
+                let $ol = tr.olabel;
+                $olabel
+            };
             if tr.weight.is_one() && !$show_weight_one {
                 writeln!(
                     $f,
                     "{}\t{}\t{}\t{}",
-                    $state_id, &tr.nextstate, &tr.ilabel, &tr.olabel
+                    $state_id, &tr.nextstate, &ilabel, &olabel
                 )?;
             } else {
                 writeln!(
                     $f,
                     "{}\t{}\t{}\t{}\t{}",
-                    $state_id, &tr.nextstate, &tr.ilabel, &tr.olabel, &tr.weight
+                    $state_id, &tr.nextstate, &ilabel, &olabel, &tr.weight
                 )?;
             }
         }
     };
 }
 
+/// Writes the text representation of an FST. The labels are printed as is, unless the two
+/// closure-like arguments are given to turn the input and output labels into their text.
 macro_rules! write_fst {
     ($fst:expr, $f:expr, $show_weight_one: expr) => {
+        write_fst!($fst, $f, $show_weight_one, |l| l, |l| l)
+    };
+    (
+        $fst:expr,
+        $f:expr,
+        $show_weight_one: expr,
+        |$il:ident| $ilabel: expr,
+        |$ol:ident| $olabel: expr
+    ) => {
         if let Some(start_state) = $fst.start() {
             // Firstly print the trs leaving the start state
-            display_single_state!($fst, start_state, $f, $show_weight_one);
+            display_single_state!(
+                $fst,
+                start_state,
+                $f,
+                $show_weight_one,
+                |$il| $ilabel,
+                |$ol| $olabel
+            );
 
             // Secondly, print the trs leaving all the other states
             for state_id in $fst.states_iter() {
                 if state_id != start_state {
-                    display_single_state!($fst, state_id, $f, $show_weight_one);
+                    display_single_state!(
+                        $fst,
+                        state_id,
+                        $f,
+                        $show_weight_one,
+                        |$il| $ilabel,
+                        |$ol| $olabel
+                    );
                 }
             }
 
@@ -9,7 +9,7 @@ use crate::fst_traits::ExpandedFst;
 use crate::semirings::Semiring;
 use crate::tr::Tr;
 use crate::trs_iter_mut::TrsIterMut;
-use crate::{Label, StateId};
+use crate::{FstError, Label, StateId};
 
 /// Trait defining the methods to modify a wFST.
 pub trait MutableFst<W: Semiring>: ExpandedFst<W> {
@@ -239,6 +239,48 @@ pub trait MutableFst<W: Semiring>: ExpandedFst<W> {
     ///
     unsafe fn add_tr_unchecked(&mut self, state: StateId, tr: Tr<W>);
 
+    /// Adds a transition to the FST, checking that both the state `source` and the destination
+    /// of the transition exist. Unlike `add_tr`, a dangling transition is rejected immediately.
+    ///
+    /// # Errors
+    ///
+    /// An error is raised if the state `source` or the state `tr.nextstate` doesn't exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustfst::fst_traits::{CoreFst, MutableFst, ExpandedFst};
+    /// # use rustfst::fst_impls::VectorFst;
+    /// # use rustfst::semirings::{ProbabilityWeight, Semiring};
+    /// # use rustfst::Tr;
+    /// # use anyhow::Result;
+    /// # fn main() -> Result<()> {
+    /// let mut fst = VectorFst::<ProbabilityWeight>::new();
+    /// let s1 = fst.add_state();
+    /// let s2 = fst.add_state();
+    ///
+    /// fst.add_tr_checked(s1, Tr::new(3, 5, 1.2, s2))?;
+    /// assert_eq!(fst.num_trs(s1)?, 1);
+    /// assert!(fst.add_tr_checked(s1, Tr::new(3, 5, 1.2, 10)).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn add_tr_checked(&mut self, source: StateId, tr: Tr<W>) -> Result<()> {
+        let num_states = self.num_states();
+        if (source as usize) >= num_states {
+            bail!(FstError::StateNotFound(source));
+        }
+        if (tr.nextstate as usize) >= num_states {
+            bail!(
+                "Nextstate {:?} of the transition doesn't exist (number of states: {})",
+                tr.nextstate,
+                num_states
+            );
+        }
+        unsafe { self.add_tr_unchecked(source, tr) };
+        Ok(())
+    }
+
     /// Adds a transition to the FST. The transition will start in the state `source`.
     ///
     /// # Errors
@@ -303,6 +345,52 @@ pub trait MutableFst<W: Semiring>: ExpandedFst<W> {
     ///
     unsafe fn set_trs_unchecked(&mut self, source: StateId, trs: Vec<Tr<W>>);
 
+    /// Set all the `Tr`s leaving the state `source` to the parameters `trs` erasing
+    /// the `Tr`s previously stored.
+    ///
+    /// # Errors
+    ///
+    /// An error is raised if the state `source` or the destination of one of the `trs`
+    /// doesn't exist. The FST is left unchanged in that case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustfst::fst_traits::{CoreFst, MutableFst, ExpandedFst};
+    /// # use rustfst::fst_impls::VectorFst;
+    /// # use rustfst::semirings::{ProbabilityWeight, Semiring};
+    /// # use rustfst::{Tr, Trs};
+    /// # use anyhow::Result;
+    /// # fn main() -> Result<()> {
+    /// let mut fst = VectorFst::<ProbabilityWeight>::new();
+    /// let s1 = fst.add_state();
+    /// let s2 = fst.add_state();
+    /// fst.add_tr(s1, Tr::new(3, 5, 1.2, s2))?;
+    ///
+    /// fst.set_trs(s1, vec![Tr::new(1, 1, 0.5, s1), Tr::new(2, 2, 0.3, s2)])?;
+    /// assert_eq!(fst.get_trs(s1)?.trs(), &[Tr::new(1, 1, 0.5, s1), Tr::new(2, 2, 0.3, s2)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn set_trs(&mut self, source: StateId, trs: Vec<Tr<W>>) -> Result<()> {
+        let num_states = self.num_states();
+        if (source as usize) >= num_states {
+            bail!(FstError::StateNotFound(source));
+        }
+        if let Some(tr) = trs.iter().find(|tr| (tr.nextstate as usize) >= num_states) {
+            bail!(
+                "Nextstate {:?} of the transition doesn't exist (number of states: {})",
+                tr.nextstate,
+                num_states
+            );
+        }
+        // The properties only holding because of the removed trs are dropped before adding the
+        // new ones.
+        self.set_properties(self.properties() & FstProperties::delete_arcs_properties());
+        unsafe { self.set_trs_unchecked(source, trs) };
+        Ok(())
+    }
+
     /// Remove the final weight of a specific state.
     fn delete_final_weight(&mut self, source: StateId) -> Result<()>;
 
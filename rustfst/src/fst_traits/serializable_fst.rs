@@ -1,15 +1,17 @@
-use std::fs::File;
+use std::fs::{read_to_string, File};
 use std::io::{BufWriter, LineWriter, Write};
 use std::path::Path;
+use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use unsafe_unwrap::UnsafeUnwrap;
 
+use crate::draw::{draw, write_dot};
 use crate::fst_traits::ExpandedFst;
 use crate::parsers::text_fst::ParsedTextFst;
 use crate::semirings::SerializableSemiring;
 use crate::Trs;
-use crate::{DrawingConfig, StateId};
+use crate::{DrawingConfig, FstError, Label, SymbolTable};
 
 /// Trait definining the methods an Fst must implement to be serialized and deserialized.
 pub trait SerializableFst<W: SerializableSemiring>: ExpandedFst<W> {
@@ -41,6 +43,64 @@ pub trait SerializableFst<W: SerializableSemiring>: ExpandedFst<W> {
         Self::from_parsed_fst_text(parsed_text_fst)
     }
 
+    /// Deserializes a wFST in text from a string, resolving the labels through the symbol tables
+    /// when they are provided. The symbol tables are then attached to the wFST.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use anyhow::Result;
+    /// # use rustfst::fst_impls::VectorFst;
+    /// # use rustfst::fst_traits::{CoreFst, SerializableFst};
+    /// # use rustfst::semirings::TropicalWeight;
+    /// # use rustfst::{SymbolTable, Tr, Trs};
+    /// # fn main() -> Result<()> {
+    /// let mut symt = SymbolTable::new();
+    /// symt.add_symbol("a");
+    /// let symt = Arc::new(symt);
+    ///
+    /// let fst = VectorFst::<TropicalWeight>::from_text_string_with_symbols(
+    ///     "0\t1\ta\ta\t0.5\n1\n",
+    ///     Some(Arc::clone(&symt)),
+    ///     Some(symt),
+    /// )?;
+    ///
+    /// assert_eq!(fst.get_trs(0)?.trs(), &[Tr::new(1, 1, 0.5, 1)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn from_text_string_with_symbols(
+        fst_string: &str,
+        isymt: Option<Arc<SymbolTable>>,
+        osymt: Option<Arc<SymbolTable>>,
+    ) -> Result<Self> {
+        let parsed_text_fst = ParsedTextFst::from_string_with_symbols(
+            fst_string,
+            isymt.as_deref(),
+            osymt.as_deref(),
+        )?;
+        let mut fst = Self::from_parsed_fst_text(parsed_text_fst)?;
+        if let Some(isymt) = isymt {
+            fst.set_input_symbols(isymt);
+        }
+        if let Some(osymt) = osymt {
+            fst.set_output_symbols(osymt);
+        }
+        Ok(fst)
+    }
+
+    /// Deserializes a wFST in text from a path, resolving the labels through the symbol tables
+    /// when they are provided. The symbol tables are then attached to the wFST.
+    fn read_text_with_symbols<P: AsRef<Path>>(
+        path_text_fst: P,
+        isymt: Option<Arc<SymbolTable>>,
+        osymt: Option<Arc<SymbolTable>>,
+    ) -> Result<Self> {
+        let fst_string = read_to_string(path_text_fst)?;
+        Self::from_text_string_with_symbols(&fst_string, isymt, osymt)
+    }
+
     /// Serializes the FST as a text file in a format compatible with OpenFST.
     fn write_text<P: AsRef<Path>>(&self, path_output: P) -> Result<()> {
         let buffer = File::create(path_output.as_ref())?;
@@ -57,115 +117,100 @@ pub trait SerializableFst<W: SerializableSemiring>: ExpandedFst<W> {
         Ok(String::from_utf8(line_writer.into_inner()?)?)
     }
 
+    /// Serializes the FST as a text file in a format compatible with OpenFST, printing the
+    /// symbols of the symbol tables instead of the labels when they are provided.
+    fn write_text_with_symbols<P: AsRef<Path>>(
+        &self,
+        path_output: P,
+        isymt: Option<&SymbolTable>,
+        osymt: Option<&SymbolTable>,
+    ) -> Result<()> {
+        let buffer = File::create(path_output.as_ref())?;
+        let mut line_writer = LineWriter::new(buffer);
+        write_text_fst_with_symbols(self, &mut line_writer, isymt, osymt)
+    }
+
+    /// Writes the text representation of the FST into a String, printing the symbols of the
+    /// symbol tables instead of the labels when they are provided.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use rustfst::fst_impls::VectorFst;
+    /// # use rustfst::fst_traits::{MutableFst, SerializableFst};
+    /// # use rustfst::semirings::{Semiring, TropicalWeight};
+    /// # use rustfst::{SymbolTable, Tr};
+    /// # fn main() -> Result<()> {
+    /// let mut symt = SymbolTable::new();
+    /// symt.add_symbol("a");
+    ///
+    /// let mut fst = VectorFst::<TropicalWeight>::new();
+    /// fst.add_states(2);
+    /// fst.set_start(0)?;
+    /// fst.add_tr(0, Tr::new(1, 0, 0.5, 1))?;
+    /// fst.set_final(1, TropicalWeight::one())?;
+    ///
+    /// assert_eq!(
+    ///     fst.text_with_symbols(Some(&symt), None)?,
+    ///     "0\t1\ta\t0\t0.5\n1\t0\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn text_with_symbols(
+        &self,
+        isymt: Option<&SymbolTable>,
+        osymt: Option<&SymbolTable>,
+    ) -> Result<String> {
+        let mut buffer = Vec::<u8>::new();
+        write_text_fst_with_symbols(self, &mut buffer, isymt, osymt)?;
+        Ok(String::from_utf8(buffer)?)
+    }
+
     /// Serializes the FST as a DOT file compatible with GraphViz binaries.
     fn draw<P: AsRef<Path>>(&self, path_output: P, config: &DrawingConfig) -> Result<()> {
         let buffer = File::create(path_output.as_ref())?;
         let mut f = BufWriter::new(LineWriter::new(buffer));
+        write_dot(
+            self,
+            &mut f,
+            self.input_symbols().map(|s| s.as_ref()),
+            self.output_symbols().map(|s| s.as_ref()),
+            config,
+        )
+    }
 
-        if let Some(start_state) = self.start() {
-            writeln!(f, "digraph FST {{")?;
-
-            if config.vertical {
-                writeln!(f, "rankdir = BT;")?;
-            } else {
-                writeln!(f, "rankdir = LR;")?;
-            }
-
-            if let Some((width, height)) = config.size {
-                writeln!(f, "size = \"{},{}\";", width, height)?;
-            }
-
-            writeln!(f, "label = \"{}\";", config.title)?;
-            writeln!(f, "center = 1;")?;
-
-            if config.portrait {
-                writeln!(f, "orientation = Portrait;")?;
-            } else {
-                writeln!(f, "orientation = Landscape;")?;
-            }
-
-            if let Some(ranksep) = config.ranksep {
-                writeln!(f, "ranksep = {}", ranksep)?;
-            }
-
-            if let Some(nodesep) = config.nodesep {
-                writeln!(f, "nodesep = {}", nodesep)?;
-            }
-
-            // Start state first
-            draw_single_fst_state(self, &mut f, start_state, config)?;
-
-            for state in self.states_iter() {
-                if state != start_state {
-                    draw_single_fst_state(self, &mut f, state, config)?;
-                }
-            }
+    /// Writes the DOT representation of the FST into a String.
+    fn draw_to_string(&self, config: &DrawingConfig) -> Result<String> {
+        draw(
+            self,
+            self.input_symbols().map(|s| s.as_ref()),
+            self.output_symbols().map(|s| s.as_ref()),
+            config,
+        )
+    }
+}
 
-            writeln!(f, "}}")?;
-        }
-        Ok(())
+fn label_to_text(label: Label, symt: Option<&SymbolTable>, side: &str) -> Result<String> {
+    match symt {
+        Some(symt) => symt
+            .get_symbol(label)
+            .map(|v| v.to_string())
+            .ok_or(FstError::LabelNotFound(label))
+            .with_context(|| format!("Missing {} in {} SymbolTable", label, side)),
+        None => Ok(format!("{}", label)),
     }
 }
 
-fn draw_single_fst_state<S: SerializableSemiring, F: SerializableFst<S>, W: Write>(
+fn write_text_fst_with_symbols<S: SerializableSemiring, F: SerializableFst<S>, W: Write>(
     fst: &F,
-    writer: &mut W,
-    state_id: StateId,
-    config: &DrawingConfig,
+    f: &mut W,
+    isymt: Option<&SymbolTable>,
+    osymt: Option<&SymbolTable>,
 ) -> Result<()> {
-    let opt_isymt = fst.input_symbols();
-    let opt_osymt = fst.output_symbols();
-
-    write!(writer, "{}", state_id)?;
-    write!(writer, " [label = \"{}", state_id)?;
-    if let Some(final_weight) = fst.final_weight(state_id)? {
-        if config.print_weight && (config.show_weight_one || !final_weight.is_one()) {
-            write!(writer, "/{}", final_weight)?;
-        }
-        write!(writer, "\", shape = doublecircle,")?;
-    } else {
-        write!(writer, "\", shape = circle,")?;
-    }
-
-    if fst.is_start(state_id) {
-        write!(writer, " style = bold,")?;
-    } else {
-        write!(writer, " style = solid,")?;
-    }
-
-    writeln!(writer, " fontsize = {}]", config.fontsize)?;
-
-    for tr in fst.get_trs(state_id).unwrap().trs() {
-        write!(writer, "\t{} -> {}", state_id, tr.nextstate)?;
-
-        let ilabel = opt_isymt.clone().map_or_else(
-            || Ok(format!("{}", tr.ilabel)),
-            |symt| {
-                symt.get_symbol(tr.ilabel)
-                    .map(|v| v.to_string())
-                    .ok_or_else(|| format_err!("Missing {} in input SymbolTable", tr.ilabel))
-            },
-        )?;
-
-        let olabel = opt_osymt.clone().map_or_else(
-            || Ok(format!("{}", tr.olabel)),
-            |symt| {
-                symt.get_symbol(tr.olabel)
-                    .map(|v| v.to_string())
-                    .ok_or_else(|| format_err!("Missing {} in output SymbolTable", tr.olabel))
-            },
-        )?;
-
-        write!(writer, " [label = \"{}", ilabel)?;
-        if !config.acceptor {
-            write!(writer, ":{}", olabel)?;
-        }
-
-        if config.print_weight && (config.show_weight_one || !tr.weight.is_one()) {
-            write!(writer, "/{}", tr.weight)?;
-        }
-        writeln!(writer, "\", fontsize = {}];", config.fontsize)?;
-    }
-
+    write_fst!(fst, f, true, |l| label_to_text(l, isymt, "input")?, |l| {
+        label_to_text(l, osymt, "output")?
+    });
     Ok(())
 }
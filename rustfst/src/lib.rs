@@ -87,7 +87,7 @@
 //!
 //! ## Status
 //!
-//! A big number of algorithms are already implemented. The main one missing is the Composition.
+//! A big number of algorithms are already implemented, including the Composition (both static and lazy).
 
 #[warn(missing_docs)]
 #[cfg(test)]
@@ -95,13 +95,12 @@ extern crate counter;
 #[macro_use]
 extern crate anyhow;
 #[cfg(test)]
-extern crate rand;
-#[cfg(test)]
 extern crate serde;
 #[cfg(test)]
 extern crate serde_json;
 
 pub use crate::drawing_config::DrawingConfig;
+pub use crate::fst_error::FstError;
 pub use crate::fst_path::{check_path_in_fst, FstPath};
 pub use crate::symbol_table::SymbolTable;
 
@@ -130,6 +129,9 @@ pub type Label = usize;
 pub type Symbol = String;
 
 /// Type used to identify a state in a wFST -> usize
+///
+/// As `StateId` and `Label` are the same type, the compiler doesn't check that one is not used
+/// for the other.
 #[cfg(feature = "state-label-u32")]
 pub type StateId = u32;
 #[cfg(not(feature = "state-label-u32"))]
@@ -160,7 +162,10 @@ pub mod fst_impls;
 /// Provides a trait that shall be implemented for all weights stored inside a wFST.
 pub mod semirings;
 
+/// Graphviz (DOT) export of the wFSTs.
+pub mod draw;
 mod drawing_config;
+mod fst_error;
 /// Implementation of a successful path inside a wFST.
 mod fst_path;
 mod parsers;
@@ -72,14 +72,29 @@ impl FstHeader {
         min_file_version: i32,
         fst_loading_type: S1,
         tr_loading_type: S2,
+    ) -> IResult<&[u8], FstHeader, NomCustomError<&[u8]>> {
+        Self::parse_with_checks(
+            i,
+            min_file_version,
+            |fst_type| fst_type == fst_loading_type.as_ref(),
+            |tr_type| tr_type == tr_loading_type.as_ref(),
+        )
+    }
+
+    /// Parses a header whatever the type of the FST and the type of its trs.
+    pub(crate) fn parse_any(i: &[u8]) -> IResult<&[u8], FstHeader, NomCustomError<&[u8]>> {
+        Self::parse_with_checks(i, 0, |_| true, |_| true)
+    }
+
+    fn parse_with_checks<F1: Fn(&str) -> bool, F2: Fn(&str) -> bool>(
+        i: &[u8],
+        min_file_version: i32,
+        check_fst_type: F1,
+        check_tr_type: F2,
     ) -> IResult<&[u8], FstHeader, NomCustomError<&[u8]>> {
         let (i, magic_number) = verify(parse_bin_i32, |v: &i32| *v == FST_MAGIC_NUMBER)(i)?;
-        let (i, fst_type) = verify(OpenFstString::parse, |v| {
-            v.s.as_str() == fst_loading_type.as_ref()
-        })(i)?;
-        let (i, tr_type) = verify(OpenFstString::parse, |v| {
-            v.s.as_str() == tr_loading_type.as_ref()
-        })(i)?;
+        let (i, fst_type) = verify(OpenFstString::parse, |v| check_fst_type(v.s.as_str()))(i)?;
+        let (i, tr_type) = verify(OpenFstString::parse, |v| check_tr_type(v.s.as_str()))(i)?;
         let (i, version) = verify(parse_bin_i32, |v: &i32| *v >= min_file_version)(i)?;
         let (i, flags) = map_res(parse_bin_u32, |v: u32| {
             FstFlags::from_bits(v).ok_or_else(|| "Could not parse Fst Flags")
@@ -143,6 +158,10 @@ impl OpenFstString {
             s: _s,
         }
     }
+    pub(crate) fn as_str(&self) -> &str {
+        self.s.as_str()
+    }
+
     pub(crate) fn parse(i: &[u8]) -> IResult<&[u8], OpenFstString, NomCustomError<&[u8]>> {
         let (i, n) = parse_bin_i32(i)?;
         let (i, s) = take(n as usize)(i)?;
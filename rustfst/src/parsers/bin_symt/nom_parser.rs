@@ -30,12 +30,8 @@ pub(crate) fn parse_symbol_table_bin(
 
     let mut symt = SymbolTable::empty();
     for (key, symbol) in pairs_idx_symbols.into_iter() {
-        let inserted_label = symt.add_symbol(symbol);
-        if inserted_label != key as Label {
-            return Err(nom::Err::Error(NomCustomError::SymbolTableError(
-                format!("SymbolTable must contain increasing labels with no hole. Expected : {} and Got : {}", inserted_label, key)
-            )));
-        }
+        symt.add_symbol_with_label(symbol, key as Label)
+            .map_err(|e| nom::Err::Error(NomCustomError::SymbolTableError(format!("{}", e))))?;
     }
 
     Ok((i, symt))
@@ -47,8 +43,7 @@ pub(crate) fn write_bin_symt<W: Write, H: BuildHasher>(
 ) -> Result<()> {
     write_bin_i32(file, SYMBOL_TABLE_MAGIC_NUMBER)?;
     OpenFstString::new("rustfst_symboltable").write(file)?;
-    // TODO: Might not be available
-    write_bin_i64(file, symt.len() as i64)?;
+    write_bin_i64(file, symt.available_label() as i64)?;
     write_bin_i64(file, symt.len() as i64)?;
     for (label, symbol) in symt.iter() {
         OpenFstString::new(symbol).write(file)?;
@@ -1,4 +1,5 @@
 mod nom_parser;
 mod parsed_text_fst;
 
+pub(crate) use self::parsed_text_fst::{parse_row_with_symbols, RowParsed};
 pub use self::parsed_text_fst::{FinalState, ParsedTextFst, Transition};
@@ -1,11 +1,11 @@
 use std::fs::read_to_string;
 use std::path::Path;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 
 use crate::parsers::text_fst::nom_parser::vec_rows_parsed;
 use crate::semirings::SerializableSemiring;
-use crate::{Label, StateId};
+use crate::{FstError, Label, StateId, SymbolTable};
 
 #[derive(Debug, PartialEq)]
 pub enum RowParsed<W: SerializableSemiring> {
@@ -91,6 +91,57 @@ impl<W: SerializableSemiring> ParsedTextFst<W> {
         Ok(Self::from_vec_rows_parsed(vec_rows_parsed))
     }
 
+    /// Loads an FST from a loaded string in text format, resolving the labels through the
+    /// symbol tables when they are provided. Without a symbol table, the labels must be
+    /// integers.
+    ///
+    /// Contrary to `from_string`, the values can be separated by any whitespace and the blank
+    /// lines as well as the lines starting with `#` are ignored.
+    ///
+    /// The transitions of an acceptor can be written `src dest label [weight]`, in which case the
+    /// label is resolved through the input symbol table and used as both the input and the
+    /// output label. A line of 4 values is read as `src dest ilabel olabel` unless its last
+    /// value is not a valid output label but a valid weight.
+    ///
+    /// ## Example:
+    /// ```text
+    /// # Comment
+    /// 0   1   a   b
+    /// 1   2   b   a   0.25
+    /// 2   3   a
+    /// 3   0   b   0.5
+    ///
+    /// 2   0.67
+    /// ```
+    pub fn from_string_with_symbols(
+        fst_string: &str,
+        isymt: Option<&SymbolTable>,
+        osymt: Option<&SymbolTable>,
+    ) -> Result<Self> {
+        let mut rows = vec![];
+        for (idx, line) in fst_string.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let row = parse_row_with_symbols(line, isymt, osymt)
+                .with_context(|| format!("Error while parsing line {} of text fst", idx + 1))?;
+            rows.push(row);
+        }
+        Ok(Self::from_vec_rows_parsed(rows))
+    }
+
+    /// Loads an FST from a serialized file in text format, resolving the labels through the
+    /// symbol tables when they are provided. See `from_string_with_symbols`.
+    pub fn from_path_with_symbols<P: AsRef<Path>>(
+        path_fst_text: P,
+        isymt: Option<&SymbolTable>,
+        osymt: Option<&SymbolTable>,
+    ) -> Result<Self> {
+        let fst_string = read_to_string(path_fst_text)?;
+        Self::from_string_with_symbols(&fst_string, isymt, osymt)
+    }
+
     fn from_vec_rows_parsed(v: Vec<RowParsed<W>>) -> Self {
         let mut parsed_fst = ParsedTextFst::default();
 
@@ -156,6 +207,85 @@ impl<W: SerializableSemiring> ParsedTextFst<W> {
     }
 }
 
+fn parse_state(field: &str) -> Result<StateId> {
+    field
+        .parse()
+        .map_err(|_| format_err!("Invalid state {:?}", field))
+}
+
+fn parse_label(field: &str, symt: Option<&SymbolTable>) -> Result<Label> {
+    match symt {
+        Some(symt) => symt
+            .get_label(field)
+            .ok_or_else(|| FstError::SymbolNotFound(field.to_string()).into()),
+        None => field
+            .parse()
+            .map_err(|_| format_err!("Invalid label {:?}", field)),
+    }
+}
+
+fn parse_weight<W: SerializableSemiring>(field: &str) -> Result<W> {
+    match W::parse_text(field) {
+        Ok(("", weight)) => Ok(weight),
+        _ => bail!("Invalid weight {:?}", field),
+    }
+}
+
+pub(crate) fn parse_row_with_symbols<W: SerializableSemiring>(
+    line: &str,
+    isymt: Option<&SymbolTable>,
+    osymt: Option<&SymbolTable>,
+) -> Result<RowParsed<W>> {
+    let fields: Vec<_> = line.split_whitespace().collect();
+    match fields.len() {
+        1 => Ok(RowParsed::FinalState(FinalState::new(
+            parse_state(fields[0])?,
+            None,
+        ))),
+        2 if fields[1] == "Infinity" => Ok(RowParsed::InfinityFinalState(parse_state(fields[0])?)),
+        2 => Ok(RowParsed::FinalState(FinalState::new(
+            parse_state(fields[0])?,
+            Some(parse_weight(fields[1])?),
+        ))),
+        3 => {
+            let label = parse_label(fields[2], isymt)?;
+            Ok(RowParsed::Transition(Transition::new(
+                parse_state(fields[0])?,
+                label,
+                label,
+                None,
+                parse_state(fields[1])?,
+            )))
+        }
+        4 => {
+            let ilabel = parse_label(fields[2], isymt)?;
+            let (olabel, weight) = match parse_label(fields[3], osymt) {
+                Ok(olabel) => (olabel, None),
+                Err(e) => match parse_weight(fields[3]) {
+                    // Acceptor transition with a weight.
+                    Ok(weight) => (ilabel, Some(weight)),
+                    Err(_) => return Err(e),
+                },
+            };
+            Ok(RowParsed::Transition(Transition::new(
+                parse_state(fields[0])?,
+                ilabel,
+                olabel,
+                weight,
+                parse_state(fields[1])?,
+            )))
+        }
+        5 => Ok(RowParsed::Transition(Transition::new(
+            parse_state(fields[0])?,
+            parse_label(fields[2], isymt)?,
+            parse_label(fields[3], osymt)?,
+            Some(parse_weight(fields[4])?),
+            parse_state(fields[1])?,
+        ))),
+        n => bail!("Expected between 1 and 5 fields, got {}", n),
+    }
+}
+
 impl<W: SerializableSemiring> Transition<W> {
     pub fn new(
         state: StateId,
@@ -258,4 +388,101 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_text_fst_with_symbols_transducer() -> Result<()> {
+        let mut isymt = SymbolTable::new();
+        isymt.add_symbol("a");
+        isymt.add_symbol("b");
+        let mut osymt = SymbolTable::new();
+        osymt.add_symbol("x");
+
+        let text = "# A transducer\n0 1 a x 0.5\n\n1\t2\tb\t<eps>\n  2 0.25\n";
+        let parsed_fst = ParsedTextFst::<TropicalWeight>::from_string_with_symbols(
+            text,
+            Some(&isymt),
+            Some(&osymt),
+        )?;
+
+        let parsed_fst_ref = ParsedTextFst {
+            start_state: Some(0),
+            transitions: vec![
+                Transition::new(0, 1, 1, Some(TropicalWeight::new(0.5)), 1),
+                Transition::new(1, 2, 0, None, 2),
+            ],
+            final_states: vec![FinalState::new(2, Some(TropicalWeight::new(0.25)))],
+        };
+        assert_eq!(parsed_fst, parsed_fst_ref);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_text_fst_with_symbols_acceptor() -> Result<()> {
+        let mut symt = SymbolTable::new();
+        symt.add_symbol("a");
+        symt.add_symbol("b");
+
+        // The label of an acceptor transition is resolved through the input symbol table.
+        let text = "# An acceptor\n0\t1\ta\n1 2 b 0.5\n2\tInfinity\n2\n";
+        let parsed_fst =
+            ParsedTextFst::<TropicalWeight>::from_string_with_symbols(text, Some(&symt), None)?;
+
+        let parsed_fst_ref = ParsedTextFst {
+            start_state: Some(0),
+            transitions: vec![
+                Transition::new(0, 1, 1, None, 1),
+                Transition::new(1, 2, 2, Some(TropicalWeight::new(0.5)), 2),
+            ],
+            final_states: vec![FinalState::new(2, None)],
+        };
+        assert_eq!(parsed_fst, parsed_fst_ref);
+
+        // Without symbol table, a line of 4 integers is a transducer transition.
+        let text = "0\t1\t2\n0\t1\t2\t3\n0\t1\t2\t0.25\n";
+        let parsed_fst =
+            ParsedTextFst::<TropicalWeight>::from_string_with_symbols(text, None, None)?;
+        assert_eq!(
+            parsed_fst.transitions,
+            vec![
+                Transition::new(0, 2, 2, None, 1),
+                Transition::new(0, 2, 3, None, 1),
+                Transition::new(0, 2, 2, Some(TropicalWeight::new(0.25)), 1),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_text_fst_with_symbols_errors() {
+        let mut symt = SymbolTable::new();
+        symt.add_symbol("a");
+
+        let res = ParsedTextFst::<TropicalWeight>::from_string_with_symbols(
+            "0\t1\ta\tc\n",
+            Some(&symt),
+            Some(&symt),
+        );
+        assert!(res.is_err());
+        let res = ParsedTextFst::<TropicalWeight>::from_string_with_symbols(
+            "0\t1\tb\n",
+            Some(&symt),
+            None,
+        );
+        assert!(res.is_err());
+        let res = ParsedTextFst::<TropicalWeight>::from_string_with_symbols(
+            "0\t1\ta\tc\n",
+            Some(&symt),
+            None,
+        );
+        assert!(res.is_err());
+        let res = ParsedTextFst::<TropicalWeight>::from_string_with_symbols(
+            "0\t1\t1\t1\t0.5\t2\n",
+            None,
+            None,
+        );
+        assert!(res.is_err());
+        let res =
+            ParsedTextFst::<TropicalWeight>::from_string_with_symbols("0\t1\ta\tb\n", None, None);
+        assert!(res.is_err());
+    }
 }
@@ -1,9 +1,12 @@
 use anyhow::Result;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::semirings::{CompleteSemiring, ReverseBack, Semiring, SemiringProperties, StarSemiring};
 use std::borrow::Borrow;
 /// Boolean semiring: (&, |, false, true).
 #[derive(Clone, Debug, PartialEq, PartialOrd, Default, Eq, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
 pub struct BooleanWeight {
     value: bool,
 }
@@ -32,10 +35,6 @@ impl Semiring for BooleanWeight {
         Ok(())
     }
 
-    fn approx_equal<P: Borrow<Self>>(&self, rhs: P, _delta: f32) -> bool {
-        self.value == rhs.borrow().value
-    }
-
     fn value(&self) -> &Self::Type {
         &self.value
     }
@@ -105,4 +104,14 @@ mod tests {
         assert_eq!(b_false.times(&b_false)?, b_false);
         Ok(())
     }
+
+    #[test]
+    fn test_boolean_weight_pow() -> Result<()> {
+        let b_true = BooleanWeight::new(true);
+        let b_false = BooleanWeight::new(false);
+        assert_eq!(b_true.pow(5)?, b_true);
+        assert_eq!(b_false.pow(5)?, b_false);
+        assert_eq!(b_false.pow(0)?, BooleanWeight::one());
+        Ok(())
+    }
 }
@@ -0,0 +1,291 @@
+use std::borrow::Borrow;
+use std::fmt;
+use std::fmt::Debug;
+use std::io::Write;
+
+use anyhow::Result;
+use nom::IResult;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::parsers::nom_utils::NomCustomError;
+#[cfg(test)]
+use crate::semirings::ProbabilityWeight;
+use crate::semirings::{ReverseBack, Semiring, SemiringProperties, SerializableSemiring};
+
+/// Expectation semiring: pairs (w, v) of a probability-like weight `w` and of an accumulated
+/// value `v`. The sum is componentwise and the product is
+/// `(w1 ⊗ w2, w1 ⊗ v2 ⊕ v1 ⊗ w2)`, the weights being converted into values with `Into`.
+///
+/// The shortest distance in this semiring computes at the same time the total weight of the
+/// paths and the sum of the values weighted by the paths weights, i.e the expectation of the
+/// values up to normalization (see Eisner, "Parameter estimation for probabilistic finite-state
+/// transducers", 2002).
+///
+/// The reverse is only supported for commutative weights, in which case it is the weight itself.
+#[derive(Debug, Eq, PartialOrd, PartialEq, Clone, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+pub struct ExpectationWeight<W, V>
+where
+    W: Semiring + Into<V>,
+    V: Semiring,
+{
+    pub(crate) weight: (W, V),
+}
+
+impl<W, V> AsRef<Self> for ExpectationWeight<W, V>
+where
+    W: Semiring + Into<V>,
+    V: Semiring,
+{
+    fn as_ref(&self) -> &ExpectationWeight<W, V> {
+        &self
+    }
+}
+
+impl<W, V> Semiring for ExpectationWeight<W, V>
+where
+    W: Semiring + Into<V>,
+    V: Semiring,
+{
+    type Type = (W, V);
+    type ReverseWeight = ExpectationWeight<W, V>;
+
+    fn zero() -> Self {
+        Self {
+            weight: (W::zero(), V::zero()),
+        }
+    }
+
+    fn one() -> Self {
+        Self {
+            weight: (W::one(), V::zero()),
+        }
+    }
+
+    fn new(weight: <Self as Semiring>::Type) -> Self {
+        Self { weight }
+    }
+
+    fn plus_assign<P: Borrow<Self>>(&mut self, rhs: P) -> Result<()> {
+        self.weight.0.plus_assign(&rhs.borrow().weight.0)?;
+        self.weight.1.plus_assign(&rhs.borrow().weight.1)?;
+        Ok(())
+    }
+
+    fn times_assign<P: Borrow<Self>>(&mut self, rhs: P) -> Result<()> {
+        let (w1, v1) = &self.weight;
+        let (w2, v2) = &rhs.borrow().weight;
+        let w1_value: V = w1.clone().into();
+        let w2_value: V = w2.clone().into();
+        let value = w1_value.times(v2)?.plus(v1.times(w2_value)?)?;
+        self.weight.0.times_assign(w2)?;
+        self.weight.1 = value;
+        Ok(())
+    }
+
+    fn approx_equal<P: Borrow<Self>>(&self, rhs: P, delta: f32) -> bool {
+        self.value1().approx_equal(rhs.borrow().value1(), delta)
+            && self.value2().approx_equal(rhs.borrow().value2(), delta)
+    }
+
+    fn value(&self) -> &<Self as Semiring>::Type {
+        &self.weight
+    }
+
+    fn take_value(self) -> <Self as Semiring>::Type {
+        self.weight
+    }
+
+    fn set_value(&mut self, value: <Self as Semiring>::Type) {
+        self.weight = value;
+    }
+
+    fn reverse(&self) -> Result<Self::ReverseWeight> {
+        if !Self::properties().contains(SemiringProperties::COMMUTATIVE) {
+            bail!("ExpectationWeight : Reverse is only supported for commutative weights")
+        }
+        Ok(self.clone())
+    }
+
+    fn properties() -> SemiringProperties {
+        W::properties()
+            & V::properties()
+            & (SemiringProperties::LEFT_SEMIRING
+                | SemiringProperties::RIGHT_SEMIRING
+                | SemiringProperties::COMMUTATIVE)
+    }
+}
+
+impl<W, V> ReverseBack<ExpectationWeight<W, V>> for ExpectationWeight<W, V>
+where
+    W: Semiring + Into<V>,
+    V: Semiring,
+{
+    fn reverse_back(&self) -> Result<ExpectationWeight<W, V>> {
+        self.reverse()
+    }
+}
+
+impl<W, V> ExpectationWeight<W, V>
+where
+    W: Semiring + Into<V>,
+    V: Semiring,
+{
+    pub fn value1(&self) -> &W {
+        &self.weight.0
+    }
+
+    pub fn value2(&self) -> &V {
+        &self.weight.1
+    }
+
+    pub fn set_value1(&mut self, new_weight: W) {
+        self.weight.0 = new_weight;
+    }
+
+    pub fn set_value2(&mut self, new_value: V) {
+        self.weight.1 = new_value;
+    }
+}
+
+impl<W, V> From<(W, V)> for ExpectationWeight<W, V>
+where
+    W: Semiring + Into<V>,
+    V: Semiring,
+{
+    fn from(t: (W, V)) -> Self {
+        Self::new(t)
+    }
+}
+
+impl<W, V> fmt::Display for ExpectationWeight<W, V>
+where
+    W: SerializableSemiring + Into<V>,
+    V: SerializableSemiring,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.value1(), self.value2())?;
+        Ok(())
+    }
+}
+
+impl<W, V> SerializableSemiring for ExpectationWeight<W, V>
+where
+    W: SerializableSemiring + Into<V>,
+    V: SerializableSemiring,
+{
+    fn weight_type() -> String {
+        format!("expectation_{}_{}", W::weight_type(), V::weight_type())
+    }
+
+    fn parse_binary(i: &[u8]) -> IResult<&[u8], Self, NomCustomError<&[u8]>> {
+        let (i, weight) = W::parse_binary(i)?;
+        let (i, value) = V::parse_binary(i)?;
+        Ok((i, Self::new((weight, value))))
+    }
+
+    fn write_binary<F: Write>(&self, file: &mut F) -> Result<()> {
+        self.value1().write_binary(file)?;
+        self.value2().write_binary(file)?;
+        Ok(())
+    }
+
+    fn binary_size() -> Option<usize> {
+        Some(W::binary_size()? + V::binary_size()?)
+    }
+
+    fn parse_text(i: &str) -> IResult<&str, Self> {
+        let (i, weight) = W::parse_text(i)?;
+        let (i, _) = nom::bytes::complete::tag(",")(i)?;
+        let (i, value) = V::parse_text(i)?;
+        Ok((i, Self::new((weight, value))))
+    }
+}
+
+test_semiring_serializable!(
+    tests_expectation_weight_serializable,
+    ExpectationWeight::<ProbabilityWeight, ProbabilityWeight>,
+    ExpectationWeight::new((ProbabilityWeight::new(0.2), ProbabilityWeight::new(1.7)))
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::algorithms::shortest_distance;
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::{CoreFst, ExpandedFst, MutableFst, PathsIterator};
+    use crate::Tr;
+
+    type ProbabilityExpectationWeight = ExpectationWeight<ProbabilityWeight, ProbabilityWeight>;
+
+    fn weight(w: f32, v: f32) -> ProbabilityExpectationWeight {
+        (ProbabilityWeight::new(w), ProbabilityWeight::new(v)).into()
+    }
+
+    #[test]
+    fn test_expectation_weight_arithmetic() -> Result<()> {
+        let w1 = weight(0.5, 2.0);
+        let w2 = weight(0.25, 3.0);
+        assert_eq!(w1.plus(&w2)?, weight(0.75, 5.0));
+        // (0.5 * 0.25, 0.5 * 3.0 + 2.0 * 0.25)
+        assert_eq!(w1.times(&w2)?, weight(0.125, 2.0));
+        assert_eq!(w1.plus(ProbabilityExpectationWeight::zero())?, w1);
+        assert_eq!(w1.times(ProbabilityExpectationWeight::one())?, w1);
+        assert_eq!(ProbabilityExpectationWeight::one().times(&w1)?, w1);
+        assert_eq!(
+            w1.times(ProbabilityExpectationWeight::zero())?,
+            ProbabilityExpectationWeight::zero()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_expectation_weight_expected_trs_count() -> Result<()> {
+        // Transitions (state, label, probability, nextstate) of a small probabilistic acceptor.
+        let trs = vec![
+            (0, 1, 0.5, 1),
+            (0, 2, 0.3, 2),
+            (0, 3, 0.2, 3),
+            (1, 4, 1.0, 3),
+            (2, 5, 0.4, 1),
+            (2, 6, 0.6, 3),
+        ];
+
+        let mut fst_proba = VectorFst::<ProbabilityWeight>::new();
+        let mut fst_expectation = VectorFst::<ProbabilityExpectationWeight>::new();
+        fst_proba.add_states(4);
+        fst_expectation.add_states(4);
+        fst_proba.set_start(0)?;
+        fst_expectation.set_start(0)?;
+        for (state, label, proba, nextstate) in trs {
+            fst_proba.add_tr(state, Tr::new(label, label, proba, nextstate))?;
+            // Each transition counts for one.
+            let w = weight(proba, proba);
+            fst_expectation.add_tr(state, Tr::new(label, label, w, nextstate))?;
+        }
+        fst_proba.set_final(3, ProbabilityWeight::one())?;
+        fst_expectation.set_final(3, ProbabilityExpectationWeight::one())?;
+
+        // Direct enumeration of the paths.
+        let mut total_proba = 0.0;
+        let mut expected_count = 0.0;
+        for path in fst_proba.paths_iter() {
+            total_proba += *path.weight.value();
+            expected_count += *path.weight.value() * path.ilabels.len() as f32;
+        }
+        assert!((total_proba - 1.0f32).abs() < 1e-6);
+
+        let distances = shortest_distance(&fst_expectation, false)?;
+        let mut total = ProbabilityExpectationWeight::zero();
+        for state in fst_expectation.states_range() {
+            if let Some(final_weight) = fst_expectation.final_weight(state)? {
+                total.plus_assign(distances[state as usize].times(final_weight)?)?;
+            }
+        }
+
+        assert_eq!(total, weight(total_proba, expected_count));
+        Ok(())
+    }
+}
@@ -6,38 +6,45 @@ use std::marker::PhantomData;
 
 use anyhow::Result;
 use nom::IResult;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::parsers::nom_utils::NomCustomError;
 use crate::semirings::Semiring;
 #[cfg(test)]
 use crate::semirings::TropicalWeight;
 use crate::semirings::{
-    DivideType, SemiringProperties, SerializableSemiring, StringWeightLeft, StringWeightRestrict,
-    StringWeightRight, UnionWeight, UnionWeightOption, WeaklyDivisibleSemiring, WeightQuantize,
+    DivideType, SemiringProperties, SerializableSemiring, StarSemiring, StringWeightLeft,
+    StringWeightRestrict, StringWeightRight, UnionWeight, UnionWeightOption,
+    WeaklyDivisibleSemiring, WeightQuantize,
 };
 use crate::semirings::{ProductWeight, ReverseBack};
 use crate::Label;
 
 /// Product of StringWeightLeft and an arbitrary weight.
 #[derive(PartialOrd, PartialEq, Eq, Clone, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
 pub struct GallicWeightLeft<W>(ProductWeight<StringWeightLeft, W>)
 where
     W: Semiring;
 
 /// Product of StringWeightRight and an arbitrary weight.
 #[derive(PartialOrd, PartialEq, Eq, Clone, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
 pub struct GallicWeightRight<W>(ProductWeight<StringWeightRight, W>)
 where
     W: Semiring;
 
 /// Product of StringWeighRestrict and an arbitrary weight.
 #[derive(PartialOrd, PartialEq, Eq, Clone, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
 pub struct GallicWeightRestrict<W>(ProductWeight<StringWeightRestrict, W>)
 where
     W: Semiring;
 
 /// Product of StringWeightRestrict and an arbitrary weight.
 #[derive(PartialOrd, PartialEq, Eq, Clone, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
 pub struct GallicWeightMin<W>(ProductWeight<StringWeightRestrict, W>)
 where
     W: Semiring;
@@ -271,6 +278,20 @@ gallic_weight!(
     GallicType::GallicMin,
     GallicWeightMin<W::ReverseWeight>
 );
+
+// The closure of a left (resp. right) string weight is always the empty string: it is the
+// longest common prefix (resp. suffix) of a set of strings containing the empty string.
+impl<W: StarSemiring> StarSemiring for GallicWeightLeft<W> {
+    fn closure(&self) -> Self {
+        (StringWeightLeft::one(), self.value2().closure()).into()
+    }
+}
+
+impl<W: StarSemiring> StarSemiring for GallicWeightRight<W> {
+    fn closure(&self) -> Self {
+        (StringWeightRight::one(), self.value2().closure()).into()
+    }
+}
 #[derive(Debug, Hash, Clone, PartialEq, PartialOrd, Eq)]
 pub struct GallicUnionWeightOption<W> {
     ghost: PhantomData<W>,
@@ -322,6 +343,7 @@ impl<W: Semiring> UnionWeightOption<GallicWeightRestrict<W>>
 
 /// UnionWeight of GallicWeightRestrict.
 #[derive(Debug, PartialOrd, PartialEq, Clone, Hash, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
 pub struct GallicWeight<W>(
     pub UnionWeight<GallicWeightRestrict<W>, GallicUnionWeightOption<GallicWeightRestrict<W>>>,
 )
@@ -542,3 +564,66 @@ test_semiring_serializable!(
     GallicWeight::zero()
     GallicWeight::from((vec![1,2],TropicalWeight::new(0.3)))
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gallic_weight_left_plus() -> Result<()> {
+        let w1 = GallicWeightLeft::from((vec![1, 2, 3], TropicalWeight::new(2.0)));
+        let w2 = GallicWeightLeft::from((vec![1, 2, 4], TropicalWeight::new(3.0)));
+
+        let w = w1.plus(&w2)?;
+        assert_eq!(
+            w,
+            GallicWeightLeft::from((vec![1, 2], TropicalWeight::new(2.0)))
+        );
+        assert_eq!(w1.plus(&GallicWeightLeft::zero())?, w1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_gallic_weight_right_plus() -> Result<()> {
+        let w1 = GallicWeightRight::from((vec![1, 2, 3], TropicalWeight::new(2.0)));
+        let w2 = GallicWeightRight::from((vec![4, 2, 3], TropicalWeight::new(1.0)));
+
+        let w = w1.plus(&w2)?;
+        assert_eq!(
+            w,
+            GallicWeightRight::from((vec![2, 3], TropicalWeight::new(1.0)))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_gallic_weight_left_divide() -> Result<()> {
+        let w1 = GallicWeightLeft::from((vec![1, 2, 3], TropicalWeight::new(3.0)));
+        let w2 = GallicWeightLeft::from((vec![1, 2], TropicalWeight::new(1.0)));
+
+        let w = w1.divide(&w2, DivideType::DivideLeft)?;
+        assert_eq!(
+            w,
+            GallicWeightLeft::from((vec![3], TropicalWeight::new(2.0)))
+        );
+        assert_eq!(w2.times(&w)?, w1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_gallic_weight_closure() -> Result<()> {
+        let w = GallicWeightLeft::from((vec![1, 2], TropicalWeight::new(3.0)));
+        assert_eq!(w.closure(), GallicWeightLeft::one());
+
+        let w = GallicWeightRight::from((vec![1, 2], TropicalWeight::new(3.0)));
+        assert_eq!(w.closure(), GallicWeightRight::one());
+        Ok(())
+    }
+
+    #[test]
+    fn test_gallic_weight_restrict_plus_different_strings() {
+        let w1 = GallicWeightRestrict::from((vec![1], TropicalWeight::new(2.0)));
+        let w2 = GallicWeightRestrict::from((vec![2], TropicalWeight::new(3.0)));
+        assert!(w1.plus(&w2).is_err());
+    }
+}
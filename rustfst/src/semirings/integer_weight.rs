@@ -2,11 +2,14 @@ use std::borrow::Borrow;
 use std::i32;
 
 use anyhow::Result;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::semirings::{CompleteSemiring, ReverseBack, Semiring, SemiringProperties, StarSemiring};
 
 /// Probability semiring: (x, +, 0.0, 1.0).
 #[derive(Clone, Debug, PartialEq, PartialOrd, Default, Hash, Eq, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
 pub struct IntegerWeight {
     value: i32,
 }
@@ -36,10 +39,6 @@ impl Semiring for IntegerWeight {
         Ok(())
     }
 
-    fn approx_equal<P: Borrow<Self>>(&self, rhs: P, _delta: f32) -> bool {
-        self.value == rhs.borrow().value
-    }
-
     fn value(&self) -> &Self::Type {
         &self.value
     }
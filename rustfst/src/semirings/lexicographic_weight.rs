@@ -0,0 +1,287 @@
+use std::borrow::Borrow;
+use std::fmt;
+use std::fmt::Debug;
+use std::io::Write;
+
+use anyhow::Result;
+use nom::IResult;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::parsers::nom_utils::NomCustomError;
+#[cfg(test)]
+use crate::semirings::TropicalWeight;
+use crate::semirings::{
+    DivideType, ReverseBack, Semiring, SemiringProperties, SerializableSemiring,
+    WeaklyDivisibleSemiring, WeightQuantize,
+};
+
+/// Lexicographic semiring: W1 * W2. The sum selects the lowest weight according to the natural
+/// order of W1 and breaks ties using the natural order of W2. The product is componentwise.
+///
+/// Both W1 and W2 must have the path property (i.e. the sum always returns one of its
+/// arguments), the natural order being otherwise not total.
+#[derive(Debug, Eq, PartialOrd, PartialEq, Clone, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+pub struct LexicographicWeight<W1, W2>
+where
+    W1: Semiring,
+    W2: Semiring,
+{
+    pub(crate) weight: (W1, W2),
+}
+
+/// Returns `Less` if `w1` is strictly lower than `w2` according to the natural order.
+fn natural_compare<W: Semiring>(w1: &W, w2: &W) -> Result<std::cmp::Ordering> {
+    if w1 == w2 {
+        Ok(std::cmp::Ordering::Equal)
+    } else if &w1.plus(w2)? == w1 {
+        Ok(std::cmp::Ordering::Less)
+    } else {
+        Ok(std::cmp::Ordering::Greater)
+    }
+}
+
+impl<W1, W2> AsRef<Self> for LexicographicWeight<W1, W2>
+where
+    W1: Semiring,
+    W2: Semiring,
+{
+    fn as_ref(&self) -> &LexicographicWeight<W1, W2> {
+        &self
+    }
+}
+
+impl<W1, W2> Semiring for LexicographicWeight<W1, W2>
+where
+    W1: Semiring,
+    W2: Semiring,
+{
+    type Type = (W1, W2);
+    type ReverseWeight = LexicographicWeight<W1::ReverseWeight, W2::ReverseWeight>;
+
+    fn zero() -> Self {
+        Self {
+            weight: (W1::zero(), W2::zero()),
+        }
+    }
+
+    fn one() -> Self {
+        Self {
+            weight: (W1::one(), W2::one()),
+        }
+    }
+
+    fn new(weight: <Self as Semiring>::Type) -> Self {
+        Self { weight }
+    }
+
+    fn plus_assign<P: Borrow<Self>>(&mut self, rhs: P) -> Result<()> {
+        if !W1::properties().contains(SemiringProperties::PATH)
+            || !W2::properties().contains(SemiringProperties::PATH)
+        {
+            bail!("LexicographicWeight : Both weights must have the path property")
+        }
+        let rhs = rhs.borrow();
+        let ordering = natural_compare(self.value1(), rhs.value1())?
+            .then(natural_compare(self.value2(), rhs.value2())?);
+        if ordering == std::cmp::Ordering::Greater {
+            self.weight = rhs.weight.clone();
+        }
+        Ok(())
+    }
+
+    fn times_assign<P: Borrow<Self>>(&mut self, rhs: P) -> Result<()> {
+        self.weight.0.times_assign(&rhs.borrow().weight.0)?;
+        self.weight.1.times_assign(&rhs.borrow().weight.1)?;
+        Ok(())
+    }
+
+    fn approx_equal<P: Borrow<Self>>(&self, rhs: P, delta: f32) -> bool {
+        self.value1().approx_equal(rhs.borrow().value1(), delta)
+            && self.value2().approx_equal(rhs.borrow().value2(), delta)
+    }
+
+    fn value(&self) -> &<Self as Semiring>::Type {
+        &self.weight
+    }
+
+    fn take_value(self) -> <Self as Semiring>::Type {
+        self.weight
+    }
+
+    fn set_value(&mut self, value: <Self as Semiring>::Type) {
+        self.weight = value;
+    }
+
+    fn reverse(&self) -> Result<Self::ReverseWeight> {
+        Ok((self.value1().reverse()?, self.value2().reverse()?).into())
+    }
+
+    fn properties() -> SemiringProperties {
+        W1::properties()
+            & W2::properties()
+            & (SemiringProperties::LEFT_SEMIRING
+                | SemiringProperties::RIGHT_SEMIRING
+                | SemiringProperties::PATH
+                | SemiringProperties::IDEMPOTENT
+                | SemiringProperties::COMMUTATIVE)
+    }
+}
+
+impl<W1: Semiring, W2: Semiring> ReverseBack<LexicographicWeight<W1, W2>>
+    for <LexicographicWeight<W1, W2> as Semiring>::ReverseWeight
+{
+    fn reverse_back(&self) -> Result<LexicographicWeight<W1, W2>> {
+        Ok((self.value1().reverse_back()?, self.value2().reverse_back()?).into())
+    }
+}
+
+impl<W1, W2> LexicographicWeight<W1, W2>
+where
+    W1: Semiring,
+    W2: Semiring,
+{
+    pub fn value1(&self) -> &W1 {
+        &self.weight.0
+    }
+
+    pub fn value2(&self) -> &W2 {
+        &self.weight.1
+    }
+
+    pub fn set_value1(&mut self, new_weight: W1) {
+        self.weight.0 = new_weight;
+    }
+
+    pub fn set_value2(&mut self, new_weight: W2) {
+        self.weight.1 = new_weight;
+    }
+}
+
+impl<W1, W2> From<(W1, W2)> for LexicographicWeight<W1, W2>
+where
+    W1: Semiring,
+    W2: Semiring,
+{
+    fn from(t: (W1, W2)) -> Self {
+        Self::new(t)
+    }
+}
+
+impl<W1, W2> WeaklyDivisibleSemiring for LexicographicWeight<W1, W2>
+where
+    W1: WeaklyDivisibleSemiring,
+    W2: WeaklyDivisibleSemiring,
+{
+    fn divide_assign(&mut self, rhs: &Self, divide_type: DivideType) -> Result<()> {
+        self.weight.0.divide_assign(&rhs.weight.0, divide_type)?;
+        self.weight.1.divide_assign(&rhs.weight.1, divide_type)?;
+        Ok(())
+    }
+}
+
+impl<W1, W2> WeightQuantize for LexicographicWeight<W1, W2>
+where
+    W1: WeightQuantize,
+    W2: WeightQuantize,
+{
+    fn quantize_assign(&mut self, delta: f32) -> Result<()> {
+        self.set_value1(self.value1().quantize(delta)?);
+        self.set_value2(self.value2().quantize(delta)?);
+        Ok(())
+    }
+}
+
+impl<W1, W2> fmt::Display for LexicographicWeight<W1, W2>
+where
+    W1: SerializableSemiring,
+    W2: SerializableSemiring,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.value1(), self.value2())?;
+        Ok(())
+    }
+}
+
+impl<W1, W2> SerializableSemiring for LexicographicWeight<W1, W2>
+where
+    W1: SerializableSemiring,
+    W2: SerializableSemiring,
+{
+    fn weight_type() -> String {
+        format!("{}_LT_{}", W1::weight_type(), W2::weight_type())
+    }
+
+    fn parse_binary(i: &[u8]) -> IResult<&[u8], Self, NomCustomError<&[u8]>> {
+        let (i, weight_1) = W1::parse_binary(i)?;
+        let (i, weight_2) = W2::parse_binary(i)?;
+        Ok((i, Self::new((weight_1, weight_2))))
+    }
+
+    fn write_binary<F: Write>(&self, file: &mut F) -> Result<()> {
+        self.value1().write_binary(file)?;
+        self.value2().write_binary(file)?;
+        Ok(())
+    }
+
+    fn binary_size() -> Option<usize> {
+        Some(W1::binary_size()? + W2::binary_size()?)
+    }
+
+    fn parse_text(i: &str) -> IResult<&str, Self> {
+        let (i, weight_1) = W1::parse_text(i)?;
+        let (i, _) = nom::bytes::complete::tag(",")(i)?;
+        let (i, weight_2) = W2::parse_text(i)?;
+        Ok((i, Self::new((weight_1, weight_2))))
+    }
+}
+
+test_semiring_serializable!(
+    tests_lexicographic_weight_serializable,
+    LexicographicWeight::<TropicalWeight, TropicalWeight>,
+    LexicographicWeight::new((TropicalWeight::new(0.2), TropicalWeight::new(1.7)))
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::semirings::LogWeight;
+
+    type TropicalLexicographicWeight = LexicographicWeight<TropicalWeight, TropicalWeight>;
+
+    fn weight(w1: f32, w2: f32) -> TropicalLexicographicWeight {
+        (TropicalWeight::new(w1), TropicalWeight::new(w2)).into()
+    }
+
+    #[test]
+    fn test_lexicographic_weight_plus() -> Result<()> {
+        assert_eq!(weight(1.0, 5.0).plus(weight(1.0, 3.0))?, weight(1.0, 3.0));
+        assert_eq!(weight(1.0, 3.0).plus(weight(1.0, 5.0))?, weight(1.0, 3.0));
+        assert_eq!(weight(1.0, 3.0).plus(weight(0.0, 9.0))?, weight(0.0, 9.0));
+        assert_eq!(weight(0.0, 9.0).plus(weight(1.0, 5.0))?, weight(0.0, 9.0));
+        assert_eq!(
+            weight(2.0, 1.0).plus(TropicalLexicographicWeight::zero())?,
+            weight(2.0, 1.0)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lexicographic_weight_times() -> Result<()> {
+        assert_eq!(weight(1.0, 5.0).times(weight(2.0, 3.0))?, weight(3.0, 8.0));
+        assert_eq!(
+            weight(1.0, 5.0).times(TropicalLexicographicWeight::one())?,
+            weight(1.0, 5.0)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lexicographic_weight_not_path() {
+        let w1 = LexicographicWeight::<TropicalWeight, LogWeight>::one();
+        let w2 = LexicographicWeight::<TropicalWeight, LogWeight>::one();
+        assert!(w1.plus(w2).is_err());
+    }
+}
@@ -14,7 +14,7 @@ use crate::parsers::write_bin_f32;
 use crate::semirings::utils_float::float_approx_equal;
 use crate::semirings::{
     CompleteSemiring, DivideType, ReverseBack, Semiring, SemiringProperties, SerializableSemiring,
-    StarSemiring, WeaklyDivisibleSemiring, WeightQuantize,
+    StarSemiring, TropicalWeight, WeaklyDivisibleSemiring, WeightQuantize,
 };
 use crate::KDELTA;
 
@@ -140,6 +140,8 @@ impl_quantize_f32!(LogWeight);
 
 partial_eq_and_hash_f32!(LogWeight);
 
+serde_f32!(LogWeight);
+
 impl SerializableSemiring for LogWeight {
     fn weight_type() -> String {
         "log".to_string()
@@ -154,6 +156,10 @@ impl SerializableSemiring for LogWeight {
         write_bin_f32(file, *self.value())
     }
 
+    fn binary_size() -> Option<usize> {
+        Some(4)
+    }
+
     fn parse_text(i: &str) -> IResult<&str, Self> {
         let (i, f) = float(i)?;
         Ok((i, Self::new(f)))
@@ -171,3 +177,17 @@ impl Into<LogWeight> for f32 {
         LogWeight::new(self)
     }
 }
+
+/// Converts a `TropicalWeight` into a `LogWeight` holding the same value.
+impl From<TropicalWeight> for LogWeight {
+    fn from(w: TropicalWeight) -> Self {
+        LogWeight::new(*w.value())
+    }
+}
+
+impl LogWeight {
+    /// Converts to a `TropicalWeight` holding the same value.
+    pub fn to_tropical(&self) -> TropicalWeight {
+        TropicalWeight::from(*self)
+    }
+}
@@ -12,6 +12,9 @@ macro_rules! test_semiring_serializable {
                     let mut serialization = vec![];
 
                     weight.write_binary(&mut serialization)?;
+                    if let Some(size) = <$semiring>::binary_size() {
+                        assert_eq!(serialization.len(), size);
+                    }
 
                     let (_, weight_deserialized) = <$semiring>::parse_binary(serialization.as_slice())
                         .map_err(|e| format_err!("Can't parse weight : {:?}", e))?;
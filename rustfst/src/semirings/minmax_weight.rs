@@ -0,0 +1,209 @@
+use std::borrow::Borrow;
+use std::f32;
+use std::hash::{Hash, Hasher};
+use std::io::Write;
+
+use anyhow::Result;
+use nom::number::complete::float;
+use nom::IResult;
+use ordered_float::OrderedFloat;
+
+use crate::parsers::nom_utils::NomCustomError;
+use crate::parsers::parse_bin_f32;
+use crate::parsers::write_bin_f32;
+use crate::semirings::semiring::SerializableSemiring;
+use crate::semirings::utils_float::float_approx_equal;
+use crate::semirings::{
+    CompleteSemiring, ReverseBack, Semiring, SemiringProperties, StarSemiring, WeightQuantize,
+};
+use crate::KDELTA;
+
+/// MinMax semiring: (min, max, inf, -inf).
+///
+/// The shortest distance in this semiring is the lowest bottleneck : the minimum over all the
+/// paths of the maximum weight along the path.
+#[derive(Clone, Debug, PartialOrd, Default, Copy, Eq)]
+pub struct MinMaxWeight {
+    value: OrderedFloat<f32>,
+}
+
+impl Semiring for MinMaxWeight {
+    type Type = f32;
+    type ReverseWeight = MinMaxWeight;
+
+    fn zero() -> Self {
+        Self {
+            value: OrderedFloat(f32::INFINITY),
+        }
+    }
+
+    fn one() -> Self {
+        Self {
+            value: OrderedFloat(f32::NEG_INFINITY),
+        }
+    }
+
+    fn new(value: <Self as Semiring>::Type) -> Self {
+        MinMaxWeight {
+            value: OrderedFloat(value),
+        }
+    }
+
+    fn plus_assign<P: Borrow<Self>>(&mut self, rhs: P) -> Result<()> {
+        if rhs.borrow().value < self.value {
+            self.value = rhs.borrow().value;
+        }
+        Ok(())
+    }
+
+    fn times_assign<P: Borrow<Self>>(&mut self, rhs: P) -> Result<()> {
+        if rhs.borrow().value > self.value {
+            self.value = rhs.borrow().value;
+        }
+        Ok(())
+    }
+
+    fn approx_equal<P: Borrow<Self>>(&self, rhs: P, delta: f32) -> bool {
+        float_approx_equal(self.value.0, rhs.borrow().value.0, delta)
+    }
+
+    fn value(&self) -> &Self::Type {
+        &self.value.0
+    }
+
+    fn take_value(self) -> Self::Type {
+        self.value.0
+    }
+
+    fn set_value(&mut self, value: <Self as Semiring>::Type) {
+        self.value.0 = value
+    }
+
+    fn reverse(&self) -> Result<Self::ReverseWeight> {
+        Ok(*self)
+    }
+
+    fn properties() -> SemiringProperties {
+        SemiringProperties::LEFT_SEMIRING
+            | SemiringProperties::RIGHT_SEMIRING
+            | SemiringProperties::COMMUTATIVE
+            | SemiringProperties::PATH
+            | SemiringProperties::IDEMPOTENT
+    }
+}
+
+impl ReverseBack<MinMaxWeight> for MinMaxWeight {
+    fn reverse_back(&self) -> Result<MinMaxWeight> {
+        Ok(*self)
+    }
+}
+
+impl AsRef<MinMaxWeight> for MinMaxWeight {
+    fn as_ref(&self) -> &MinMaxWeight {
+        &self
+    }
+}
+
+display_semiring!(MinMaxWeight);
+
+impl CompleteSemiring for MinMaxWeight {}
+
+impl StarSemiring for MinMaxWeight {
+    fn closure(&self) -> Self {
+        Self::one()
+    }
+}
+
+impl_quantize_f32!(MinMaxWeight);
+
+partial_eq_and_hash_f32!(MinMaxWeight);
+
+serde_f32!(MinMaxWeight);
+
+impl SerializableSemiring for MinMaxWeight {
+    fn weight_type() -> String {
+        "minmax".to_string()
+    }
+
+    fn parse_binary(i: &[u8]) -> IResult<&[u8], Self, NomCustomError<&[u8]>> {
+        let (i, weight) = parse_bin_f32(i)?;
+        Ok((i, Self::new(weight)))
+    }
+
+    fn write_binary<F: Write>(&self, file: &mut F) -> Result<()> {
+        write_bin_f32(file, *self.value())
+    }
+
+    fn binary_size() -> Option<usize> {
+        Some(4)
+    }
+
+    fn parse_text(i: &str) -> IResult<&str, Self> {
+        let (i, f) = float(i)?;
+        Ok((i, Self::new(f)))
+    }
+}
+
+test_semiring_serializable!(
+    tests_minmax_weight_serializable,
+    MinMaxWeight,
+    MinMaxWeight::one() MinMaxWeight::zero() MinMaxWeight::new(0.3) MinMaxWeight::new(0.5) MinMaxWeight::new(0.0) MinMaxWeight::new(-1.2)
+);
+
+impl Into<MinMaxWeight> for f32 {
+    fn into(self) -> MinMaxWeight {
+        MinMaxWeight::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::algorithms::shortest_distance;
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::MutableFst;
+    use crate::Tr;
+
+    #[test]
+    fn test_minmax_weight_arithmetic() -> Result<()> {
+        let w1 = MinMaxWeight::new(2.0);
+        let w2 = MinMaxWeight::new(5.0);
+        assert_eq!(w1.plus(&w2)?, MinMaxWeight::new(2.0));
+        assert_eq!(w1.times(&w2)?, MinMaxWeight::new(5.0));
+        assert_eq!(w1.plus(MinMaxWeight::zero())?, w1);
+        assert_eq!(w1.times(MinMaxWeight::one())?, w1);
+        assert_eq!(w1.times(MinMaxWeight::zero())?, MinMaxWeight::zero());
+        assert_eq!(w1.closure(), MinMaxWeight::one());
+        Ok(())
+    }
+
+    #[test]
+    fn test_minmax_weight_approx_equal_infinite() {
+        assert!(MinMaxWeight::zero().approx_equal(MinMaxWeight::zero(), 1e-6));
+        assert!(MinMaxWeight::one().approx_equal(MinMaxWeight::one(), 1e-6));
+        assert!(!MinMaxWeight::zero().approx_equal(MinMaxWeight::one(), 1e-6));
+        assert!(!MinMaxWeight::new(2.0).approx_equal(MinMaxWeight::zero(), 1e-6));
+    }
+
+    #[test]
+    fn test_minmax_weight_bottleneck_shortest_distance() -> Result<()> {
+        // Three paths from 0 to 3 with transitions weights [3, 7], [5, 4] and [1, 6, 7].
+        // Their bottlenecks are respectively 7, 5 and 7, the lowest one being 5.
+        let mut fst = VectorFst::<MinMaxWeight>::new();
+        fst.add_states(5);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 3.0, 1))?;
+        fst.add_tr(1, Tr::new(2, 2, 7.0, 3))?;
+        fst.add_tr(0, Tr::new(3, 3, 5.0, 2))?;
+        fst.add_tr(2, Tr::new(4, 4, 4.0, 3))?;
+        fst.add_tr(0, Tr::new(5, 5, 1.0, 4))?;
+        fst.add_tr(4, Tr::new(6, 6, 6.0, 1))?;
+        fst.set_final(3, MinMaxWeight::one())?;
+
+        let distances = shortest_distance(&fst, false)?;
+        assert_eq!(distances[1], MinMaxWeight::new(3.0));
+        assert_eq!(distances[3], MinMaxWeight::new(5.0));
+        Ok(())
+    }
+}
@@ -5,9 +5,12 @@ mod semiring;
 mod macros;
 
 mod boolean_weight;
+mod expectation_weight;
 mod gallic_weight;
 mod integer_weight;
+mod lexicographic_weight;
 mod log_weight;
+mod minmax_weight;
 mod power_weight;
 mod probability_weight;
 mod product_weight;
@@ -18,11 +21,15 @@ mod union_weight;
 pub(crate) mod utils_float;
 
 pub use self::boolean_weight::BooleanWeight;
+pub use self::expectation_weight::ExpectationWeight;
 pub use self::gallic_weight::{
     GallicWeight, GallicWeightLeft, GallicWeightMin, GallicWeightRestrict, GallicWeightRight,
 };
 pub use self::integer_weight::IntegerWeight;
+pub use self::lexicographic_weight::LexicographicWeight;
 pub use self::log_weight::LogWeight;
+pub use self::minmax_weight::MinMaxWeight;
+pub use self::power_weight::PowerWeight;
 pub use self::probability_weight::ProbabilityWeight;
 pub use self::product_weight::ProductWeight;
 pub use self::semiring::{
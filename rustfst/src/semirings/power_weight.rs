@@ -1,194 +1,245 @@
-#![allow(unused)]
-
-use std::cmp::Ordering;
+use std::borrow::Borrow;
+use std::convert::TryInto;
 use std::fmt;
-use std::fmt::Debug;
-use std::hash::Hash;
-use std::hash::Hasher;
+use std::ops::{Index, IndexMut};
 
 use anyhow::Result;
 
-use generic_array::ArrayLength;
-use generic_array::GenericArray;
-
 use crate::semirings::{
-    DivideType, Semiring, SemiringProperties, WeaklyDivisibleSemiring, WeightQuantize,
+    DivideType, ReverseBack, Semiring, SemiringProperties, WeaklyDivisibleSemiring, WeightQuantize,
 };
 
-/// Cartesian power semiring: W ^ n.
-pub struct PowerWeight<W, N>
-where
-    W: Semiring,
-    N: ArrayLength<W>,
-{
-    weights: GenericArray<W, N>,
+/// Cartesian power semiring: W ^ N. The sum and the product are componentwise.
+#[derive(Debug, Eq, PartialOrd, PartialEq, Clone, Hash)]
+pub struct PowerWeight<W: Semiring, const N: usize> {
+    weights: [W; N],
 }
 
-impl<W, N> fmt::Display for PowerWeight<W, N>
-where
-    W: Semiring,
-    N: ArrayLength<W>,
-{
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.weights.as_slice().fmt(f)
+/// Builds an array of N copies of a weight. `[W; N]` requires `W: Copy` and `array::map` isn't
+/// available on the minimum supported Rust version.
+fn array_from_elem<W: Clone, const N: usize>(w: W) -> [W; N] {
+    let weights = vec![w; N];
+    match weights.try_into() {
+        Ok(weights) => weights,
+        Err(_) => unreachable!(),
     }
 }
 
-impl<W, N> fmt::Debug for PowerWeight<W, N>
+/// Builds an array of N weights from a fallible function of the index.
+fn try_array_from_fn<W, F, const N: usize>(f: F) -> Result<[W; N]>
 where
-    W: Semiring,
-    N: ArrayLength<W>,
+    F: FnMut(usize) -> Result<W>,
 {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.weights.as_slice().fmt(f)
-    }
+    let weights = (0..N).map(f).collect::<Result<Vec<_>>>()?;
+    weights
+        .try_into()
+        .map_err(|_| format_err!("PowerWeight : Expected {} weights", N))
 }
 
-impl<W, N> Hash for PowerWeight<W, N>
-where
-    W: Semiring,
-    N: ArrayLength<W>,
-{
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.weights.as_slice().hash(state);
+impl<W: Semiring, const N: usize> AsRef<Self> for PowerWeight<W, N> {
+    fn as_ref(&self) -> &PowerWeight<W, N> {
+        &self
     }
 }
 
-impl<W, N> Clone for PowerWeight<W, N>
-where
-    W: Semiring,
-    N: ArrayLength<W>,
-{
-    fn clone(&self) -> Self {
-        PowerWeight {
-            weights: self.weights.clone(),
+impl<W: Semiring, const N: usize> Semiring for PowerWeight<W, N> {
+    type Type = [W; N];
+    type ReverseWeight = PowerWeight<W::ReverseWeight, N>;
+
+    fn zero() -> Self {
+        Self {
+            weights: array_from_elem(W::zero()),
         }
     }
+
+    fn one() -> Self {
+        Self {
+            weights: array_from_elem(W::one()),
+        }
+    }
+
+    fn new(weights: <Self as Semiring>::Type) -> Self {
+        Self { weights }
+    }
+
+    fn plus_assign<P: Borrow<Self>>(&mut self, rhs: P) -> Result<()> {
+        for (w, w_rhs) in self.weights.iter_mut().zip(rhs.borrow().weights.iter()) {
+            w.plus_assign(w_rhs)?;
+        }
+        Ok(())
+    }
+
+    fn times_assign<P: Borrow<Self>>(&mut self, rhs: P) -> Result<()> {
+        for (w, w_rhs) in self.weights.iter_mut().zip(rhs.borrow().weights.iter()) {
+            w.times_assign(w_rhs)?;
+        }
+        Ok(())
+    }
+
+    fn approx_equal<P: Borrow<Self>>(&self, rhs: P, delta: f32) -> bool {
+        self.weights
+            .iter()
+            .zip(rhs.borrow().weights.iter())
+            .all(|(w, w_rhs)| w.approx_equal(w_rhs, delta))
+    }
+
+    fn value(&self) -> &<Self as Semiring>::Type {
+        &self.weights
+    }
+
+    fn take_value(self) -> <Self as Semiring>::Type {
+        self.weights
+    }
+
+    fn set_value(&mut self, value: <Self as Semiring>::Type) {
+        self.weights = value;
+    }
+
+    fn reverse(&self) -> Result<Self::ReverseWeight> {
+        Ok(PowerWeight::new(try_array_from_fn(|i| {
+            self.weights[i].reverse()
+        })?))
+    }
+
+    fn properties() -> SemiringProperties {
+        W::properties()
+            & (SemiringProperties::LEFT_SEMIRING
+                | SemiringProperties::RIGHT_SEMIRING
+                | SemiringProperties::COMMUTATIVE
+                | SemiringProperties::IDEMPOTENT)
+    }
 }
 
-impl<W, N> PartialOrd for PowerWeight<W, N>
-where
-    W: Semiring,
-    N: ArrayLength<W>,
+impl<W: Semiring, const N: usize> ReverseBack<PowerWeight<W, N>>
+    for <PowerWeight<W, N> as Semiring>::ReverseWeight
 {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.weights.partial_cmp(&other.weights)
+    fn reverse_back(&self) -> Result<PowerWeight<W, N>> {
+        Ok(PowerWeight::new(try_array_from_fn(|i| {
+            self.weights[i].reverse_back()
+        })?))
     }
 }
 
-impl<W, N> PartialEq for PowerWeight<W, N>
-where
-    W: Semiring,
-    N: ArrayLength<W>,
-{
-    fn eq(&self, other: &Self) -> bool {
-        self.weights.eq(&other.weights)
+impl<W: Semiring, const N: usize> PowerWeight<W, N> {
+    /// Number of components of the weight.
+    pub fn len(&self) -> usize {
+        N
+    }
+
+    pub fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<W> {
+        self.weights.iter()
+    }
+}
+
+impl<W: Semiring, const N: usize> From<[W; N]> for PowerWeight<W, N> {
+    fn from(weights: [W; N]) -> Self {
+        Self::new(weights)
     }
 }
 
-impl<W, N> AsRef<Self> for PowerWeight<W, N>
+impl<W: Semiring, const N: usize> Index<usize> for PowerWeight<W, N> {
+    type Output = W;
+
+    fn index(&self, index: usize) -> &W {
+        &self.weights[index]
+    }
+}
+
+impl<W: Semiring, const N: usize> IndexMut<usize> for PowerWeight<W, N> {
+    fn index_mut(&mut self, index: usize) -> &mut W {
+        &mut self.weights[index]
+    }
+}
+
+impl<W, const N: usize> WeaklyDivisibleSemiring for PowerWeight<W, N>
 where
-    W: Semiring,
-    N: ArrayLength<W>,
+    W: WeaklyDivisibleSemiring,
 {
-    fn as_ref(&self) -> &PowerWeight<W, N> {
-        &self
+    fn divide_assign(&mut self, rhs: &Self, divide_type: DivideType) -> Result<()> {
+        for (w, w_rhs) in self.weights.iter_mut().zip(rhs.weights.iter()) {
+            w.divide_assign(w_rhs, divide_type)?;
+        }
+        Ok(())
     }
 }
 
-impl<W, N> Eq for PowerWeight<W, N>
+impl<W, const N: usize> WeightQuantize for PowerWeight<W, N>
 where
-    W: Semiring,
-    N: ArrayLength<W>,
+    W: WeightQuantize,
 {
+    fn quantize_assign(&mut self, delta: f32) -> Result<()> {
+        for w in self.weights.iter_mut() {
+            w.quantize_assign(delta)?;
+        }
+        Ok(())
+    }
+}
+
+impl<W: Semiring + fmt::Display, const N: usize> fmt::Display for PowerWeight<W, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, w) in self.weights.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", w)?;
+        }
+        Ok(())
+    }
 }
 
-//impl<W, N> Semiring for PowerWeight<W, N>
-//where
-//    W: Semiring,
-//    N: ArrayLength<W>,
-//{
-//    type Type = GenericArray<W, N>;
-//    type ReverseSemiring<P> = PowerWeight<W::ReverseSemiring, P>;
-//
-//    fn zero() -> Self {
-//        Self {
-//            weights: GenericArray::clone_from_slice(&[W::zero()]),
-//        }
-//    }
-//
-//    fn one() -> Self {
-//        Self {
-//            weights: GenericArray::clone_from_slice(&[W::one()]),
-//        }
-//    }
-//
-//    fn new(value: <Self as Semiring>::Type) -> Self {
-//        Self { weights: value }
-//    }
-//
-//    fn plus_assign<P: AsRef<Self>>(&mut self, rhs: P) -> Result<()> {
-//        for i in 0..self.weights.len() {
-//            self.weights[i].plus_assign(&rhs.as_ref().weights[i])?;
-//        }
-//        Ok(())
-//    }
-//
-//    fn times_assign<P: AsRef<Self>>(&mut self, rhs: P) -> Result<()> {
-//        for i in 0..self.weights.len() {
-//            self.weights[i].times_assign(&rhs.as_ref().weights[i])?;
-//        }
-//        Ok(())
-//    }
-//
-//    fn value(&self) -> <Self as Semiring>::Type {
-//        self.weights.clone()
-//    }
-//
-//    fn set_value(&mut self, value: <Self as Semiring>::Type) {
-//        self.weights = value;
-//    }
-//
-//    fn reverse(&self) -> Self::ReverseSemiring {
-//        let mut rw = Vec::with_capacity(self.weights.len());
-//        for i in 0..self.weights.len() {
-//            rw.push(self.weights[i].reverse());
-//        }
-//        PowerWeight::new(GenericArray::clone_from(rw))
-//    }
-//
-//    fn properties() -> SemiringProperties {
-//        W::properties()
-//            & (SemiringProperties::LEFT_SEMIRING
-//                | SemiringProperties::RIGHT_SEMIRING
-//                | SemiringProperties::COMMUTATIVE
-//                | SemiringProperties::IDEMPOTENT)
-//    }
-//}
-//
-//impl<W, N> WeaklyDivisibleSemiring for PowerWeight<W, N>
-//where
-//    W: WeaklyDivisibleSemiring,
-//    N: ArrayLength<W>,
-//{
-//    fn divide(&self, rhs: &Self, divide_type: DivideType) -> Result<Self> {
-//        let mut mul = self.clone();
-//        for i in 0..self.weights.len() {
-//            mul.weights[i] = self.weights[i].divide(&rhs.weights[i], divide_type)?;
-//        }
-//        Ok(mul)
-//    }
-//}
-//
-//impl<W, N> WeightQuantize for PowerWeight<W, N>
-//where
-//    W: WeightQuantize,
-//    N: ArrayLength<W>,
-//{
-//    fn quantize_assign(&mut self, delta: f32) -> Result<()> {
-//        for i in 0..self.weights.len() {
-//            unsafe { self.weights.get_unchecked_mut(i).quantize_assign(delta)? };
-//        }
-//        Ok(())
-//    }
-//}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::semirings::TropicalWeight;
+
+    type TropicalPowerWeight = PowerWeight<TropicalWeight, 3>;
+
+    fn weight(w1: f32, w2: f32, w3: f32) -> TropicalPowerWeight {
+        PowerWeight::from([
+            TropicalWeight::new(w1),
+            TropicalWeight::new(w2),
+            TropicalWeight::new(w3),
+        ])
+    }
+
+    #[test]
+    fn test_power_weight_identities() {
+        assert_eq!(
+            TropicalPowerWeight::zero().value(),
+            &[TropicalWeight::zero(); 3]
+        );
+        assert_eq!(
+            TropicalPowerWeight::one().value(),
+            &[TropicalWeight::one(); 3]
+        );
+    }
+
+    #[test]
+    fn test_power_weight_componentwise() -> Result<()> {
+        let w1 = weight(1.0, 5.0, 2.0);
+        let w2 = weight(3.0, 4.0, 2.5);
+        assert_eq!(w1.plus(&w2)?, weight(1.0, 4.0, 2.0));
+        assert_eq!(w1.times(&w2)?, weight(4.0, 9.0, 4.5));
+        assert_eq!(w1.plus(TropicalPowerWeight::zero())?, w1);
+        assert_eq!(w1.times(TropicalPowerWeight::one())?, w1);
+        assert_eq!(
+            w1.times(TropicalPowerWeight::zero())?,
+            TropicalPowerWeight::zero()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_power_weight_index() {
+        let mut w = weight(1.0, 5.0, 2.0);
+        assert_eq!(w.len(), 3);
+        assert_eq!(w[1], TropicalWeight::new(5.0));
+        w[1] = TropicalWeight::new(0.5);
+        assert_eq!(w, weight(1.0, 0.5, 2.0));
+    }
+}
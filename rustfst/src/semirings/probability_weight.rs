@@ -112,6 +112,10 @@ impl SerializableSemiring for ProbabilityWeight {
         write_bin_f32(file, *self.value())
     }
 
+    fn binary_size() -> Option<usize> {
+        Some(4)
+    }
+
     fn parse_text(i: &str) -> IResult<&str, Self> {
         let (i, f) = float(i)?;
         Ok((i, Self::new(f)))
@@ -139,6 +143,8 @@ impl_quantize_f32!(ProbabilityWeight);
 
 partial_eq_and_hash_f32!(ProbabilityWeight);
 
+serde_f32!(ProbabilityWeight);
+
 test_semiring_serializable!(
     tests_probability_weight_serializable,
     ProbabilityWeight,
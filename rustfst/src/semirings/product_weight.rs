@@ -5,17 +5,20 @@ use std::io::Write;
 
 use anyhow::Result;
 use nom::IResult;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::parsers::nom_utils::NomCustomError;
 use crate::semirings::{
-    DivideType, ReverseBack, Semiring, SemiringProperties, SerializableSemiring,
-    WeaklyDivisibleSemiring, WeightQuantize,
+    CompleteSemiring, DivideType, ReverseBack, Semiring, SemiringProperties, SerializableSemiring,
+    StarSemiring, WeaklyDivisibleSemiring, WeightQuantize,
 };
 #[cfg(test)]
 use crate::semirings::{LogWeight, TropicalWeight};
 
 /// Product semiring: W1 * W2.
 #[derive(Debug, Eq, PartialOrd, PartialEq, Clone, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
 pub struct ProductWeight<W1, W2>
 where
     W1: Semiring,
@@ -154,6 +157,23 @@ where
     }
 }
 
+impl<W1, W2> CompleteSemiring for ProductWeight<W1, W2>
+where
+    W1: CompleteSemiring,
+    W2: CompleteSemiring,
+{
+}
+
+impl<W1, W2> StarSemiring for ProductWeight<W1, W2>
+where
+    W1: StarSemiring,
+    W2: StarSemiring,
+{
+    fn closure(&self) -> Self {
+        (self.value1().closure(), self.value2().closure()).into()
+    }
+}
+
 impl<W1, W2> WeightQuantize for ProductWeight<W1, W2>
 where
     W1: WeightQuantize,
@@ -198,6 +218,10 @@ where
         Ok(())
     }
 
+    fn binary_size() -> Option<usize> {
+        Some(W1::binary_size()? + W2::binary_size()?)
+    }
+
     fn parse_text(i: &str) -> IResult<&str, Self> {
         let (i, weight_1) = W1::parse_text(i)?;
         let (i, _) = nom::bytes::complete::tag(",")(i)?;
@@ -211,3 +235,63 @@ test_semiring_serializable!(
     ProductWeight::<TropicalWeight, LogWeight>,
     ProductWeight::new((TropicalWeight::new(0.2), LogWeight::new(1.7)))
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type TropicalLogWeight = ProductWeight<TropicalWeight, LogWeight>;
+
+    fn weight(w1: f32, w2: f32) -> TropicalLogWeight {
+        (TropicalWeight::new(w1), LogWeight::new(w2)).into()
+    }
+
+    #[test]
+    fn test_product_weight_componentwise() -> Result<()> {
+        let w1 = weight(1.0, 2.0);
+        let w2 = weight(3.0, 0.5);
+
+        let sum = w1.plus(&w2)?;
+        assert_eq!(sum.value1(), &TropicalWeight::new(1.0));
+        assert!(sum
+            .value2()
+            .approx_equal(LogWeight::new(2.0).plus(LogWeight::new(0.5))?, 1e-5));
+
+        assert_eq!(w1.times(&w2)?, weight(4.0, 2.5));
+
+        assert_eq!(
+            TropicalLogWeight::zero(),
+            weight(f32::INFINITY, f32::INFINITY)
+        );
+        assert_eq!(TropicalLogWeight::one(), weight(0.0, 0.0));
+        assert_eq!(w1.plus(&TropicalLogWeight::zero())?, w1);
+        assert_eq!(w1.times(&TropicalLogWeight::one())?, w1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_product_weight_distributivity() -> Result<()> {
+        let a = weight(1.0, 0.3);
+        let b = weight(2.5, 1.2);
+        let c = weight(0.5, 2.0);
+
+        let left = a.times(&b.plus(&c)?)?;
+        let right = a.times(&b)?.plus(&a.times(&c)?)?;
+        assert!(left.approx_equal(&right, 1e-5));
+
+        let left = b.plus(&c)?.times(&a)?;
+        let right = b.times(&a)?.plus(&c.times(&a)?)?;
+        assert!(left.approx_equal(&right, 1e-5));
+        Ok(())
+    }
+
+    #[test]
+    fn test_product_weight_closure() {
+        let w = weight(1.0, 2.0);
+        let closure = TropicalLogWeight::from((
+            TropicalWeight::new(1.0).closure(),
+            LogWeight::new(2.0).closure(),
+        ));
+        assert_eq!(w.closure(), closure);
+    }
+}
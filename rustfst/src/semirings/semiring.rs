@@ -56,7 +56,29 @@ pub trait Semiring: Clone + PartialEq + PartialOrd + Debug + Hash + Eq + Sync +
     }
     fn times_assign<P: Borrow<Self>>(&mut self, rhs: P) -> Result<()>;
 
-    fn approx_equal<P: Borrow<Self>>(&self, rhs: P, delta: f32) -> bool;
+    /// Computes `self ^ n`, i.e the product of `n` copies of `self`, using exponentiation by
+    /// squaring. Returns `One()` for `n == 0`.
+    fn pow(&self, n: usize) -> Result<Self> {
+        let mut res = Self::one();
+        let mut base = self.clone();
+        let mut n = n;
+        while n > 0 {
+            if n & 1 == 1 {
+                res.times_assign(&base)?;
+            }
+            n >>= 1;
+            if n > 0 {
+                base = base.times(&base)?;
+            }
+        }
+        Ok(res)
+    }
+
+    /// Checks whether two weights are equal up to `delta`. Defaults to the equality of the weights,
+    /// the semirings over floats compare the values with the given `delta` instead.
+    fn approx_equal<P: Borrow<Self>>(&self, rhs: P, _delta: f32) -> bool {
+        self == rhs.borrow()
+    }
 
     /// Borrow underneath value.
     fn value(&self) -> &Self::Type;
@@ -174,10 +196,40 @@ macro_rules! partial_eq_and_hash_f32 {
     };
 }
 
+/// Serializes the weight with serde as its float value.
+macro_rules! serde_f32 {
+    ($semiring:tt) => {
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $semiring {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> std::result::Result<S::Ok, S::Error> {
+                serializer.serialize_f32(*self.value())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $semiring {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> std::result::Result<Self, D::Error> {
+                let value = <f32 as serde::Deserialize>::deserialize(deserializer)?;
+                Ok(Self::new(value))
+            }
+        }
+    };
+}
+
 pub trait SerializableSemiring: Semiring + Display {
     fn weight_type() -> String;
     fn parse_binary(i: &[u8]) -> IResult<&[u8], Self, NomCustomError<&[u8]>>;
     fn write_binary<F: Write>(&self, file: &mut F) -> Result<()>;
+    /// Number of bytes written by `write_binary` for any weight or `None` if it depends on the
+    /// weight.
+    fn binary_size() -> Option<usize> {
+        None
+    }
 
     fn parse_text(i: &str) -> IResult<&str, Self>;
     fn write_text<F: Write>(&self, file: &mut F) -> Result<()> {
@@ -1,6 +1,10 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use crate::Label;
 
 #[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum StringWeightVariant {
     Infinity,
     Labels(Vec<Label>),
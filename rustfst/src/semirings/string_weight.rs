@@ -7,31 +7,36 @@ use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::multi::{count, separated_list0};
 use nom::IResult;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::parsers::nom_utils::{num, NomCustomError};
 use crate::parsers::parse_bin_i32;
 use crate::parsers::write_bin_i32;
 use crate::semirings::string_variant::StringWeightVariant;
 use crate::semirings::{
-    DivideType, ReverseBack, Semiring, SemiringProperties, SerializableSemiring,
+    DivideType, ReverseBack, Semiring, SemiringProperties, SerializableSemiring, StarSemiring,
     WeaklyDivisibleSemiring, WeightQuantize,
 };
 use crate::Label;
 
 /// String semiring: (identity, ., Infinity, Epsilon)
 #[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
 pub struct StringWeightRestrict {
     pub(crate) value: StringWeightVariant,
 }
 
 /// String semiring: (longest_common_prefix, ., Infinity, Epsilon)
 #[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
 pub struct StringWeightLeft {
     pub(crate) value: StringWeightVariant,
 }
 
 /// String semiring: (longest_common_suffix, ., Infinity, Epsilon)
 #[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
 pub struct StringWeightRight {
     pub(crate) value: StringWeightVariant,
 }
@@ -139,10 +144,6 @@ macro_rules! string_semiring {
                 Ok(())
             }
 
-            fn approx_equal<P: Borrow<Self>>(&self, rhs: P, _delta: f32) -> bool {
-                self.value() == rhs.borrow().value()
-            }
-
             fn value(&self) -> &<Self as Semiring>::Type {
                 &self.value
             }
@@ -370,6 +371,20 @@ impl WeaklyDivisibleSemiring for StringWeightRestrict {
     }
 }
 
+// The longest common prefix (resp. suffix) of a set of strings containing the empty string
+// is the empty string.
+impl StarSemiring for StringWeightLeft {
+    fn closure(&self) -> Self {
+        Self::one()
+    }
+}
+
+impl StarSemiring for StringWeightRight {
+    fn closure(&self) -> Self {
+        Self::one()
+    }
+}
+
 test_semiring_serializable!(
     tests_string_weight_left_serializable,
     StringWeightLeft,
@@ -393,3 +408,73 @@ test_semiring_serializable!(
     StringWeightRestrict::new(StringWeightVariant::Labels(vec![1]))
     StringWeightRestrict::new(StringWeightVariant::Labels(vec![4, 5, 2]))
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string_weight_left_plus() -> Result<()> {
+        let w1: StringWeightLeft = vec![1, 2, 3].into();
+        let w2: StringWeightLeft = vec![1, 2, 4].into();
+        assert_eq!(w1.plus(&w2)?, StringWeightLeft::from(vec![1, 2]));
+
+        let w3: StringWeightLeft = vec![4].into();
+        assert_eq!(w1.plus(&w3)?, StringWeightLeft::one());
+        Ok(())
+    }
+
+    #[test]
+    fn test_string_weight_right_plus() -> Result<()> {
+        let w1: StringWeightRight = vec![1, 2, 3].into();
+        let w2: StringWeightRight = vec![4, 2, 3].into();
+        assert_eq!(w1.plus(&w2)?, StringWeightRight::from(vec![2, 3]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_string_weight_times() -> Result<()> {
+        let w1: StringWeightLeft = vec![1, 2].into();
+        let w2: StringWeightLeft = vec![3].into();
+        assert_eq!(w1.times(&w2)?, StringWeightLeft::from(vec![1, 2, 3]));
+
+        let w1: StringWeightRight = vec![1, 2].into();
+        let w2: StringWeightRight = vec![3].into();
+        assert_eq!(w1.times(&w2)?, StringWeightRight::from(vec![1, 2, 3]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_string_weight_identities() -> Result<()> {
+        let w: StringWeightLeft = vec![1, 2].into();
+        assert_eq!(w.plus(&StringWeightLeft::zero())?, w);
+        assert_eq!(w.times(&StringWeightLeft::one())?, w);
+        assert_eq!(StringWeightLeft::one().times(&w)?, w);
+        assert!(w.times(&StringWeightLeft::zero())?.is_zero());
+        assert!(StringWeightLeft::zero().times(&w)?.is_zero());
+
+        let w: StringWeightRestrict = vec![1, 2].into();
+        assert_eq!(w.plus(&w)?, w);
+        assert!(w.plus(&StringWeightRestrict::from(vec![1])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_string_weight_divide() -> Result<()> {
+        let w1: StringWeightLeft = vec![1, 2, 3].into();
+        let w2: StringWeightLeft = vec![1, 2].into();
+        assert_eq!(
+            w1.divide(&w2, DivideType::DivideLeft)?,
+            StringWeightLeft::from(vec![3])
+        );
+        assert!(w1.divide(&w2, DivideType::DivideRight).is_err());
+
+        let w1: StringWeightRight = vec![1, 2, 3].into();
+        let w2: StringWeightRight = vec![2, 3].into();
+        assert_eq!(
+            w1.divide(&w2, DivideType::DivideRight)?,
+            StringWeightRight::from(vec![1])
+        );
+        Ok(())
+    }
+}
@@ -14,8 +14,8 @@ use crate::parsers::write_bin_f32;
 use crate::semirings::semiring::SerializableSemiring;
 use crate::semirings::utils_float::float_approx_equal;
 use crate::semirings::{
-    CompleteSemiring, DivideType, ReverseBack, Semiring, SemiringProperties, StarSemiring,
-    WeaklyDivisibleSemiring, WeightQuantize,
+    CompleteSemiring, DivideType, LogWeight, ReverseBack, Semiring, SemiringProperties,
+    StarSemiring, WeaklyDivisibleSemiring, WeightQuantize,
 };
 use crate::KDELTA;
 
@@ -132,6 +132,8 @@ impl_quantize_f32!(TropicalWeight);
 
 partial_eq_and_hash_f32!(TropicalWeight);
 
+serde_f32!(TropicalWeight);
+
 impl SerializableSemiring for TropicalWeight {
     fn weight_type() -> String {
         "tropical".to_string()
@@ -146,6 +148,10 @@ impl SerializableSemiring for TropicalWeight {
         write_bin_f32(file, *self.value())
     }
 
+    fn binary_size() -> Option<usize> {
+        Some(4)
+    }
+
     fn parse_text(i: &str) -> IResult<&str, Self> {
         let (i, f) = float(i)?;
         Ok((i, Self::new(f)))
@@ -163,3 +169,55 @@ impl Into<TropicalWeight> for f32 {
         TropicalWeight::new(self)
     }
 }
+
+/// Converts a `LogWeight` into a `TropicalWeight` holding the same value. Only `plus` differs.
+impl From<LogWeight> for TropicalWeight {
+    fn from(w: LogWeight) -> Self {
+        TropicalWeight::new(*w.value())
+    }
+}
+
+impl TropicalWeight {
+    /// Converts to a `LogWeight` holding the same value.
+    pub fn to_log(&self) -> LogWeight {
+        LogWeight::from(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tropical_weight_approx_equal() {
+        let w1 = TropicalWeight::new(0.0);
+        let w2 = TropicalWeight::new(1e-7);
+        assert!(w1.approx_equal(&w2, 1e-6));
+        assert!(!w1.approx_equal(&w2, 1e-9));
+        assert!(TropicalWeight::zero().approx_equal(TropicalWeight::zero(), 1e-9));
+        assert!(!TropicalWeight::zero().approx_equal(TropicalWeight::one(), 1e-9));
+    }
+
+    #[test]
+    fn test_tropical_weight_pow() -> Result<()> {
+        let w = TropicalWeight::new(2.0);
+        assert_eq!(w.pow(0)?, TropicalWeight::one());
+        assert_eq!(w.pow(1)?, w);
+        assert_eq!(w.pow(3)?, TropicalWeight::new(6.0));
+        assert_eq!(w.pow(10)?, TropicalWeight::new(20.0));
+        assert_eq!(TropicalWeight::zero().pow(3)?, TropicalWeight::zero());
+        Ok(())
+    }
+
+    #[test]
+    fn test_tropical_log_conversions() {
+        let w = TropicalWeight::new(1.3);
+        let w_log = w.to_log();
+        assert_eq!(*w_log.value(), 1.3);
+        assert_eq!(w_log.to_tropical(), w);
+        assert_eq!(LogWeight::from(w), w_log);
+        assert_eq!(TropicalWeight::from(w_log), w);
+        assert_eq!(TropicalWeight::zero().to_log(), LogWeight::zero());
+        assert_eq!(LogWeight::one().to_tropical(), TropicalWeight::one());
+    }
+}
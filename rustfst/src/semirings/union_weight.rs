@@ -11,6 +11,8 @@ use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::multi::{count, separated_list0};
 use nom::IResult;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::parsers::nom_utils::NomCustomError;
 use crate::parsers::parse_bin_i32;
@@ -32,8 +34,10 @@ pub trait UnionWeightOption<W: Semiring>:
 /// for Plus() and Zero(), respectively. Template argument O specifies the union
 /// weight options as above.
 #[derive(PartialOrd, PartialEq, Clone, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
 pub struct UnionWeight<W: Semiring, O: UnionWeightOption<W>> {
     pub(crate) list: Vec<W>,
+    #[cfg_attr(feature = "serde", serde(skip))]
     ghost: PhantomData<O>,
 }
 
@@ -8,10 +8,11 @@ use itertools::Itertools;
 
 use crate::parsers::bin_symt::nom_parser::{parse_symbol_table_bin, write_bin_symt};
 use crate::parsers::text_symt::parsed_text_symt::ParsedTextSymt;
-use crate::{Label, EPS_SYMBOL};
+use crate::{Label, EPS_LABEL, EPS_SYMBOL};
 use std::collections::hash_map::{Entry, RandomState};
 use std::collections::HashMap;
 use std::hash::BuildHasher;
+use std::iter::FromIterator;
 
 /// A symbol table stores a bidirectional mapping between transition labels and "symbols" (strings).
 #[derive(Debug, Clone)]
@@ -48,15 +49,12 @@ impl SymbolTable {
     }
 
     fn from_parsed_symt_text(parsed_symt_text: ParsedTextSymt) -> Result<Self> {
-        let mut bimap = BiHashMapString::new();
+        let mut symt = SymbolTable::empty();
         for (symbol, label) in parsed_symt_text.pairs.into_iter() {
-            let inserted_label = bimap.get_id_or_insert(symbol);
-            if inserted_label as Label != label {
-                bail!("The SymbolTable should contain labels with increasing ids and no hole. Expected {} and got {}", inserted_label, label)
-            }
+            symt.add_symbol_with_label(symbol, label)?;
         }
 
-        Ok(SymbolTable { bimap })
+        Ok(symt)
     }
 
     pub fn from_text_string(symt_string: &str) -> Result<Self> {
@@ -69,6 +67,7 @@ impl SymbolTable {
         Self::from_parsed_symt_text(parsed_symt)
     }
 
+    /// Reads a `SymbolTable` stored in the OpenFst binary format.
     pub fn read<P: AsRef<Path>>(path_bin_symt: P) -> Result<Self> {
         let data = read(path_bin_symt.as_ref()).with_context(|| {
             format!(
@@ -95,7 +94,33 @@ impl<H: BuildHasher> SymbolTable<H> {
         self.len() == 0
     }
 
+    /// Returns the label of a symbol, adding the symbol to the table if it is not present.
+    /// Only one lookup is performed in both cases.
+    ///
+    /// A new symbol is mapped to the label following the highest label of the table
+    /// (see `available_label`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[macro_use] extern crate rustfst; fn main() {
+    /// # use rustfst::SymbolTable;
+    /// let mut symt = symt!["a", "b"];
+    ///
+    /// assert_eq!(symt.find_or_add("b"), 2);
+    /// assert_eq!(symt.find_or_add("c"), 3);
+    /// assert_eq!(symt.len(), 4);
+    /// # }
+    /// ```
+    pub fn find_or_add<S: Into<String>>(&mut self, sym: S) -> Label {
+        self.bimap.get_id_or_insert(sym.into()) as Label
+    }
+
     /// Adds a symbol to the symbol table. The corresponding label is returned.
+    /// If the symbol is already present, its label is returned and the table is left unchanged
+    /// (see `find_or_add`).
+    ///
+    /// A new symbol is mapped to the label following the highest label of the table
+    /// (see `available_label`).
     ///
     /// # Examples
     /// ```rust
@@ -114,7 +139,45 @@ impl<H: BuildHasher> SymbolTable<H> {
     /// # }
     /// ```
     pub fn add_symbol(&mut self, sym: impl Into<String>) -> Label {
-        self.bimap.get_id_or_insert(sym.into()) as Label
+        self.find_or_add(sym)
+    }
+
+    /// Adds a symbol to the symbol table with a specific label.
+    ///
+    /// The label can be greater than `available_label`, in which case the labels in between are
+    /// left unmapped and the following calls to `add_symbol` use the labels after `label`.
+    /// Adding a pair already stored in the table is a no-op. An error is raised if the label or
+    /// the symbol is already mapped to something else.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[macro_use] extern crate rustfst; fn main() -> anyhow::Result<()> {
+    /// # use rustfst::SymbolTable;
+    /// let mut symt = symt!["a", "b"];
+    ///
+    /// symt.add_symbol_with_label("c", 10)?;
+    /// assert_eq!(symt.get_label("c"), Some(10));
+    /// assert_eq!(symt.get_symbol(5), None);
+    /// assert_eq!(symt.add_symbol("d"), 11);
+    ///
+    /// assert!(symt.add_symbol_with_label("e", 1).is_err());
+    /// assert!(symt.add_symbol_with_label("a", 12).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_symbol_with_label<S: Into<String>>(&mut self, sym: S, label: Label) -> Result<()> {
+        let sym = sym.into();
+        if let Some(l) = self.get_label(&sym) {
+            if l == label {
+                return Ok(());
+            }
+            bail!("Symbol {:?} is already mapped to label {}", sym, l);
+        }
+        if let Some(s) = self.get_symbol(label) {
+            bail!("Label {} is already mapped to symbol {:?}", label, s);
+        }
+        self.bimap.insert_with_id(label as usize, sym);
+        Ok(())
     }
 
     pub fn add_symbols<S: Into<String>, P: IntoIterator<Item = S>>(&mut self, symbols: P) {
@@ -123,6 +186,58 @@ impl<H: BuildHasher> SymbolTable<H> {
         }
     }
 
+    /// Removes a symbol from the symbol table. The label it was mapped to is returned.
+    /// If the symbol is not stored in the table then `None` is returned.
+    ///
+    /// The removed label is left as a hole : the other labels are unchanged, so the labels used
+    /// on the trs of an FST remain valid, and the label is not reused by `add_symbol`.
+    /// The epsilon label can't be removed, `None` is returned and the table is left untouched.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[macro_use] extern crate rustfst; fn main() {
+    /// # use rustfst::{SymbolTable, EPS_SYMBOL};
+    /// let mut symt = symt!["a", "b", "c"];
+    ///
+    /// assert_eq!(symt.remove_symbol("b"), Some(2));
+    /// assert_eq!(symt.remove_symbol("b"), None);
+    /// assert_eq!(symt.remove_symbol(EPS_SYMBOL), None);
+    ///
+    /// // Elements in the table : `<eps>`, `a`, `c`
+    /// assert_eq!(symt.len(), 3);
+    /// assert_eq!(symt.get_label("c"), Some(3));
+    /// # }
+    /// ```
+    pub fn remove_symbol<S: Into<String>>(&mut self, sym: S) -> Option<Label> {
+        let label = self.get_label(sym.into())?;
+        self.remove_label(label).map(|_| label)
+    }
+
+    /// Removes a label from the symbol table. The symbol it was mapped to is returned.
+    /// If the label is not stored in the table then `None` is returned.
+    ///
+    /// Same as `remove_symbol`, the removed label is left as a hole and the epsilon label
+    /// can't be removed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[macro_use] extern crate rustfst; fn main() {
+    /// # use rustfst::{SymbolTable, EPS_LABEL};
+    /// let mut symt = symt!["a", "b"];
+    ///
+    /// assert_eq!(symt.remove_label(1), Some("a".to_string()));
+    /// assert_eq!(symt.get_symbol(1), None);
+    /// assert_eq!(symt.get_symbol(2), Some("b"));
+    /// assert_eq!(symt.remove_label(EPS_LABEL), None);
+    /// # }
+    /// ```
+    pub fn remove_label(&mut self, label: Label) -> Option<String> {
+        if label == EPS_LABEL {
+            return None;
+        }
+        self.bimap.remove_id(label as usize)
+    }
+
     /// Returns the number of symbols stored in the symbol table.
     ///
     /// # Examples
@@ -137,6 +252,26 @@ impl<H: BuildHasher> SymbolTable<H> {
         self.bimap.len()
     }
 
+    /// Returns the label that `add_symbol` maps the next new symbol to, i.e the highest label
+    /// of the table plus one. It is equal to `len` when the table has no hole.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[macro_use] extern crate rustfst; fn main() -> anyhow::Result<()> {
+    /// # use rustfst::SymbolTable;
+    /// let mut symt = symt!["a", "b"];
+    /// assert_eq!(symt.available_label(), 3);
+    ///
+    /// symt.add_symbol_with_label("c", 5)?;
+    /// assert_eq!(symt.len(), 4);
+    /// assert_eq!(symt.available_label(), 6);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn available_label(&self) -> Label {
+        self.bimap.available_id() as Label
+    }
+
     /// Given a symbol, returns the label corresponding.
     /// If the symbol is not stored in the table then `None` is returned.
     ///
@@ -207,7 +342,7 @@ impl<H: BuildHasher> SymbolTable<H> {
     }
 
     /// An iterator on all the labels stored in the `SymbolTable`.
-    /// The iterator element is `&'a Label`.
+    /// The iterator element is `Label`.
     ///
     /// # Examples
     /// ```rust
@@ -218,12 +353,12 @@ impl<H: BuildHasher> SymbolTable<H> {
     ///
     /// # }
     /// ```
-    pub fn labels(&self) -> impl Iterator<Item = Label> {
+    pub fn labels(&self) -> impl Iterator<Item = Label> + '_ {
         self.bimap.iter_ids().map(|it| it as Label)
     }
 
     /// An iterator on all the symbols stored in the `SymbolTable`.
-    /// The iterator element is `&'a Symbol`.
+    /// The iterator element is `&'a str`.
     ///
     /// # Examples
     /// ```rust
@@ -241,12 +376,80 @@ impl<H: BuildHasher> SymbolTable<H> {
         self.bimap.iter_strings()
     }
 
-    /// An iterator on all the labels stored in the `SymbolTable`.
-    /// The iterator element is `(&'a Label, &'a Symbol)`.
+    /// An iterator on all the pairs stored in the `SymbolTable`.
+    /// The iterator element is `(Label, &'a str)`.
     pub fn iter(&self) -> impl Iterator<Item = (Label, &str)> {
         self.bimap.iter().map(|(label, sym)| (label as Label, sym))
     }
 
+    /// An iterator on all the pairs stored in the `SymbolTable`, by increasing label.
+    /// The iterator element is `(Label, &'a str)`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use rustfst::{SymbolTable, EPS_SYMBOL};
+    /// let mut symt = SymbolTable::new();
+    /// symt.add_symbol_with_label("b", 7)?;
+    /// symt.add_symbol_with_label("a", 3)?;
+    ///
+    /// assert_eq!(
+    ///     symt.iter_sorted().collect::<Vec<_>>(),
+    ///     vec![(0, EPS_SYMBOL), (3, "a"), (7, "b")]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_sorted(&self) -> impl Iterator<Item = (Label, &str)> {
+        self.iter().sorted_by_key(|k| k.0)
+    }
+
+    /// Rewrites the labels of the table according to `mapping`. Labels not present in `mapping`
+    /// (for instance the epsilon label) are left unchanged.
+    ///
+    /// The mapping must be injective on the labels of the table : an error is raised if two
+    /// distinct symbols would end up with the same label, including a symbol whose label is left
+    /// unchanged. In that case, the table is left untouched. The new labels can be greater than
+    /// the current ones, leaving holes in the label space.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[macro_use] extern crate rustfst; fn main() -> anyhow::Result<()> {
+    /// # use rustfst::SymbolTable;
+    /// # use std::collections::HashMap;
+    /// let mut symt = symt!["a", "b"];
+    ///
+    /// // Shift the non-epsilon labels by 10.
+    /// let mapping: HashMap<_, _> = symt.labels().skip(1).map(|l| (l, l + 10)).collect();
+    /// symt.relabel(&mapping)?;
+    ///
+    /// assert_eq!(symt.get_label("a"), Some(11));
+    /// assert_eq!(symt.get_label("b"), Some(12));
+    /// assert_eq!(symt.get_symbol(1), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn relabel(&mut self, mapping: &HashMap<Label, Label>) -> Result<()> {
+        let mut new_symbols: Vec<Option<String>> = vec![];
+        for (label, symbol) in self.iter() {
+            let new_label = mapping.get(&label).cloned().unwrap_or(label);
+            if new_label as usize >= new_symbols.len() {
+                new_symbols.resize(new_label as usize + 1, None);
+            }
+            if let Some(other_symbol) = &new_symbols[new_label as usize] {
+                bail!(
+                    "Can't relabel : symbols {:?} and {:?} would both be mapped to label {}",
+                    other_symbol,
+                    symbol,
+                    new_label
+                );
+            }
+            new_symbols[new_label as usize] = Some(symbol.to_string());
+        }
+        self.bimap.set_strings(new_symbols);
+        Ok(())
+    }
+
     /// Adds another SymbolTable to this table.
     pub fn add_table(&mut self, other: &SymbolTable) {
         for symbol in other.symbols() {
@@ -254,6 +457,100 @@ impl<H: BuildHasher> SymbolTable<H> {
         }
     }
 
+    /// Merges another SymbolTable into this table while preserving the labels of `other`.
+    ///
+    /// There is a conflict when a label is mapped to different symbols in the two tables or when
+    /// a symbol is mapped to different labels. The pairs that are only present in `other` are
+    /// added to this table.
+    ///
+    /// If at least one conflict is found, an error listing all the conflicts is raised and
+    /// the table is left untouched.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[macro_use] extern crate rustfst; fn main() -> anyhow::Result<()> {
+    /// # use rustfst::SymbolTable;
+    /// let mut symt1 = symt!["a", "b"];
+    /// let symt2 = symt!["a", "b", "c"];
+    /// symt1.merge(&symt2)?;
+    /// assert_eq!(symt1.get_label("c"), Some(3));
+    ///
+    /// let symt3 = symt!["b"];
+    /// assert!(symt1.merge(&symt3).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn merge(&mut self, other: &SymbolTable) -> Result<()> {
+        let mut conflicts = vec![];
+        for (label, symbol) in other.iter() {
+            if let Some(self_symbol) = self.get_symbol(label) {
+                if self_symbol != symbol {
+                    conflicts.push(format!(
+                        "label {} is mapped to {:?} and {:?}",
+                        label, self_symbol, symbol
+                    ));
+                }
+            } else if let Some(self_label) = self.get_label(symbol) {
+                conflicts.push(format!(
+                    "symbol {:?} is mapped to {} and {}",
+                    symbol, self_label, label
+                ));
+            }
+        }
+        if !conflicts.is_empty() {
+            bail!(
+                "Can't merge the SymbolTables, conflicts found : {}",
+                conflicts.join(", ")
+            );
+        }
+
+        for (label, symbol) in other.iter() {
+            if !self.contains_label(label) {
+                self.bimap
+                    .insert_with_id(label as usize, symbol.to_string());
+            }
+        }
+        Ok(())
+    }
+
+    /// Computes a fingerprint of the symbols stored in the table, ignoring their labels.
+    /// The result doesn't depend on the order in which the symbols are stored and is stable
+    /// across platforms and Rust releases, so it can be stored and compared later.
+    pub fn check_sum(&self) -> u64 {
+        self.symbols()
+            .map(|symbol| fnv1a_hash(symbol.as_bytes(), FNV_OFFSET_BASIS))
+            .fold(0, u64::wrapping_add)
+    }
+
+    /// Computes a fingerprint of the (label, symbol) pairs stored in the table.
+    /// Same as `check_sum`, the result doesn't depend on the order in which the pairs are stored.
+    pub fn labeled_check_sum(&self) -> u64 {
+        self.iter()
+            .map(|(label, symbol)| {
+                let hash = fnv1a_hash(&(label as u64).to_le_bytes(), FNV_OFFSET_BASIS);
+                fnv1a_hash(symbol.as_bytes(), hash)
+            })
+            .fold(0, u64::wrapping_add)
+    }
+
+    /// Returns whether the two tables have the same (label, symbol) pairs according
+    /// to their `labeled_check_sum`. Useful to check that two FSTs can be composed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[macro_use] extern crate rustfst; fn main() {
+    /// # use rustfst::SymbolTable;
+    /// let symt1 = symt!["a", "b"];
+    /// let symt2 = symt!["a", "b"];
+    /// let symt3 = symt!["b", "a"];
+    /// assert!(symt1.compatible(&symt2));
+    /// assert!(!symt1.compatible(&symt3));
+    /// # }
+    /// ```
+    pub fn compatible<H2: BuildHasher>(&self, other: &SymbolTable<H2>) -> bool {
+        self.labeled_check_sum() == other.labeled_check_sum()
+    }
+
     pub fn write_text<P: AsRef<Path>>(&self, path_output: P) -> Result<()> {
         let buffer = File::create(path_output.as_ref())?;
         let mut writer = BufWriter::new(LineWriter::new(buffer));
@@ -263,6 +560,8 @@ impl<H: BuildHasher> SymbolTable<H> {
         Ok(())
     }
 
+    /// Writes the `SymbolTable` using the OpenFst binary format (little-endian).
+    /// The resulting file can be read by `SymbolTable::read` or by OpenFst.
     pub fn write<P: AsRef<Path>>(&self, path_bin_symt: P) -> Result<()> {
         let buffer = File::create(path_bin_symt.as_ref())?;
         let mut writer = BufWriter::new(LineWriter::new(buffer));
@@ -283,7 +582,7 @@ impl<H: BuildHasher> SymbolTable<H> {
 
 impl<H: BuildHasher> fmt::Display for SymbolTable<H> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for (label, symbol) in self.iter().sorted_by_key(|k| k.0) {
+        for (label, symbol) in self.iter_sorted() {
             writeln!(f, "{}\t{}", symbol, label)?;
         }
         Ok(())
@@ -296,6 +595,82 @@ impl<H: BuildHasher> PartialEq for SymbolTable<H> {
     }
 }
 
+/// The `SymbolTable` is serialized as the list of its `(label, symbol)` pairs by increasing label.
+#[cfg(feature = "serde")]
+impl<H: BuildHasher> serde::Serialize for SymbolTable<H> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter_sorted())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, H: BuildHasher + Default> serde::Deserialize<'de> for SymbolTable<H> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let pairs = <Vec<(Label, String)> as serde::Deserialize>::deserialize(deserializer)?;
+        let mut symt = SymbolTable {
+            bimap: BiHashMapString::with_hasher(H::default()),
+        };
+        for (label, symbol) in pairs.into_iter() {
+            symt.add_symbol_with_label(symbol, label)
+                .map_err(serde::de::Error::custom)?;
+        }
+        Ok(symt)
+    }
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// 64-bit FNV-1a hash of `bytes`, starting from `hash`. Contrary to `DefaultHasher`, its output
+/// is fixed, which is required for the checksums of a `SymbolTable`.
+fn fnv1a_hash(bytes: &[u8], hash: u64) -> u64 {
+    bytes
+        .iter()
+        .fold(hash, |h, b| (h ^ u64::from(*b)).wrapping_mul(FNV_PRIME))
+}
+
+/// Creates a `SymbolTable` containing the symbols of the iterator.
+/// Same as `SymbolTable::new`, the epsilon symbol is mapped to the label `0`.
+///
+/// # Examples
+/// ```rust
+/// # use rustfst::{SymbolTable, EPS_SYMBOL};
+/// let symt: SymbolTable = vec!["a", "b"].into_iter().collect();
+/// assert_eq!(symt.len(), 3);
+/// assert_eq!(symt.get_symbol(0).unwrap(), EPS_SYMBOL);
+/// assert_eq!(symt.get_symbol(1).unwrap(), "a");
+/// assert_eq!(symt.get_symbol(2).unwrap(), "b");
+/// ```
+impl<S: Into<String>> FromIterator<S> for SymbolTable {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        let mut symt = SymbolTable::new();
+        symt.add_symbols(iter);
+        symt
+    }
+}
+
+/// Adds all the symbols of the iterator to the `SymbolTable`.
+///
+/// # Examples
+/// ```rust
+/// # #[macro_use] extern crate rustfst; fn main() {
+/// # use rustfst::SymbolTable;
+/// let mut symt = symt!["a", "b"];
+/// symt.extend(vec!["b", "c"]);
+/// assert_eq!(symt.len(), 4);
+/// # }
+/// ```
+impl<S: Into<String>, H: BuildHasher> Extend<S> for SymbolTable<H> {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, iter: I) {
+        self.add_symbols(iter)
+    }
+}
+
 /// Creates a `SymbolTable` containing the arguments.
 /// ```
 /// # #[macro_use] extern crate rustfst; fn main() {
@@ -323,7 +698,8 @@ macro_rules! symt {
 #[derive(Clone, Debug, Default)]
 pub(crate) struct BiHashMapString<H: BuildHasher = RandomState> {
     string_to_id: HashMap<String, usize, H>,
-    id_to_string: Vec<String>,
+    // `None` for the ids that are not mapped to any string.
+    id_to_string: Vec<Option<String>>,
 }
 
 impl<H: BuildHasher> PartialEq for BiHashMapString<H> {
@@ -350,6 +726,10 @@ impl<H: BuildHasher> BiHashMapString<H> {
     }
 
     pub fn len(&self) -> usize {
+        self.string_to_id.len()
+    }
+
+    pub fn available_id(&self) -> usize {
         self.id_to_string.len()
     }
 
@@ -363,23 +743,49 @@ impl<H: BuildHasher> BiHashMapString<H> {
             Entry::Occupied(e) => *e.get(),
             Entry::Vacant(e) => {
                 let n = self.id_to_string.len();
-                self.id_to_string.push(e.key().clone());
+                self.id_to_string.push(Some(e.key().clone()));
                 e.insert(n);
                 n
             }
         }
     }
 
+    /// Maps `v` to `id`. Neither of them must be already mapped.
+    pub fn insert_with_id(&mut self, id: usize, v: String) {
+        if id >= self.id_to_string.len() {
+            self.id_to_string.resize(id + 1, None);
+        }
+        self.id_to_string[id] = Some(v.clone());
+        self.string_to_id.insert(v, id);
+    }
+
+    pub fn set_strings(&mut self, id_to_string: Vec<Option<String>>) {
+        self.string_to_id.clear();
+        for (id, s) in id_to_string.iter().enumerate() {
+            if let Some(s) = s {
+                self.string_to_id.insert(s.clone(), id);
+            }
+        }
+        self.id_to_string = id_to_string;
+    }
+
+    /// Unmaps `id`, leaving a hole. The other ids are unchanged.
+    pub fn remove_id(&mut self, id: usize) -> Option<String> {
+        let v = self.id_to_string.get_mut(id)?.take()?;
+        self.string_to_id.remove(&v);
+        Some(v)
+    }
+
     pub fn get_id(&self, v: impl AsRef<str>) -> Option<usize> {
         self.string_to_id.get(v.as_ref()).cloned()
     }
 
     pub fn get_string(&self, id: usize) -> Option<&str> {
-        self.id_to_string.get(id).map(|s| s.as_str())
+        self.id_to_string.get(id).and_then(|s| s.as_deref())
     }
 
-    pub fn iter_ids(&self) -> impl Iterator<Item = usize> {
-        0..self.id_to_string.len()
+    pub fn iter_ids(&self) -> impl Iterator<Item = usize> + '_ {
+        self.iter().map(|(id, _)| id)
     }
 
     pub fn iter_strings(&self) -> impl Iterator<Item = &str> {
@@ -387,13 +793,17 @@ impl<H: BuildHasher> BiHashMapString<H> {
     }
 
     pub fn iter(&self) -> impl Iterator<Item = (usize, &str)> {
-        self.id_to_string.iter().map(|s| s.as_str()).enumerate()
+        self.id_to_string
+            .iter()
+            .enumerate()
+            .filter_map(|(id, s)| s.as_deref().map(|s| (id, s)))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tempfile::tempdir;
 
     #[test]
     fn test_symt() {
@@ -426,12 +836,317 @@ mod tests {
 
     #[test]
     fn test_symt_add_twice_symbol() {
+        let mut symt = SymbolTable::new();
+        let label_1 = symt.add_symbol("a");
+        let label_2 = symt.add_symbol("a");
+
+        assert_eq!(label_1, label_2);
+        assert_eq!(symt.len(), 2);
+        assert_eq!(symt.get_label("a"), Some(1));
+        assert_eq!(symt.get_symbol(1), Some("a"));
+        assert_eq!(symt.get_symbol(2), None);
+        assert_eq!(symt.labels().count(), symt.symbols().count());
+    }
+
+    #[test]
+    fn test_symt_add_symbol_interleaved() {
+        let mut symt = SymbolTable::new();
+        let labels: Vec<_> = vec!["a", "b", "a", "c", "b", "a"]
+            .into_iter()
+            .map(|s| symt.add_symbol(s))
+            .collect();
+
+        assert_eq!(labels, vec![1, 2, 1, 3, 2, 1]);
+        assert_eq!(symt.len(), 4);
+    }
+
+    #[test]
+    fn test_symt_find_or_add() {
+        let mut symt = symt!["a", "b"];
+        let symt_ref = symt.clone();
+
+        // Existing symbols : the table is left unchanged.
+        assert_eq!(symt.find_or_add("b"), 2);
+        assert_eq!(symt.find_or_add(EPS_SYMBOL), 0);
+        assert_eq!(symt, symt_ref);
+
+        // New symbols, interleaved with existing ones.
+        assert_eq!(symt.find_or_add("c"), 3);
+        assert_eq!(symt.find_or_add("a"), 1);
+        assert_eq!(symt.find_or_add("d".to_string()), 4);
+        assert_eq!(symt.find_or_add("c"), 3);
+        assert_eq!(symt.len(), 5);
+        assert_eq!(symt.get_symbol(4), Some("d"));
+    }
+
+    #[test]
+    fn test_symt_remove_symbol() {
         let mut symt = SymbolTable::new();
         symt.add_symbol("a");
+        symt.add_symbol("b");
+        symt.add_symbol("c");
+
+        assert_eq!(symt.remove_symbol("b"), Some(2));
+        assert_eq!(symt.remove_symbol("b"), None);
+        assert_eq!(symt.remove_symbol("d"), None);
+
+        assert_eq!(symt.len(), 3);
+        assert_eq!(symt.contains_symbol("b"), false);
+        assert_eq!(symt.get_label("a"), Some(1));
+        assert_eq!(symt.get_label("c"), Some(3));
+        assert_eq!(symt.get_symbol(2), None);
+        assert_eq!(symt.get_symbol(3), Some("c"));
+        assert_eq!(symt.labels().collect::<Vec<_>>(), vec![0, 1, 3]);
+
+        assert_eq!(symt.remove_symbol(EPS_SYMBOL), None);
+        assert_eq!(symt.get_label(EPS_SYMBOL), Some(0));
+    }
+
+    #[test]
+    fn test_symt_remove_label() {
+        let mut symt = SymbolTable::new();
         symt.add_symbol("a");
+        symt.add_symbol("b");
+
+        assert_eq!(symt.remove_label(1), Some("a".to_string()));
+        assert_eq!(symt.remove_label(1), None);
+        assert_eq!(symt.remove_label(EPS_LABEL), None);
 
         assert_eq!(symt.len(), 2);
-        assert_eq!(symt.get_label("a"), Some(1));
+        assert_eq!(symt.get_symbol(1), None);
+        assert_eq!(symt.get_label("a"), None);
+        assert_eq!(symt.get_symbol(2), Some("b"));
+        assert_eq!(symt.get_symbol(0), Some(EPS_SYMBOL));
+
+        // The freed label is not reused by a new symbol.
+        assert_eq!(symt.add_symbol("c"), 3);
+        // But it can be mapped again explicitly.
+        assert!(symt.add_symbol_with_label("a", 1).is_ok());
+    }
+
+    #[test]
+    fn test_symt_write_read_binary() -> Result<()> {
+        let mut symt = SymbolTable::new();
+        symt.add_symbol("a");
+        symt.add_symbol("b");
+
+        let dir = tempdir()?;
+        let path = dir.path().join("symt.bin");
+        symt.write(&path)?;
+        let symt_read = SymbolTable::read(&path)?;
+
+        assert_eq!(symt, symt_read);
+        Ok(())
+    }
+
+    #[test]
+    fn test_symt_relabel_offset() -> Result<()> {
+        let mut symt = SymbolTable::new();
+        symt.add_symbols(vec!["a", "b", "c"]);
+
+        // Shift all the non-epsilon labels by 100.
+        let mapping: HashMap<Label, Label> = (1..4).map(|l| (l, l + 100)).collect();
+        symt.relabel(&mapping)?;
+
+        assert_eq!(symt.len(), 4);
+        assert_eq!(symt.get_label(EPS_SYMBOL), Some(0));
+        assert_eq!(symt.get_label("a"), Some(101));
+        assert_eq!(symt.get_label("b"), Some(102));
+        assert_eq!(symt.get_label("c"), Some(103));
+        assert_eq!(symt.get_symbol(1), None);
+        assert_eq!(symt.available_label(), 104);
+        assert_eq!(symt.add_symbol("d"), 104);
+        Ok(())
+    }
+
+    #[test]
+    fn test_symt_relabel_rotation() -> Result<()> {
+        let mut symt = SymbolTable::new();
+        symt.add_symbols(vec!["a", "b", "c"]);
+
+        let mapping: HashMap<Label, Label> = (1..4).map(|l| (l, l % 3 + 1)).collect();
+        symt.relabel(&mapping)?;
+
+        assert_eq!(symt.get_label("a"), Some(2));
+        assert_eq!(symt.get_label("b"), Some(3));
+        assert_eq!(symt.get_label("c"), Some(1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_symt_relabel_collision() {
+        let mut symt = SymbolTable::new();
+        symt.add_symbols(vec!["a", "b"]);
+        let symt_ref = symt.clone();
+
+        // "a" would collide with "b" whose label is left unchanged.
+        let mut mapping = HashMap::new();
+        mapping.insert(1, 2);
+        assert!(symt.relabel(&mapping).is_err());
+
+        let mut mapping = HashMap::new();
+        mapping.insert(1, 5);
+        mapping.insert(2, 5);
+        assert!(symt.relabel(&mapping).is_err());
+
+        // Epsilon is preserved unless explicitly remapped.
+        let mut mapping = HashMap::new();
+        mapping.insert(2, 0);
+        assert!(symt.relabel(&mapping).is_err());
+
+        assert_eq!(symt, symt_ref);
+    }
+
+    #[test]
+    fn test_symt_merge() -> Result<()> {
+        let mut symt1 = SymbolTable::new();
+        symt1.add_symbols(vec!["a", "b"]);
+
+        let mut symt2 = SymbolTable::new();
+        symt2.add_symbols(vec!["a", "b", "c", "d"]);
+
+        symt1.merge(&symt2)?;
+
+        assert_eq!(symt1, symt2);
+        assert_eq!(symt1.get_label("c"), Some(3));
+        assert_eq!(symt1.get_label("d"), Some(4));
+        Ok(())
+    }
+
+    #[test]
+    fn test_symt_merge_conflicts() {
+        let mut symt1 = SymbolTable::new();
+        symt1.add_symbols(vec!["a", "b"]);
+        let symt1_ref = symt1.clone();
+
+        // Label 1 is mapped to "a" and "c".
+        let mut symt2 = SymbolTable::new();
+        symt2.add_symbols(vec!["c"]);
+        assert!(symt1.merge(&symt2).is_err());
+
+        // Symbols "a" and "b" are swapped.
+        let mut symt3 = SymbolTable::new();
+        symt3.add_symbols(vec!["b", "a", "c"]);
+        assert!(symt1.merge(&symt3).is_err());
+
+        assert_eq!(symt1, symt1_ref);
+    }
+
+    #[test]
+    fn test_symt_add_symbol_with_label() -> Result<()> {
+        let mut symt = SymbolTable::new();
+        symt.add_symbol("a");
+
+        symt.add_symbol_with_label("b", 2)?;
+        // Adding the same pair twice is a no-op.
+        symt.add_symbol_with_label("b", 2)?;
+        assert_eq!(symt.add_symbol("c"), 3);
+
+        assert!(symt.add_symbol_with_label("d", 1).is_err());
+        assert!(symt.add_symbol_with_label("a", 4).is_err());
+
+        assert_eq!(symt.len(), 4);
+        assert_eq!(symt.get_symbol(2), Some("b"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_symt_add_symbol_with_high_label() -> Result<()> {
+        let mut symt = SymbolTable::new();
+        symt.add_symbol("a");
+
+        symt.add_symbol_with_label("b", 10)?;
+        assert_eq!(symt.add_symbol("c"), 11);
+        symt.add_symbol_with_label("d", 5)?;
+
+        assert_eq!(symt.len(), 5);
+        assert_eq!(symt.available_label(), 12);
+        assert_eq!(symt.get_symbol(4), None);
+        assert_eq!(symt.get_symbol(5), Some("d"));
+        assert_eq!(symt.get_label("b"), Some(10));
+        assert_eq!(
+            symt.iter().collect::<Vec<_>>(),
+            vec![(0, EPS_SYMBOL), (1, "a"), (5, "d"), (10, "b"), (11, "c")]
+        );
+        assert_eq!(symt.labels().collect::<Vec<_>>(), vec![0, 1, 5, 10, 11]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_symt_with_holes_write_read() -> Result<()> {
+        let mut symt = SymbolTable::new();
+        symt.add_symbol_with_label("a", 3)?;
+        symt.add_symbol_with_label("b", 7)?;
+
+        assert_eq!(SymbolTable::from_text_string(&symt.text()?)?, symt);
+
+        let dir = tempdir()?;
+        let path = dir.path().join("symt.bin");
+        symt.write(&path)?;
+        assert_eq!(SymbolTable::read(&path)?, symt);
+        Ok(())
+    }
+
+    #[test]
+    fn test_symt_iter_sorted() -> Result<()> {
+        let mut symt = SymbolTable::new();
+        symt.add_symbol_with_label("c", 9)?;
+        symt.add_symbol_with_label("a", 2)?;
+        symt.add_symbol_with_label("b", 5)?;
+
+        let mapping: HashMap<Label, Label> = vec![(2, 20), (9, 1)].into_iter().collect();
+        symt.relabel(&mapping)?;
+
+        assert_eq!(
+            symt.iter_sorted().collect::<Vec<_>>(),
+            vec![(0, EPS_SYMBOL), (1, "c"), (5, "b"), (20, "a")]
+        );
+        assert_eq!(
+            symt.iter().sorted().collect::<Vec<_>>(),
+            symt.iter_sorted().collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_symt_check_sum() -> Result<()> {
+        let mut symt1 = SymbolTable::new();
+        symt1.add_symbols(vec!["a", "b", "c"]);
+
+        let mut symt2 = SymbolTable::new();
+        symt2.add_symbols(vec!["c", "b", "a"]);
+
+        assert_eq!(symt1.check_sum(), symt2.check_sum());
+        assert_ne!(symt1.labeled_check_sum(), symt2.labeled_check_sum());
+        assert!(!symt1.compatible(&symt2));
+
+        let mapping: HashMap<Label, Label> = vec![(1, 3), (3, 1)].into_iter().collect();
+        symt2.relabel(&mapping)?;
+
+        assert_eq!(symt1.labeled_check_sum(), symt2.labeled_check_sum());
+        assert!(symt1.compatible(&symt2));
+
+        symt2.add_symbol("d");
+        assert_ne!(symt1.check_sum(), symt2.check_sum());
+        assert!(!symt1.compatible(&symt2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_symt_check_sum_stable() {
+        // Reference values of the 64-bit FNV-1a hash.
+        assert_eq!(fnv1a_hash(b"", FNV_OFFSET_BASIS), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a_hash(b"a", FNV_OFFSET_BASIS), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(
+            fnv1a_hash(b"foobar", FNV_OFFSET_BASIS),
+            0x8594_4171_f739_67e8
+        );
+
+        let symt = symt!["a"];
+        assert_eq!(
+            symt.check_sum(),
+            fnv1a_hash(b"<eps>", FNV_OFFSET_BASIS).wrapping_add(0xaf63_dc4c_8601_ec8c)
+        );
     }
 
     #[test]
@@ -1,8 +1,12 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use crate::semirings::SerializableSemiring;
-use crate::{Label, StateId};
+use crate::{Label, StateId, EPS_LABEL};
 
 /// Structure representing a transition from a state to another state in a FST.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Tr<W> {
     /// Input label.
     pub ilabel: Label,
@@ -39,6 +43,99 @@ impl<W> Tr<W> {
         }
     }
 
+    /// Creates a new Tr of an acceptor, the input and output labels being equal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustfst::Tr;
+    /// # use rustfst::semirings::{TropicalWeight, Semiring};
+    /// let transition = Tr::<TropicalWeight>::acceptor(3, 1.3, 2);
+    ///
+    /// assert_eq!(transition, Tr::new(3, 3, 1.3, 2));
+    /// ```
+    pub fn acceptor<S: Into<W>>(label: Label, weight: S, nextstate: StateId) -> Self {
+        Self::new(label, label, weight, nextstate)
+    }
+
+    /// Returns the Tr with its weight replaced by `weight`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustfst::Tr;
+    /// # use rustfst::semirings::{TropicalWeight, Semiring};
+    /// let transition = Tr::<TropicalWeight>::new(0, 1, 1.3, 2).with_weight(0.5);
+    ///
+    /// assert_eq!(transition, Tr::new(0, 1, 0.5, 2));
+    /// ```
+    pub fn with_weight<S: Into<W>>(self, weight: S) -> Self {
+        Tr {
+            weight: weight.into(),
+            ..self
+        }
+    }
+
+    /// Returns the Tr with its destination replaced by `nextstate`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustfst::Tr;
+    /// # use rustfst::semirings::{TropicalWeight, Semiring};
+    /// let transition = Tr::<TropicalWeight>::new(0, 1, 1.3, 2).with_nextstate(5);
+    ///
+    /// assert_eq!(transition, Tr::new(0, 1, 1.3, 5));
+    /// ```
+    pub fn with_nextstate(self, nextstate: StateId) -> Self {
+        Tr { nextstate, ..self }
+    }
+
+    /// Returns true if both the input and the output labels are epsilons.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustfst::{Tr, EPS_LABEL};
+    /// # use rustfst::semirings::{TropicalWeight, Semiring};
+    /// assert!(Tr::<TropicalWeight>::new(EPS_LABEL, EPS_LABEL, 1.3, 2).is_epsilon());
+    /// assert!(!Tr::<TropicalWeight>::new(EPS_LABEL, 1, 1.3, 2).is_epsilon());
+    /// ```
+    #[inline]
+    pub fn is_epsilon(&self) -> bool {
+        self.ilabel == EPS_LABEL && self.olabel == EPS_LABEL
+    }
+
+    /// Returns true if the input label is an epsilon.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustfst::{Tr, EPS_LABEL};
+    /// # use rustfst::semirings::{TropicalWeight, Semiring};
+    /// assert!(Tr::<TropicalWeight>::new(EPS_LABEL, 1, 1.3, 2).is_input_epsilon());
+    /// assert!(!Tr::<TropicalWeight>::new(1, EPS_LABEL, 1.3, 2).is_input_epsilon());
+    /// ```
+    #[inline]
+    pub fn is_input_epsilon(&self) -> bool {
+        self.ilabel == EPS_LABEL
+    }
+
+    /// Returns true if the output label is an epsilon.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustfst::{Tr, EPS_LABEL};
+    /// # use rustfst::semirings::{TropicalWeight, Semiring};
+    /// assert!(Tr::<TropicalWeight>::new(1, EPS_LABEL, 1.3, 2).is_output_epsilon());
+    /// assert!(!Tr::<TropicalWeight>::new(EPS_LABEL, 1, 1.3, 2).is_output_epsilon());
+    /// ```
+    #[inline]
+    pub fn is_output_epsilon(&self) -> bool {
+        self.olabel == EPS_LABEL
+    }
+
     /// Updates the values of the attributes of the Tr from another Tr.
     ///
     /// # Example
@@ -1,14 +1,20 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use crate::semirings::Semiring;
 use crate::Tr;
 use std::fmt::Debug;
 use std::sync::Arc;
 
+/// Trs leaving a state of an FST. All the implementations give access to the trs as a slice :
+/// they can be iterated in both directions with `trs().iter().rev()`.
 pub trait Trs<W: Semiring>: std::ops::Deref<Target = [Tr<W>]> + Debug {
     fn trs(&self) -> &[Tr<W>];
     fn shallow_clone(&self) -> Self;
 }
 
 #[derive(Debug, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TrsVec<W: Semiring>(pub(crate) Arc<Vec<Tr<W>>>);
 
 impl<W: Semiring> Trs<W> for TrsVec<W> {
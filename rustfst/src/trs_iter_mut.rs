@@ -238,6 +238,72 @@ impl<'a, W: Semiring> TrsIterMut<'a, W> {
     }
 }
 
+/// Mutable access to the weights of the trs leaving a state, the labels and the nextstates
+/// being left untouched. Used by FSTs which can't modify the structure of their trs in place,
+/// such as `ConstFst`.
+pub struct TrsWeightsIterMut<'a, W: Semiring> {
+    trs: &'a mut [Tr<W>],
+    properties: &'a mut FstProperties,
+}
+
+impl<'a, W: Semiring> Index<usize> for TrsWeightsIterMut<'a, W> {
+    type Output = Tr<W>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.trs[index]
+    }
+}
+
+impl<'a, W: Semiring> TrsWeightsIterMut<'a, W> {
+    pub(crate) fn new(trs: &'a mut [Tr<W>], properties: &'a mut FstProperties) -> Self {
+        Self { trs, properties }
+    }
+
+    pub fn get(&self, idx: usize) -> Option<&Tr<W>> {
+        self.trs.get(idx)
+    }
+
+    /// Get a reference to the  number `idx` `Tr` of the object.
+    ///
+    /// # Safety
+    ///
+    /// Unsafe behaviour if `self.len() >= idx`
+    pub unsafe fn get_unchecked(&self, idx: usize) -> &Tr<W> {
+        self.trs.get_unchecked(idx)
+    }
+
+    pub fn len(&self) -> usize {
+        self.trs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.trs.is_empty()
+    }
+
+    pub fn set_weight(&mut self, idx: usize, weight: W) -> Result<()> {
+        let old_tr = self
+            .trs
+            .get_mut(idx)
+            .ok_or_else(|| format_err!("set_tr shouldn't be called when the iteration is over"))?;
+        *self.properties =
+            compute_new_properties_weights(*self.properties, &old_tr.weight, &weight);
+        old_tr.weight = weight;
+        Ok(())
+    }
+
+    /// Modify the weight of the  number `idx` `Tr` of the object.
+    ///
+    /// # Safety
+    ///
+    /// Unsafe behaviour if `self.len() >= idx`
+    pub unsafe fn set_weight_unchecked(&mut self, idx: usize, weight: W) {
+        let old_tr = self.trs.get_unchecked_mut(idx);
+        *self.properties =
+            compute_new_properties_weights(*self.properties, &old_tr.weight, &weight);
+        old_tr.weight = weight;
+    }
+}
+
 fn update_properties_labels(
     properties: &mut FstProperties,
     old_ilabel: Label,
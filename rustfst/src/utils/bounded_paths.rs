@@ -0,0 +1,134 @@
+use crate::fst_path::FstPath;
+use crate::fst_traits::Fst;
+use crate::semirings::Semiring;
+use crate::{Label, StateId, Trs};
+
+struct BoundedPathsIterator<'a, W: Semiring, F: Fst<W>> {
+    fst: &'a F,
+    max_len: usize,
+    // State reached, path followed to reach it and number of trs of this path.
+    stack: Vec<(StateId, FstPath<W>, usize)>,
+}
+
+impl<'a, W: Semiring, F: Fst<W>> Iterator for BoundedPathsIterator<'a, W, F> {
+    type Item = (Vec<Label>, Vec<Label>, W);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((state, mut path, len)) = self.stack.pop() {
+            if len < self.max_len {
+                // Pushed in reverse order so that the trs are followed in order.
+                for tr in unsafe { self.fst.get_trs_unchecked(state) }
+                    .trs()
+                    .iter()
+                    .rev()
+                {
+                    let mut new_path = path.clone();
+                    new_path
+                        .add_to_path(tr.ilabel, tr.olabel, &tr.weight)
+                        .expect("Error add_to_path in BoundedPathsIterator");
+                    self.stack.push((tr.nextstate, new_path, len + 1));
+                }
+            }
+
+            if let Some(final_weight) = unsafe { self.fst.final_weight_unchecked(state) } {
+                path.add_weight(&final_weight)
+                    .expect("Error add_weight in BoundedPathsIterator");
+                return Some((path.ilabels, path.olabels, path.weight));
+            }
+        }
+        None
+    }
+}
+
+/// Enumerates the (input labels, output labels, weight) triples of the successful paths made of
+/// at most `max_len` trs, using a depth-first traversal from the start state.
+///
+/// Contrary to `PathsIterator`, the enumeration terminates on cyclic FSTs. The epsilon labels
+/// are not included in the label sequences, although the epsilon trs count in the number of
+/// trs of the path. A string accepted by several paths is returned once per path.
+///
+/// # Example
+///
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::fst_traits::MutableFst;
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::utils::paths;
+/// # use rustfst::Tr;
+/// # fn main() -> Result<()> {
+/// let mut fst = VectorFst::<TropicalWeight>::new();
+/// fst.add_state();
+/// fst.set_start(0)?;
+/// fst.set_final(0, TropicalWeight::one())?;
+/// fst.add_tr(0, Tr::new(1, 2, 0.5, 0))?;
+///
+/// let res: Vec<_> = paths(&fst, 2).collect();
+/// assert_eq!(
+///     res,
+///     vec![
+///         (vec![], vec![], TropicalWeight::new(0.0)),
+///         (vec![1], vec![2], TropicalWeight::new(0.5)),
+///         (vec![1, 1], vec![2, 2], TropicalWeight::new(1.0)),
+///     ]
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn paths<'a, W, F>(
+    fst: &'a F,
+    max_len: usize,
+) -> impl Iterator<Item = (Vec<Label>, Vec<Label>, W)> + 'a
+where
+    W: Semiring,
+    F: 'a + Fst<W>,
+{
+    let stack = match fst.start() {
+        Some(start) => vec![(start, FstPath::default(), 0)],
+        None => vec![],
+    };
+    BoundedPathsIterator {
+        fst,
+        max_len,
+        stack,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anyhow::Result;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::MutableFst;
+    use crate::semirings::TropicalWeight;
+    use crate::{Tr, EPS_LABEL};
+
+    #[test]
+    fn test_bounded_paths_cyclic_fst() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(3);
+        fst.set_start(0)?;
+        fst.add_tr(0, Tr::new(1, 1, 1.0, 1))?;
+        fst.add_tr(1, Tr::new(2, EPS_LABEL, 2.0, 0))?;
+        fst.add_tr(1, Tr::new(EPS_LABEL, 3, 0.25, 2))?;
+        fst.set_final(1, 0.5)?;
+        fst.set_final(2, 0.0)?;
+
+        let res: Vec<_> = paths(&fst, 3).collect();
+        assert_eq!(
+            res,
+            vec![
+                (vec![1], vec![1], TropicalWeight::new(1.5)),
+                (vec![1, 2, 1], vec![1, 1], TropicalWeight::new(4.5)),
+                (vec![1], vec![1, 3], TropicalWeight::new(1.25)),
+            ]
+        );
+
+        assert_eq!(paths(&fst, 0).count(), 0);
+        assert_eq!(paths(&fst, 5).count(), 3 + 2);
+        assert_eq!(paths(&VectorFst::<TropicalWeight>::new(), 3).count(), 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use std::fmt::{Display, Write};
+
+use crate::fst_traits::ExpandedFst;
+use crate::semirings::Semiring;
+use crate::{Label, StateId, SymbolTable, Trs};
+
+fn label_to_symbol(label: Label, symt: Option<&SymbolTable>) -> String {
+    symt.and_then(|symt| symt.get_symbol(label))
+        .map(|symbol| symbol.to_string())
+        .unwrap_or_else(|| format!("{}", label))
+}
+
+fn write_state<W, F>(
+    fst: &F,
+    state: StateId,
+    isymt: Option<&SymbolTable>,
+    osymt: Option<&SymbolTable>,
+    res: &mut String,
+) where
+    W: Semiring + Display,
+    F: ExpandedFst<W>,
+{
+    for tr in unsafe { fst.get_trs_unchecked(state) }.trs() {
+        writeln!(
+            res,
+            "{}\t{}\t{}:{}/{}",
+            state,
+            tr.nextstate,
+            label_to_symbol(tr.ilabel, isymt),
+            label_to_symbol(tr.olabel, osymt),
+            tr.weight
+        )
+        .unwrap();
+    }
+}
+
+/// Renders an FST for debugging purposes, printing each transition as
+/// `state\tnextstate\tisymbol:osymbol/weight` and each final state as `state\tweight`.
+///
+/// The labels are replaced by their symbol when a symbol table is provided and contains them. As
+/// for the `Display` of the FSTs, the trs leaving the start state are printed first. Nothing is
+/// printed if the FST has no start state.
+///
+/// # Example
+///
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::fst_traits::MutableFst;
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::utils::display_with_symbols;
+/// # use rustfst::{SymbolTable, Tr};
+/// # fn main() -> Result<()> {
+/// let mut symt = SymbolTable::new();
+/// symt.add_symbols(vec!["a", "b"]);
+///
+/// let mut fst = VectorFst::<TropicalWeight>::new();
+/// fst.add_states(2);
+/// fst.set_start(0)?;
+/// fst.add_tr(0, Tr::new(1, 2, 0.5, 1))?;
+/// fst.set_final(1, TropicalWeight::one())?;
+///
+/// assert_eq!(display_with_symbols(&fst, Some(&symt), None), "0\t1\ta:2/0.5\n1\t0\n");
+/// # Ok(())
+/// # }
+/// ```
+pub fn display_with_symbols<W, F>(
+    fst: &F,
+    isymt: Option<&SymbolTable>,
+    osymt: Option<&SymbolTable>,
+) -> String
+where
+    W: Semiring + Display,
+    F: ExpandedFst<W>,
+{
+    let mut res = String::new();
+    let start_state = match fst.start() {
+        Some(s) => s,
+        None => return res,
+    };
+
+    write_state(fst, start_state, isymt, osymt, &mut res);
+    for state in fst.states_range() {
+        if state != start_state {
+            write_state(fst, state, isymt, osymt, &mut res);
+        }
+    }
+    for state in fst.states_range() {
+        if let Some(final_weight) = unsafe { fst.final_weight_unchecked(state) } {
+            writeln!(res, "{}\t{}", state, final_weight).unwrap();
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anyhow::Result;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::MutableFst;
+    use crate::semirings::TropicalWeight;
+    use crate::Tr;
+
+    #[test]
+    fn test_display_with_symbols_transducer() -> Result<()> {
+        let mut isymt = SymbolTable::new();
+        isymt.add_symbols(vec!["hello", "world"]);
+        let mut osymt = SymbolTable::new();
+        osymt.add_symbols(vec!["bonjour", "monde"]);
+
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(3);
+        fst.set_start(1)?;
+        fst.add_tr(1, Tr::new(1, 1, 0.5, 0))?;
+        fst.add_tr(0, Tr::new(2, 0, 1.5, 2))?;
+        fst.set_final(2, 0.25)?;
+
+        assert_eq!(
+            display_with_symbols(&fst, Some(&isymt), Some(&osymt)),
+            "1\t0\thello:bonjour/0.5\n0\t2\tworld:<eps>/1.5\n2\t0.25\n"
+        );
+        // The labels missing from the symbol tables are printed as numbers.
+        assert_eq!(
+            display_with_symbols(&fst, None, Some(&SymbolTable::empty())),
+            "1\t0\t1:1/0.5\n0\t2\t2:0/1.5\n2\t0.25\n"
+        );
+        assert_eq!(
+            format!("{}", fst),
+            "1\t0\t1\t1\t0.5\n0\t2\t2\t0\t1.5\n2\t0.25\n"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,184 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+
+use crate::fst_traits::MutableFst;
+use crate::parsers::text_fst::{parse_row_with_symbols, RowParsed};
+use crate::semirings::SerializableSemiring;
+use crate::{StateId, SymbolTable, Tr};
+
+/// Builds an FST from its text representation (`At&T FSM format`) one line at a time.
+///
+/// Contrary to `SerializableFst::from_text_string`, the text is never stored in memory : each
+/// line is parsed and added to the FST right away. The states are created as they get
+/// referenced and the state of the first line is the start state.
+///
+/// The values can be separated by any whitespace and the blank lines as well as the lines
+/// starting with `#` are ignored.
+///
+/// # Example
+///
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::fst_traits::{CoreFst, ExpandedFst};
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::utils::FstBuilder;
+/// # fn main() -> Result<()> {
+/// let mut builder = FstBuilder::<TropicalWeight, VectorFst<_>>::new();
+/// for line in "0\t1\t3\t5\t0.5\n1\t2\t4\t6\n2\t0.25\n".lines() {
+///     builder.add_line(line)?;
+/// }
+/// let fst = builder.finish()?;
+///
+/// assert_eq!(fst.num_states(), 3);
+/// assert_eq!(fst.start(), Some(0));
+/// assert_eq!(fst.final_weight(2)?, Some(TropicalWeight::new(0.25)));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct FstBuilder<W: SerializableSemiring, F: MutableFst<W>> {
+    fst: F,
+    isymt: Option<Arc<SymbolTable>>,
+    osymt: Option<Arc<SymbolTable>>,
+    num_lines: usize,
+    w: PhantomData<W>,
+}
+
+impl<W: SerializableSemiring, F: MutableFst<W>> FstBuilder<W, F> {
+    pub fn new() -> Self {
+        Self::new_with_symbols(None, None)
+    }
+
+    /// Creates a builder resolving the labels through the symbol tables when they are provided.
+    /// The symbol tables are attached to the resulting FST.
+    pub fn new_with_symbols(
+        isymt: Option<Arc<SymbolTable>>,
+        osymt: Option<Arc<SymbolTable>>,
+    ) -> Self {
+        Self {
+            fst: F::new(),
+            isymt,
+            osymt,
+            num_lines: 0,
+            w: PhantomData,
+        }
+    }
+
+    fn add_states_up_to(&mut self, state: StateId) {
+        let num_states = state as usize + 1;
+        if num_states > self.fst.num_states() {
+            self.fst.add_states(num_states - self.fst.num_states());
+        }
+    }
+
+    /// Parses a line describing either a transition (`src dest ilabel olabel [weight]` or
+    /// `src dest label [weight]` for an acceptor) or a final state (`state [weight]`) and adds it
+    /// to the FST.
+    pub fn add_line(&mut self, line: &str) -> Result<()> {
+        self.num_lines += 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(());
+        }
+        let row = parse_row_with_symbols(line, self.isymt.as_deref(), self.osymt.as_deref())
+            .with_context(|| format!("Error while parsing line {} of text fst", self.num_lines))?;
+
+        let state = match &row {
+            RowParsed::Transition(t) => t.state,
+            RowParsed::FinalState(f) => f.state,
+            RowParsed::InfinityFinalState(s) => *s,
+        };
+        self.add_states_up_to(state);
+        if self.fst.start().is_none() {
+            self.fst.set_start(state)?;
+        }
+
+        match row {
+            RowParsed::Transition(t) => {
+                self.add_states_up_to(t.nextstate);
+                let weight = t.weight.unwrap_or_else(W::one);
+                let tr = Tr::new(t.ilabel, t.olabel, weight, t.nextstate);
+                unsafe { self.fst.add_tr_unchecked(t.state, tr) };
+            }
+            RowParsed::FinalState(f) => {
+                let weight = f.weight.unwrap_or_else(W::one);
+                unsafe { self.fst.set_final_unchecked(f.state, weight) };
+            }
+            RowParsed::InfinityFinalState(_) => {}
+        };
+        Ok(())
+    }
+
+    /// Returns the FST built from all the lines added so far, with its properties computed.
+    pub fn finish(self) -> Result<F> {
+        let mut fst = self.fst;
+        if let Some(isymt) = self.isymt {
+            fst.set_input_symbols(isymt);
+        }
+        if let Some(osymt) = self.osymt {
+            fst.set_output_symbols(osymt);
+        }
+        fst.compute_and_update_properties_all()?;
+        Ok(fst)
+    }
+}
+
+impl<W: SerializableSemiring, F: MutableFst<W>> Default for FstBuilder<W, F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::SerializableFst;
+    use crate::semirings::TropicalWeight;
+
+    #[test]
+    fn test_fst_builder_same_as_batch_parser() -> Result<()> {
+        let text = "0\t1\t32\t32\n1\t2\t45\t45\n2\t3\t18\t18\t0.25\n2\tInfinity\n\
+                    3\t5\t45\t45\n5\t1\t5\t5\t0.31\n3\t0.67\n5\n";
+
+        let mut builder = FstBuilder::<TropicalWeight, VectorFst<_>>::new();
+        for line in text.lines() {
+            builder.add_line(line)?;
+        }
+        let fst = builder.finish()?;
+
+        let fst_ref = VectorFst::<TropicalWeight>::from_text_string(text)?;
+        assert_eq!(fst, fst_ref);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fst_builder_with_symbols() -> Result<()> {
+        let mut symt = SymbolTable::new();
+        symt.add_symbols(vec!["a", "b"]);
+        let symt = Arc::new(symt);
+
+        let mut builder = FstBuilder::<TropicalWeight, VectorFst<_>>::new_with_symbols(
+            Some(Arc::clone(&symt)),
+            Some(Arc::clone(&symt)),
+        );
+        builder.add_line("# A comment")?;
+        builder.add_line("1 0 a b 0.5")?;
+        builder.add_line("")?;
+        builder.add_line("0 0.25")?;
+        assert!(builder.add_line("0 1 a c").is_err());
+        let fst = builder.finish()?;
+
+        let fst_ref = VectorFst::<TropicalWeight>::from_text_string_with_symbols(
+            "1\t0\ta\tb\t0.5\n0\t0.25\n",
+            Some(Arc::clone(&symt)),
+            Some(symt),
+        )?;
+        assert_eq!(fst, fst_ref);
+        Ok(())
+    }
+}
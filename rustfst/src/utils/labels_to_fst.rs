@@ -7,6 +7,8 @@ use crate::Label;
 
 /// Turns a list of input labels and output labels into a linear FST.
 /// The only accepted path in the FST has for input `labels_input` and for output `labels_output`.
+/// If the two lists don't have the same length, the shorter one is padded with epsilons. The
+/// weight is put on the final state.
 ///
 /// # Example
 ///
@@ -259,3 +261,57 @@ macro_rules! fst {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anyhow::Result;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::{CoreFst, ExpandedFst};
+    use crate::semirings::TropicalWeight;
+    use crate::{StateId, Trs, EPS_LABEL};
+
+    #[test]
+    fn test_acceptor() -> Result<()> {
+        let fst: VectorFst<TropicalWeight> = acceptor(&[3, 1, 2], TropicalWeight::new(1.5));
+        assert_eq!(fst.num_states(), 4);
+        assert_eq!(fst.start(), Some(0));
+        for (s, label) in [3, 1, 2].iter().enumerate() {
+            let s = s as StateId;
+            assert_eq!(
+                fst.get_trs(s)?.trs(),
+                &[Tr::new(*label, *label, TropicalWeight::one(), s + 1)]
+            );
+        }
+        assert_eq!(fst.final_weight(3)?, Some(TropicalWeight::new(1.5)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_transducer_pads_with_epsilons() -> Result<()> {
+        let fst: VectorFst<TropicalWeight> = transducer(&[1, 2, 3], &[4], TropicalWeight::new(0.5));
+        assert_eq!(fst.num_states(), 4);
+        assert_eq!(
+            fst.get_trs(0)?.trs(),
+            &[Tr::new(1, 4, TropicalWeight::one(), 1)]
+        );
+        assert_eq!(
+            fst.get_trs(1)?.trs(),
+            &[Tr::new(2, EPS_LABEL, TropicalWeight::one(), 2)]
+        );
+        assert_eq!(
+            fst.get_trs(2)?.trs(),
+            &[Tr::new(3, EPS_LABEL, TropicalWeight::one(), 3)]
+        );
+        assert_eq!(fst.final_weight(3)?, Some(TropicalWeight::new(0.5)));
+
+        let fst: VectorFst<TropicalWeight> = transducer(&[], &[4, 5], TropicalWeight::one());
+        assert_eq!(
+            fst.get_trs(1)?.trs(),
+            &[Tr::new(EPS_LABEL, 5, TropicalWeight::one(), 2)]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use anyhow::Result;
+use superslice::Ext;
+
+use crate::fst_traits::ExpandedFst;
+use crate::semirings::Semiring;
+use crate::{Label, StateId, Trs};
+
+/// Returns the index of the first tr leaving `state` whose input label is greater or equal to
+/// `label`, or the number of trs leaving `state` if there is none. The search is done by
+/// dichotomy.
+///
+/// The trs leaving `state` must be sorted by input label, e.g using `tr_sort` with
+/// `ILabelCompare`. This precondition is not checked and the result is meaningless otherwise.
+///
+/// # Errors
+///
+/// Returns an error if `state` doesn't exist.
+///
+/// # Example
+///
+/// ```
+/// # use anyhow::Result;
+/// # use rustfst::fst_impls::VectorFst;
+/// # use rustfst::fst_traits::MutableFst;
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::utils::lower_bound_ilabel;
+/// # use rustfst::Tr;
+/// # fn main() -> Result<()> {
+/// let mut fst = VectorFst::<TropicalWeight>::new();
+/// fst.add_states(2);
+/// fst.add_tr(0, Tr::new(1, 1, TropicalWeight::one(), 1))?;
+/// fst.add_tr(0, Tr::new(3, 3, TropicalWeight::one(), 1))?;
+///
+/// assert_eq!(lower_bound_ilabel(&fst, 0, 2)?, 1);
+/// assert_eq!(lower_bound_ilabel(&fst, 0, 4)?, 2);
+/// # Ok(())
+/// # }
+/// ```
+pub fn lower_bound_ilabel<W, F>(fst: &F, state: StateId, label: Label) -> Result<usize>
+where
+    W: Semiring,
+    F: ExpandedFst<W>,
+{
+    let trs = fst.get_trs(state)?;
+    Ok(trs.trs().lower_bound_by(|tr| tr.ilabel.cmp(&label)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::algorithms::tr_compares::ILabelCompare;
+    use crate::algorithms::tr_sort;
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::MutableFst;
+    use crate::semirings::TropicalWeight;
+    use crate::Tr;
+
+    #[test]
+    fn test_lower_bound_ilabel_matches_linear_scan() -> Result<()> {
+        let mut fst = VectorFst::<TropicalWeight>::new();
+        fst.add_states(2);
+        for ilabel in &[5, 2, 2, 0, 7, 5, 5, 9, 3] {
+            fst.add_tr(0, Tr::new(*ilabel, 1, TropicalWeight::one(), 1))?;
+        }
+        tr_sort(&mut fst, ILabelCompare {});
+
+        let trs = fst.get_trs(0)?;
+        for label in 0..11 {
+            let expected = trs
+                .trs()
+                .iter()
+                .position(|tr| tr.ilabel >= label)
+                .unwrap_or(trs.len());
+            assert_eq!(lower_bound_ilabel(&fst, 0, label)?, expected);
+        }
+        assert_eq!(lower_bound_ilabel(&fst, 1, 3)?, 0);
+        assert!(lower_bound_ilabel(&fst, 2, 3).is_err());
+        Ok(())
+    }
+}
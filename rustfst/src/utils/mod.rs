@@ -1,5 +1,15 @@
+mod bounded_paths;
+mod display_with_symbols;
+mod fst_builder;
 mod fst_to_labels;
 mod labels_to_fst;
+mod lower_bound_ilabel;
+mod structurally_equal;
 
+pub use self::bounded_paths::paths;
+pub use self::display_with_symbols::display_with_symbols;
+pub use self::fst_builder::FstBuilder;
 pub use self::fst_to_labels::decode_linear_fst;
 pub use self::labels_to_fst::{acceptor, transducer};
+pub use self::lower_bound_ilabel::lower_bound_ilabel;
+pub use self::structurally_equal::structurally_equal;
@@ -0,0 +1,86 @@
+use crate::fst_traits::ExpandedFst;
+use crate::semirings::Semiring;
+use crate::Trs;
+
+/// Checks whether two FSTs have the same start state, the same states and the same trs and
+/// final weights for each state. The symbol tables and the properties are not compared. The
+/// states must be numbered the same way in both FSTs, use `isomorphic` otherwise.
+///
+/// This allows comparing FSTs of different types.
+///
+/// # Example
+///
+/// ```
+/// # use rustfst::fst_impls::{ConstFst, VectorFst};
+/// # use rustfst::semirings::{Semiring, TropicalWeight};
+/// # use rustfst::utils::{acceptor, structurally_equal};
+/// let fst: VectorFst<TropicalWeight> = acceptor(&[1, 2, 3], TropicalWeight::one());
+/// let const_fst: ConstFst<TropicalWeight> = fst.clone().into();
+///
+/// assert!(structurally_equal(&fst, &const_fst));
+/// ```
+pub fn structurally_equal<W, F1, F2>(fst1: &F1, fst2: &F2) -> bool
+where
+    W: Semiring,
+    F1: ExpandedFst<W>,
+    F2: ExpandedFst<W>,
+{
+    if fst1.num_states() != fst2.num_states() || fst1.start() != fst2.start() {
+        return false;
+    }
+    fst1.states_range().all(|state| unsafe {
+        fst1.final_weight_unchecked(state) == fst2.final_weight_unchecked(state)
+            && fst1.get_trs_unchecked(state).trs() == fst2.get_trs_unchecked(state).trs()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    use crate::fst_impls::VectorFst;
+    use crate::fst_traits::{Fst, MutableFst};
+    use crate::semirings::TropicalWeight;
+    use crate::utils::transducer;
+    use crate::{SymbolTable, Tr};
+
+    #[test]
+    fn test_structurally_equal_ignores_symbol_tables() {
+        let mut fst1: VectorFst<TropicalWeight> =
+            transducer(&[1, 2], &[2, 1], TropicalWeight::new(0.5));
+        let mut fst2 = fst1.clone();
+
+        let mut symt1 = SymbolTable::new();
+        symt1.add_symbol("a");
+        symt1.add_symbol("b");
+        let mut symt2 = SymbolTable::new();
+        symt2.add_symbol("c");
+        symt2.add_symbol("d");
+        fst1.set_input_symbols(Arc::new(symt1));
+        fst2.set_input_symbols(Arc::new(symt2));
+        fst2.take_output_symbols();
+
+        assert!(structurally_equal(&fst1, &fst2));
+    }
+
+    #[test]
+    fn test_structurally_equal_different_fsts() -> anyhow::Result<()> {
+        let fst1: VectorFst<TropicalWeight> =
+            transducer(&[1, 2], &[2, 1], TropicalWeight::new(0.5));
+
+        let mut fst2 = fst1.clone();
+        fst2.add_tr(0, Tr::new(1, 1, TropicalWeight::one(), 1))?;
+        assert!(!structurally_equal(&fst1, &fst2));
+
+        let mut fst3 = fst1.clone();
+        fst3.set_final(2, TropicalWeight::new(1.5))?;
+        assert!(!structurally_equal(&fst1, &fst3));
+
+        let mut fst4 = fst1.clone();
+        fst4.set_start(1)?;
+        assert!(!structurally_equal(&fst1, &fst4));
+        Ok(())
+    }
+}